@@ -0,0 +1,117 @@
+//! Benchmarks of the polynomial and field arithmetic
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::Rng;
+use shamirsecretsharing::f2x::{widening_clmul, F2x, Word};
+use shamirsecretsharing::galoisfields::{
+    batch_inv, FieldArithmetic, GF2p128, GF2p16, GF2p192, GF2p233, GF2p256, GF2p283, GF2p409,
+    GF2p571, GF2p8, F3329,
+};
+use shamirsecretsharing::gcm::GF2p128Gcm;
+use shamirsecretsharing::primefields::{Fp127, Fp25519, Secp256k1Scalar};
+use shamirsecretsharing::tower::GF2p16p8;
+
+fn bench_clmul(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let (a, b): (Word, Word) = (rng.gen(), rng.gen());
+    c.bench_function("widening_clmul", |bench| {
+        bench.iter(|| widening_clmul(black_box(a), black_box(b)))
+    });
+}
+
+fn bench_widening_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("widening_mul");
+    macro_rules! bench_limbs {
+        ($($limbs:literal),+) => {
+            $(
+                let mut rng = rand::thread_rng();
+                let lhs = F2x::<$limbs>::from_limbs(core::array::from_fn(|_| rng.gen()));
+                let rhs = F2x::<$limbs>::from_limbs(core::array::from_fn(|_| rng.gen()));
+                group.bench_function(BenchmarkId::from_parameter($limbs), |bench| {
+                    bench.iter(|| black_box(&lhs).widening_mul(black_box(&rhs)))
+                });
+                group.bench_function(BenchmarkId::new("square", $limbs), |bench| {
+                    bench.iter(|| black_box(&lhs).widening_square())
+                });
+            )+
+        };
+    }
+    bench_limbs!(1, 8, 16, 36);
+    group.finish();
+}
+
+/// The operations that walk the limbs in order, to compare limb layouts: truncating schoolbook
+/// multiplication, shifts, and conversion to and from little-endian limbs
+fn bench_limb_order(c: &mut Criterion) {
+    let mut group = c.benchmark_group("limb_order");
+    macro_rules! bench_limbs {
+        ($($limbs:literal),+) => {
+            $(
+                let mut rng = rand::thread_rng();
+                let lhs = F2x::<$limbs>::from_limbs(core::array::from_fn(|_| rng.gen()));
+                let rhs = F2x::<$limbs>::from_limbs(core::array::from_fn(|_| rng.gen()));
+                group.bench_function(BenchmarkId::new("overflowing_mul", $limbs), |bench| {
+                    bench.iter(|| black_box(&lhs).overflowing_mul(black_box(&rhs)))
+                });
+                group.bench_function(BenchmarkId::new("shl", $limbs), |bench| {
+                    bench.iter(|| black_box(&lhs).shl(black_box(17)))
+                });
+                group.bench_function(BenchmarkId::new("shr", $limbs), |bench| {
+                    bench.iter(|| black_box(&lhs).shr(black_box(17)))
+                });
+                group.bench_function(BenchmarkId::new("le_limbs", $limbs), |bench| {
+                    bench.iter(|| F2x::<$limbs>::from_le_limbs(black_box(&lhs).to_le_limbs()))
+                });
+            )+
+        };
+    }
+    bench_limbs!(1, 8, 16, 36);
+    group.finish();
+}
+
+/// Multiplication (including reduction), squaring, inversion, and batch inversion of 64 elements
+fn bench_field<F: FieldArithmetic>(c: &mut Criterion, name: &str) {
+    let mut group = c.benchmark_group(name);
+    let mut rng = rand::thread_rng();
+    let (lhs, rhs) = (F::random(&mut rng), F::random(&mut rng));
+    group.bench_function("modmul", |bench| {
+        bench.iter(|| black_box(&lhs).modmul(black_box(&rhs)))
+    });
+    group.bench_function("square", |bench| bench.iter(|| black_box(&lhs).square()));
+    group.bench_function("modinv", |bench| bench.iter(|| black_box(&lhs).modinv()));
+    let elems = (0..64).map(|_| F::random(&mut rng)).collect::<Vec<F>>();
+    group.bench_function("batch_inv/64", |bench| {
+        bench.iter(|| {
+            let mut elems = elems.clone();
+            batch_inv(black_box(&mut elems));
+            elems
+        })
+    });
+    group.finish();
+}
+
+fn bench_fields(c: &mut Criterion) {
+    bench_field::<GF2p8>(c, "GF2p8");
+    bench_field::<GF2p16>(c, "GF2p16");
+    bench_field::<GF2p128>(c, "GF2p128");
+    bench_field::<GF2p128Gcm>(c, "GF2p128Gcm");
+    bench_field::<GF2p16p8>(c, "GF2p16p8");
+    bench_field::<GF2p192>(c, "GF2p192");
+    bench_field::<GF2p233>(c, "GF2p233");
+    bench_field::<GF2p256>(c, "GF2p256");
+    bench_field::<GF2p283>(c, "GF2p283");
+    bench_field::<GF2p409>(c, "GF2p409");
+    bench_field::<GF2p571>(c, "GF2p571");
+    bench_field::<F3329>(c, "F3329");
+    bench_field::<Fp127>(c, "Fp127");
+    bench_field::<Fp25519>(c, "Fp25519");
+    bench_field::<Secp256k1Scalar>(c, "Secp256k1Scalar");
+}
+
+criterion_group!(
+    benches,
+    bench_clmul,
+    bench_widening_mul,
+    bench_limb_order,
+    bench_fields
+);
+criterion_main!(benches);
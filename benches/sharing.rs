@@ -0,0 +1,65 @@
+//! Benchmarks of splitting a secret into shares and reconstructing it
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use shamirsecretsharing::additivefft;
+use shamirsecretsharing::galoisfields::{FieldArithmetic, GF2p128};
+use shamirsecretsharing::poly::PolyVec;
+use shamirsecretsharing::secretsharing::SecretSharing256;
+
+fn bench_split(c: &mut Criterion) {
+    let mut group = c.benchmark_group("split");
+    for (threshold, redundancy) in [(3, 5), (10, 20), (50, 100)] {
+        let id = BenchmarkId::from_parameter(format!("{threshold}-of-{redundancy}"));
+        group.bench_function(id, |bench| {
+            bench.iter(|| {
+                let mut secret_sharing = SecretSharing256::init(threshold);
+                secret_sharing.fast_split(redundancy);
+                secret_sharing
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_reconstruct(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reconstruct");
+    for threshold in [3, 10, 50] {
+        let mut secret_sharing = SecretSharing256::init(threshold);
+        secret_sharing.fast_split(threshold);
+        secret_sharing.encrypt(b"Hello, world").unwrap();
+        group.bench_function(BenchmarkId::from_parameter(threshold), |bench| {
+            bench.iter(|| {
+                SecretSharing256::decrypt(
+                    &secret_sharing.ciphertext,
+                    &secret_sharing.nonce,
+                    black_box(&secret_sharing.shards),
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Lagrange interpolation against the product-tree interpolation of `additivefft`
+fn bench_interpolate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("interpolate");
+    let mut rng = rand::thread_rng();
+    for n in [16, 64, 256] {
+        let poly = PolyVec::from_coeffs((0..n).map(|_| GF2p128::random(&mut rng)).collect());
+        let points = (0..n)
+            .map(|_| {
+                let x = GF2p128::random(&mut rng);
+                (x, poly.evaluate(&x))
+            })
+            .collect::<Vec<_>>();
+        group.bench_function(BenchmarkId::new("lagrange", n), |bench| {
+            bench.iter(|| PolyVec::interpolate(black_box(&points), n))
+        });
+        group.bench_function(BenchmarkId::new("product_tree", n), |bench| {
+            bench.iter(|| additivefft::interpolate(black_box(&points)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_split, bench_reconstruct, bench_interpolate);
+criterion_main!(benches);
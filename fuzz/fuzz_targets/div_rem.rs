@@ -0,0 +1,35 @@
+//! Check that Euclidean division reconstructs its input: q * m + r == input with deg r < deg m,
+//! and that the constant-time division agrees with the variable-time one
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use shamirsecretsharing::f2x::{F2x, WideF2x};
+
+fuzz_target!(|input: (F2x<8>, F2x<8>, WideF2x<8>, WideF2x<8>)| {
+    let (dividend, divisor, wide_dividend, wide_divisor) = input;
+
+    if !divisor.is_zero() {
+        let (quot, rem) = dividend.div_rem(&divisor);
+        assert!(rem.degree() < divisor.degree());
+        assert_eq!(
+            quot.widening_mul(&divisor).add(&rem.widen()),
+            dividend.widen()
+        );
+    }
+
+    if !wide_divisor.is_zero() {
+        let (quot, rem) = wide_dividend.div_rem(&wide_divisor);
+        assert!(rem.degree() < wide_divisor.degree());
+        assert_eq!(wide_dividend.div_rem_ct(&wide_divisor), (quot, rem));
+        // The product can be computed whenever the quotient and the divisor fit into the low half
+        let fits = |poly: &WideF2x<8>| poly.shr(F2x::<8>::BITS).is_zero();
+        if fits(&quot) && fits(&wide_divisor) {
+            assert_eq!(
+                quot.truncate()
+                    .widening_mul(&wide_divisor.truncate())
+                    .add(&rem),
+                wide_dividend
+            );
+        }
+    }
+});
@@ -0,0 +1,31 @@
+//! Check that inversion round-trips in binary fields of several sizes, the tower field, and the
+//! prime fields
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use shamirsecretsharing::galoisfields::{FieldArithmetic, GF2p128, GF2p233, GF2p571, GF2p8};
+use shamirsecretsharing::primefields::{Fp25519, Secp256k1Scalar};
+use shamirsecretsharing::tower::GF2p16p8;
+
+fn check<F: FieldArithmetic + core::fmt::Debug>(elem: F) {
+    match elem.modinv() {
+        None => assert!(elem.is_zero()),
+        Some(inv) => {
+            assert!(elem.modmul(&inv).is_one());
+            assert_eq!(inv.modinv(), Some(elem));
+        }
+    }
+}
+
+fuzz_target!(
+    |input: (GF2p8, GF2p128, GF2p233, GF2p571, GF2p16p8, [u8; 32])| {
+        let (gf2p8, gf2p128, gf2p233, gf2p571, tower, bytes) = input;
+        check(gf2p8);
+        check(gf2p128);
+        check(gf2p233);
+        check(gf2p571);
+        check(tower);
+        check(Fp25519::from_be_bytes(&bytes));
+        check(Secp256k1Scalar::from_be_bytes(&bytes));
+    }
+);
@@ -0,0 +1,19 @@
+//! Parse arbitrary text as a share and reconstruct from arbitrary well-formed shares; neither may
+//! panic, and parsed shares must serialize back to an equivalent share
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use shamirsecretsharing::secretsharing::{SecretShare, SecretSharing256};
+
+fuzz_target!(|input: (&str, Vec<SecretShare>)| {
+    let (text, shares) = input;
+
+    if let Ok(share) = SecretShare::from_string(text) {
+        let serialized = share.to_string().expect("parsed shares serialize");
+        let reparsed = SecretShare::from_string(&serialized).expect("serialized shares parse");
+        assert_eq!(reparsed.to_string().unwrap(), serialized);
+        let _ = SecretSharing256::decrypt_from_secret_shares(&[share]);
+    }
+
+    let _ = SecretSharing256::decrypt_from_secret_shares(&shares);
+});
@@ -0,0 +1,38 @@
+//! Cross-check the optimized widening multiplication and squaring of F2x against a bit-serial
+//! reference, at limb counts that exercise the schoolbook, Karatsuba, and Toom-3 paths
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use shamirsecretsharing::f2x::{F2x, WideF2x};
+
+/// Shift-and-add multiplication, one bit of the multiplier at a time
+fn reference_mul<const L: usize>(lhs: &F2x<L>, rhs: &F2x<L>) -> WideF2x<L> {
+    let mut prod = WideF2x::<L>::zero();
+    for i in 0..F2x::<L>::BITS {
+        if !rhs.shr(i).shl(F2x::<L>::BITS - 1).is_zero() {
+            prod = prod.add(&lhs.widen().shl(i));
+        }
+    }
+    prod
+}
+
+fn check<const L: usize>(lhs: &F2x<L>, rhs: &F2x<L>) {
+    let prod = reference_mul(lhs, rhs);
+    assert_eq!(lhs.widening_mul(rhs), prod);
+    assert_eq!(rhs.widening_mul(lhs), prod);
+    assert_eq!(lhs.widening_mul_const(rhs), prod);
+    assert_eq!(lhs.widening_square(), reference_mul(lhs, lhs));
+}
+
+fuzz_target!(|input: (
+    (F2x<1>, F2x<1>),
+    (F2x<8>, F2x<8>),
+    (F2x<15>, F2x<15>),
+    (F2x<36>, F2x<36>)
+)| {
+    let (small, medium, odd, large) = input;
+    check(&small.0, &small.1);
+    check(&medium.0, &medium.1);
+    check(&odd.0, &odd.1);
+    check(&large.0, &large.1);
+});
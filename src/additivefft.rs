@@ -0,0 +1,551 @@
+//! Additive FFT over binary extension fields
+//!
+//! The Gao-Mateer additive FFT evaluates a polynomial over GF(2^m) at every point of a GF(2)-linear
+//! subspace spanned by k elements b_0, ..., b_(k-1). Point i of the subspace is the sum of the b_j
+//! for which bit j of i is set, so with the monomial basis 1, x, x^2, ... point i is simply the
+//! field element whose polynomial representation has the bits of i. Evaluating at all 2^k points
+//! takes O(n log^2 n) field operations instead of the n * t multiplications of Horner's rule.
+//!
+//! The transform and its inverse give fast polynomial multiplication, on which the quasi-linear
+//! `interpolate` is built with a subproduct tree.
+//!
+//! The algorithm relies on x^2 + x being linear over GF(2), so it is only correct for fields of
+//! characteristic 2, and the functions are bound on `BinaryField` accordingly.
+use crate::galoisfields::{batch_inv, BinaryField};
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/// The sum of the basis elements selected by the bits of i
+pub fn span_point<E: BinaryField>(basis: &[E], i: usize) -> E {
+    basis
+        .iter()
+        .enumerate()
+        .filter(|(j, _)| (i >> j) & 1 == 1)
+        .fold(E::zero(), |acc, (_, elem)| acc.modadd(elem))
+}
+
+/// Rewrite f, whose length is a power of 2, into its Taylor expansion at x^2 + x: afterwards
+/// f = sum((coeffs[2i] + coeffs[2i + 1] x) (x^2 + x)^i). With n = 4q, f is split into
+/// f0 + x^(2q) (f1 + x^q f2) with f0 of length 2q, and since (x^2 + x)^q = x^(2q) + x^q,
+/// f = (f0 + x^q h) + (x^2 + x)^q (h + x^q f2) where h = f1 + f2. Both halves are then expanded
+/// recursively.
+fn taylor_expand<E: BinaryField>(coeffs: &mut [E]) {
+    let n = coeffs.len();
+    if n <= 2 {
+        return;
+    }
+    let (quarter, half) = (n / 4, n / 2);
+    for i in 0..quarter {
+        let f2 = coeffs[half + quarter + i];
+        coeffs[half + i].modadd_assign(&f2);
+        let h = coeffs[half + i];
+        coeffs[quarter + i].modadd_assign(&h);
+    }
+    let (low, high) = coeffs.split_at_mut(half);
+    taylor_expand(low);
+    taylor_expand(high);
+}
+
+/// Evaluate the polynomial with the input coefficients (constant term first) at all 2^k points
+/// of the subspace spanned by the k basis elements; element i of the output is the evaluation at
+/// `span_point(basis, i)`. The basis must be linearly independent over GF(2).
+///
+/// Will panic if there are more coefficients than points, or if the basis turns out to be linearly
+/// dependent.
+pub fn additive_fft<E: BinaryField>(coeffs: &[E], basis: &[E]) -> Vec<E> {
+    let points = 1usize
+        .checked_shl(basis.len() as u32)
+        .expect("too many basis elements");
+    if coeffs.len() > points {
+        panic!("more coefficients than points");
+    }
+    let mut padded = coeffs.to_vec();
+    padded.resize(points, E::zero());
+
+    gao_mateer(padded, &levels(basis))
+}
+
+/// Recover the coefficients of the polynomial of degree less than 2^k from its evaluations at all
+/// points of the subspace spanned by the k basis elements, ordered as in `additive_fft`. This is
+/// the inverse of `additive_fft`.
+///
+/// Will panic if the number of evaluations is not 2^k, or if the basis turns out to be linearly
+/// dependent.
+pub fn inverse_additive_fft<E: BinaryField>(evals: &[E], basis: &[E]) -> Vec<E> {
+    if 1usize.checked_shl(basis.len() as u32) != Some(evals.len()) {
+        panic!("the number of evaluations does not match the subspace");
+    }
+
+    inverse_gao_mateer(evals.to_vec(), &levels(basis))
+}
+
+/// The data shared by all subproblems at one depth of the Gao-Mateer recursion: the last basis
+/// element b of the subspace at this depth, its inverse, and the subset sums G_i of the scaled
+/// basis c_j = b_j / b. The subspace at the next depth is spanned by d_j = c_j^2 + c_j.
+struct Level<E: BinaryField> {
+    last: E,
+    last_inv: E,
+    offsets: Vec<E>,
+}
+
+/// Precompute every level of the recursion, so that each depth takes a single inversion no matter
+/// how many subproblems it has
+fn levels<E: BinaryField>(basis: &[E]) -> Vec<Level<E>> {
+    let mut levels = Vec::with_capacity(basis.len());
+    let mut basis = basis.to_vec();
+    while let Some(last) = basis.pop() {
+        let last_inv = last
+            .modinv()
+            .expect("basis elements must be linearly independent");
+        let scaled = basis
+            .iter()
+            .map(|elem| elem.modmul(&last_inv))
+            .collect::<Vec<E>>();
+        // Each G_i differs from G_(i with its lowest bit cleared) by a single scaled basis element
+        let mut offsets = vec![E::zero(); 1 << scaled.len()];
+        for i in 1..offsets.len() {
+            let low = i.trailing_zeros() as usize;
+            offsets[i] = offsets[i & (i - 1)].modadd(&scaled[low]);
+        }
+        basis = scaled
+            .iter()
+            .map(|elem| elem.square().modadd(elem))
+            .collect();
+        levels.push(Level {
+            last,
+            last_inv,
+            offsets,
+        });
+    }
+
+    levels
+}
+
+/// One level of the Gao-Mateer recursion on exactly 2^k coefficients. With g(x) = f(b x) for the
+/// last basis element b, and the Taylor expansion g(x) = g0(x^2 + x) + x g1(x^2 + x), every point
+/// b (G + c) of the subspace with c in {0, 1} evaluates to g0(D) + (G + c) g1(D), where D = G^2 + G
+/// ranges over the subspace spanned by d_j = c_j^2 + c_j for c_j = b_j / b. The two
+/// half-size problems share the same subspace, which has one fewer dimension.
+fn gao_mateer<E: BinaryField>(mut coeffs: Vec<E>, levels: &[Level<E>]) -> Vec<E> {
+    let Some((level, next)) = levels.split_first() else {
+        return coeffs;
+    };
+
+    let mut power = E::one();
+    for coeff in coeffs.iter_mut() {
+        coeff.modmul_assign(&power);
+        power.modmul_assign(&level.last);
+    }
+    taylor_expand(&mut coeffs);
+    let (even, odd): (Vec<E>, Vec<E>) = coeffs.chunks_exact(2).map(|c| (c[0], c[1])).unzip();
+    let (u, v) = (gao_mateer(even, next), gao_mateer(odd, next));
+
+    let half = u.len();
+    let mut evals = vec![E::zero(); 2 * half];
+    for (i, offset) in level.offsets.iter().enumerate() {
+        let eval = u[i].modadd(&offset.modmul(&v[i]));
+        evals[i] = eval;
+        evals[half + i] = eval.modadd(&v[i]);
+    }
+
+    evals
+}
+
+/// Undo one level of `gao_mateer`, recovering u and v from the evaluations first, then g from its
+/// Taylor expansion, and finally f from g(x) = f(b x)
+fn inverse_gao_mateer<E: BinaryField>(evals: Vec<E>, levels: &[Level<E>]) -> Vec<E> {
+    let Some((level, next)) = levels.split_first() else {
+        return evals;
+    };
+
+    let half = evals.len() / 2;
+    let (mut u, mut v) = (Vec::with_capacity(half), Vec::with_capacity(half));
+    for (i, offset) in level.offsets.iter().enumerate() {
+        let odd = evals[half + i].modsub(&evals[i]);
+        u.push(evals[i].modsub(&offset.modmul(&odd)));
+        v.push(odd);
+    }
+    let (even, odd) = (inverse_gao_mateer(u, next), inverse_gao_mateer(v, next));
+    let mut coeffs = even
+        .into_iter()
+        .zip(odd)
+        .flat_map(|(a, b)| [a, b])
+        .collect::<Vec<E>>();
+    inverse_taylor_expand(&mut coeffs);
+
+    let mut power = E::one();
+    for coeff in coeffs.iter_mut() {
+        coeff.modmul_assign(&power);
+        power.modmul_assign(&level.last_inv);
+    }
+
+    coeffs
+}
+
+/// The inverse of `taylor_expand`
+fn inverse_taylor_expand<E: BinaryField>(coeffs: &mut [E]) {
+    let n = coeffs.len();
+    if n <= 2 {
+        return;
+    }
+    let (quarter, half) = (n / 4, n / 2);
+    let (low, high) = coeffs.split_at_mut(half);
+    inverse_taylor_expand(low);
+    inverse_taylor_expand(high);
+    for i in 0..quarter {
+        let h = coeffs[half + i];
+        coeffs[quarter + i].modsub_assign(&h);
+        let f2 = coeffs[half + quarter + i];
+        coeffs[half + i].modsub_assign(&f2);
+    }
+}
+
+/// Below this many coefficients in the shorter operand, `mul` falls back to school book
+/// multiplication
+pub const FFT_MUL_THRESHOLD: usize = 32;
+
+/// Linearly independent elements 1, x, x^2, ... of a binary field: element i is the one whose
+/// serialization only has bit i set. Will panic if k exceeds the exponent m.
+fn monomial_basis<E: BinaryField>(k: usize) -> Vec<E> {
+    if k > E::BITS {
+        panic!("the field is too small for the subspace");
+    }
+    let bytes = E::bytes();
+    (0..k)
+        .map(|i| {
+            let mut buf = vec![0u8; bytes];
+            buf[bytes - 1 - i / 8] = 1 << (i % 8);
+            E::from_be_bytes(&buf)
+        })
+        .collect()
+}
+
+/// Multiply two polynomials given by their coefficients (constant term first). Long operands are
+/// evaluated over a subspace large enough to determine the product with `additive_fft`, multiplied
+/// pointwise, and interpolated back with `inverse_additive_fft`.
+pub fn mul<E: BinaryField>(lhs: &[E], rhs: &[E]) -> Vec<E> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return vec![];
+    }
+    let len = lhs.len() + rhs.len() - 1;
+    if lhs.len().min(rhs.len()) < FFT_MUL_THRESHOLD {
+        let mut prod = vec![E::zero(); len];
+        for (i, a) in lhs.iter().enumerate() {
+            for (j, b) in rhs.iter().enumerate() {
+                prod[i + j].modadd_assign(&a.modmul(b));
+            }
+        }
+        return prod;
+    }
+    let basis = monomial_basis(len.next_power_of_two().trailing_zeros() as usize);
+    let (lhs_evals, rhs_evals) = (additive_fft(lhs, &basis), additive_fft(rhs, &basis));
+    let prod_evals = lhs_evals
+        .iter()
+        .zip(rhs_evals.iter())
+        .map(|(a, b)| a.modmul(b))
+        .collect::<Vec<E>>();
+    let mut prod = inverse_additive_fft(&prod_evals, &basis);
+    prod.truncate(len);
+
+    prod
+}
+
+/// The first `len` coefficients of the power series 1 / f by Newton iteration, which doubles the
+/// precision of g with g + g (1 - f g) every step. f must have a non-zero constant term.
+fn inverse_series<E: BinaryField>(f: &[E], len: usize) -> Vec<E> {
+    let constant_inv = f
+        .first()
+        .and_then(|c| c.modinv())
+        .expect("constant term is not invertible");
+    let mut inverse = vec![constant_inv];
+    while inverse.len() < len {
+        let precision = (2 * inverse.len()).min(len);
+        let mut error = mul(&f[..precision.min(f.len())], &inverse);
+        error.resize(precision, E::zero());
+        error.iter_mut().for_each(|c| *c = E::zero().modsub(c));
+        error[0].modadd_assign(&E::one());
+        let mut correction = mul(&inverse, &error);
+        correction.resize(precision, E::zero());
+        inverse.resize(precision, E::zero());
+        inverse
+            .iter_mut()
+            .zip(correction.iter())
+            .for_each(|(c, d)| c.modadd_assign(d));
+    }
+
+    inverse
+}
+
+/// The remainder of lhs divided by the monic polynomial rhs, computed from the reversed quotient
+/// rev(q) = rev(lhs) / rev(rhs) mod x^(deg lhs - deg rhs + 1)
+fn rem<E: BinaryField>(lhs: &[E], rhs: &[E]) -> Vec<E> {
+    let degree = rhs.len() - 1;
+    if lhs.len() <= degree {
+        return lhs.to_vec();
+    }
+    let quot_len = lhs.len() - degree;
+    let rev_lhs = lhs.iter().rev().take(quot_len).copied().collect::<Vec<E>>();
+    let rev_rhs = rhs.iter().rev().copied().collect::<Vec<E>>();
+    let mut rev_quot = mul(&rev_lhs, &inverse_series(&rev_rhs, quot_len));
+    rev_quot.resize(quot_len, E::zero());
+    rev_quot.reverse();
+
+    let prod = mul(&rev_quot, rhs);
+    lhs[..degree]
+        .iter()
+        .zip(prod.iter())
+        .map(|(a, b)| a.modsub(b))
+        .collect()
+}
+
+/// Below this many points, `ProductTree` evaluates with Horner's rule instead of splitting further
+const PRODUCT_TREE_LEAF: usize = 8;
+
+/// The subproduct tree of a set of points: every node holds the product of (x - a) over the
+/// points a below it
+struct ProductTree<E: BinaryField> {
+    product: Vec<E>,
+    children: Option<Box<(ProductTree<E>, ProductTree<E>)>>,
+}
+
+impl<E: BinaryField> ProductTree<E> {
+    fn new(points: &[E]) -> Self {
+        if points.len() == 1 {
+            return Self {
+                product: vec![E::zero().modsub(&points[0]), E::one()],
+                children: None,
+            };
+        }
+        let (left, right) = points.split_at(points.len() / 2);
+        let (left, right) = (Self::new(left), Self::new(right));
+
+        Self {
+            product: mul(&left.product, &right.product),
+            children: Some(Box::new((left, right))),
+        }
+    }
+
+    /// Evaluate f at every point by reducing it down the tree
+    fn evaluate(&self, f: &[E], points: &[E], evals: &mut Vec<E>) {
+        let f = rem(f, &self.product);
+        match &self.children {
+            Some(children) if points.len() > PRODUCT_TREE_LEAF => {
+                let (left, right) = points.split_at(points.len() / 2);
+                children.0.evaluate(&f, left, evals);
+                children.1.evaluate(&f, right, evals);
+            }
+            _ => evals.extend(points.iter().map(|point| {
+                f.iter()
+                    .rev()
+                    .fold(E::zero(), |acc, coeff| acc.modmul(point).modadd(coeff))
+            })),
+        }
+    }
+
+    /// The sum of weights[i] * product / (x - a_i) over the points a_i below this node
+    fn combine(&self, weights: &[E]) -> Vec<E> {
+        match &self.children {
+            None => vec![weights[0]],
+            Some(children) => {
+                let (left, right) = weights.split_at(weights.len() / 2);
+                let lhs = mul(&children.0.combine(left), &children.1.product);
+                let rhs = mul(&children.1.combine(right), &children.0.product);
+                lhs.iter()
+                    .zip(rhs.iter())
+                    .map(|(a, b)| a.modadd(b))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Recover the coefficients (constant term first) of the polynomial of degree less than n that
+/// passes through the n input points (x, f(x)) in O(M(n) log n) operations, where M(n) is the cost
+/// of `mul`. With M(x) the product of (x - x_i), the Lagrange formula is
+/// f = sum(y_i / M'(x_i) * M(x) / (x - x_i)): the M'(x_i) are found by reducing M' down the
+/// subproduct tree and the sum is assembled back up the same tree.
+///
+/// The field must have characteristic 2. The x coordinates must be distinct, otherwise the output
+/// is meaningless.
+pub fn interpolate<E: BinaryField>(points: &[(E, E)]) -> Vec<E> {
+    if points.is_empty() {
+        return vec![];
+    }
+    let xs = points.iter().map(|(x, _)| *x).collect::<Vec<E>>();
+    let tree = ProductTree::new(&xs);
+    // In characteristic 2, i * m_i is m_i for odd i and 0 for even i
+    let derivative = tree
+        .product
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, coeff)| if i % 2 == 1 { *coeff } else { E::zero() })
+        .collect::<Vec<E>>();
+    let mut weights = Vec::with_capacity(points.len());
+    tree.evaluate(&derivative, &xs, &mut weights);
+    batch_inv(&mut weights);
+    weights
+        .iter_mut()
+        .zip(points.iter())
+        .for_each(|(weight, (_, y))| weight.modmul_assign(y));
+
+    let mut coeffs = tree.combine(&weights);
+    coeffs.resize(points.len(), E::zero());
+    coeffs
+}
+
+#[cfg(all(test, feature = "gf16", feature = "gf128"))]
+mod tests {
+    use super::*;
+    use crate::f2x::F2x;
+    use crate::galoisfields::{FieldArithmetic, GF2p128, GF2p16};
+    use crate::poly::PolyVec;
+    use rand::thread_rng;
+
+    #[test]
+    fn taylor_expansion() {
+        // x^3 = (x^2 + x) x + (x^2 + x) + x, so the pairs are (0, 1) and (1, 1)
+        let one = GF2p16::ONE;
+        let mut coeffs = [GF2p16::ZERO, GF2p16::ZERO, GF2p16::ZERO, one];
+        taylor_expand(&mut coeffs);
+        assert_eq!(coeffs, [GF2p16::ZERO, one, one, one]);
+    }
+
+    #[test]
+    fn random_additive_fft() {
+        let monomials = (0..6)
+            .map(|i| GF2p16::from_poly(F2x::from_exponents(&[i])))
+            .collect::<Vec<GF2p16>>();
+        let random_basis = (0..5)
+            .map(|_| GF2p128::random(&mut thread_rng()))
+            .collect::<Vec<GF2p128>>();
+        for len in [0, 1, 2, 3, 7, 32] {
+            let poly = PolyVec::from_coeffs(
+                (0..len)
+                    .map(|_| GF2p16::random(&mut thread_rng()))
+                    .collect(),
+            );
+            let evals = additive_fft(&poly.coeffs, &monomials);
+            assert_eq!(evals.len(), 64);
+            for (i, eval) in evals.iter().enumerate() {
+                let point = GF2p16::from_poly(F2x::from_u128(i as u128));
+                assert_eq!(span_point(&monomials, i), point);
+                assert_eq!(*eval, poly.evaluate(&point));
+            }
+
+            let poly = PolyVec::from_coeffs(
+                (0..len)
+                    .map(|_| GF2p128::random(&mut thread_rng()))
+                    .collect(),
+            );
+            let evals = additive_fft(&poly.coeffs, &random_basis);
+            for (i, eval) in evals.iter().enumerate() {
+                assert_eq!(*eval, poly.evaluate(&span_point(&random_basis, i)));
+            }
+        }
+    }
+
+    #[test]
+    fn random_inverse_additive_fft() {
+        let basis = (0..6)
+            .map(|_| GF2p128::random(&mut thread_rng()))
+            .collect::<Vec<GF2p128>>();
+        let coeffs = (0..64)
+            .map(|_| GF2p128::random(&mut thread_rng()))
+            .collect::<Vec<GF2p128>>();
+        let evals = additive_fft(&coeffs, &basis);
+        assert_eq!(inverse_additive_fft(&evals, &basis), coeffs);
+        assert_eq!(inverse_additive_fft(&[coeffs[0]], &[]), vec![coeffs[0]]);
+    }
+
+    #[test]
+    fn random_fft_mul() {
+        for (lhs_len, rhs_len) in [(0, 3), (5, 7), (40, 33), (100, 64)] {
+            let expected_len: usize = if lhs_len == 0 {
+                0
+            } else {
+                lhs_len + rhs_len - 1
+            };
+            let lhs = PolyVec::from_coeffs(
+                (0..lhs_len)
+                    .map(|_| GF2p16::random(&mut thread_rng()))
+                    .collect(),
+            );
+            let rhs = PolyVec::from_coeffs(
+                (0..rhs_len)
+                    .map(|_| GF2p16::random(&mut thread_rng()))
+                    .collect(),
+            );
+            let prod = mul(&lhs.coeffs, &rhs.coeffs);
+            assert_eq!(prod.len(), expected_len);
+            for _ in 0..4 {
+                let point = GF2p16::random(&mut thread_rng());
+                let eval = prod
+                    .iter()
+                    .rev()
+                    .fold(GF2p16::ZERO, |acc, c| acc.modmul(&point).modadd(c));
+                assert_eq!(eval, lhs.evaluate(&point).modmul(&rhs.evaluate(&point)));
+            }
+        }
+    }
+
+    #[test]
+    fn random_rem() {
+        let divisor = mul(
+            &[GF2p16::random(&mut thread_rng()), GF2p16::ONE],
+            &(0..40)
+                .map(|i| {
+                    if i == 39 {
+                        GF2p16::ONE
+                    } else {
+                        GF2p16::random(&mut thread_rng())
+                    }
+                })
+                .collect::<Vec<GF2p16>>(),
+        );
+        let quot = (0..50)
+            .map(|_| GF2p16::random(&mut thread_rng()))
+            .collect::<Vec<GF2p16>>();
+        let remainder = (0..40)
+            .map(|_| GF2p16::random(&mut thread_rng()))
+            .collect::<Vec<GF2p16>>();
+        let mut dividend = mul(&quot, &divisor);
+        dividend
+            .iter_mut()
+            .zip(remainder.iter())
+            .for_each(|(c, r)| c.modadd_assign(r));
+        assert_eq!(rem(&dividend, &divisor), remainder);
+    }
+
+    #[test]
+    fn random_fast_interpolation() {
+        for n in [1, 2, 9, 100] {
+            let poly =
+                PolyVec::from_coeffs((0..n).map(|_| GF2p128::random(&mut thread_rng())).collect());
+            let points = (0..n)
+                .map(|_| {
+                    let x = GF2p128::random(&mut thread_rng());
+                    (x, poly.evaluate(&x))
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(interpolate(&points), poly.coeffs);
+            if n <= 9 {
+                assert_eq!(PolyVec::interpolate(&points, n), poly);
+            }
+        }
+        assert!(interpolate::<GF2p128>(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn additive_fft_too_many_coefficients() {
+        additive_fft(&[GF2p16::ONE; 3], &[GF2p16::ONE]);
+    }
+
+    #[test]
+    #[cfg(feature = "binary-curves")]
+    #[should_panic]
+    fn monomial_basis_beyond_the_exponent() {
+        // GF(2^233) serializes into 240 bits, but only 233 of them are independent
+        monomial_basis::<crate::galoisfields::GF2p233>(234);
+    }
+}
@@ -68,7 +68,7 @@ fn handle_split(
     input: Option<String>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut input = open_file_or_stdin(input)?;
-    let outfile = File::create(&outpath.unwrap_or("./shamir.zip".to_string()))?;
+    let outfile = File::create(outpath.unwrap_or("./shamir.zip".to_string()))?;
     let mut archive = zip::ZipWriter::new(outfile);
     let archive_options: FileOptions<()> = FileOptions::default()
         .compression_method(zip::CompressionMethod::Deflated)
@@ -76,9 +76,8 @@ fn handle_split(
 
     let mut secret = String::new();
     input.read_to_string(&mut secret)?;
-    let mut secretsharing = SecretSharing256::init_with_rng(&mut OsRng, threshold);
-    secretsharing.encrypt(&secret.as_bytes())?;
-    secretsharing.safe_split(redundancy);
+    let mut secretsharing = SecretSharing256::split(&mut OsRng, threshold, redundancy)?;
+    secretsharing.encrypt(secret.as_bytes())?;
     let shares = secretsharing.stringify_shards()?;
     // println!("count of shares {}", shares.len());
 
@@ -91,7 +90,7 @@ fn handle_split(
             // TODO: add option for verbosity?
             // println!("{share_str}");
             archive.start_file(&filename, archive_options)?;
-            archive.write_all(&share_str.as_bytes())?;
+            archive.write_all(share_str.as_bytes())?;
 
             Ok::<(), Box<dyn Error>>(())
         })
@@ -114,7 +113,7 @@ fn handle_assemble(inputs: Vec<PathBuf>) -> Result<(), Box<dyn std::error::Error
         .collect::<Result<Vec<SecretShare>, Box<dyn Error>>>()?;
     let decryption = SecretSharing256::decrypt_from_secret_shares(&shares)?;
 
-    print!("{}", String::from_utf8(decryption)?);
+    print!("{}", std::str::from_utf8(decryption.expose())?);
 
     Ok(())
 }
@@ -0,0 +1,214 @@
+//! Bit-sliced arithmetic over small binary fields
+//!
+//! A batch of 64 independent elements of GF(2^m) is stored as m 64-bit words ("bit planes"), where
+//! bit j of plane i is the coefficient of x^i in the j-th element. Addition is then one XOR per
+//! plane and multiplication is a school book product of the planes with AND and XOR, so every
+//! instruction operates on all 64 elements at once. This suits splitting long secrets byte by
+//! byte, where the same polynomial evaluation is repeated for every byte.
+#[cfg(any(feature = "gf8", feature = "gf16"))]
+use crate::f2x::F2x;
+use crate::f2x::Word;
+use crate::galoisfields::FieldArithmetic;
+#[cfg(feature = "gf16")]
+use crate::galoisfields::GF2p16;
+#[cfg(feature = "gf8")]
+use crate::galoisfields::GF2p8;
+use core::marker::PhantomData;
+
+/// The number of elements in each batch
+pub const LANES: usize = u64::BITS as usize;
+
+/// The largest exponent m supported by bit slicing
+pub const MAX_BITS: usize = Word::BITS as usize;
+
+/// A binary field whose elements fit into a single limb and can be bit-sliced
+pub trait BitSliceField: FieldArithmetic {
+    /// The exponent m of GF(2^m)
+    const BITS: usize;
+
+    /// The powers of the non-leading terms of the modulus
+    const EXPONENTS: &'static [usize];
+
+    /// The polynomial representation of self as a single word
+    fn to_word(&self) -> Word;
+
+    /// Convert a reduced polynomial back into a field element
+    fn from_word(word: Word) -> Self;
+}
+
+#[cfg(feature = "gf8")]
+impl BitSliceField for GF2p8 {
+    const BITS: usize = GF2p8::BITS;
+    const EXPONENTS: &'static [usize] = &[4, 3, 1, 0];
+
+    fn to_word(&self) -> Word {
+        *self.poly.get_limb(0).unwrap()
+    }
+
+    fn from_word(word: Word) -> Self {
+        Self::from_poly(F2x::from_limbs([word]))
+    }
+}
+
+#[cfg(feature = "gf16")]
+impl BitSliceField for GF2p16 {
+    const BITS: usize = GF2p16::BITS;
+    const EXPONENTS: &'static [usize] = &[5, 3, 1, 0];
+
+    fn to_word(&self) -> Word {
+        *self.poly.get_limb(0).unwrap()
+    }
+
+    fn from_word(word: Word) -> Self {
+        Self::from_poly(F2x::from_limbs([word]))
+    }
+}
+
+/// A batch of `LANES` elements of F in bit-sliced form. Only the first F::BITS planes are used.
+#[cfg_attr(feature = "debug-secrets", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct BitSliced<F: BitSliceField> {
+    planes: [u64; MAX_BITS],
+    _field: PhantomData<F>,
+}
+
+#[cfg(not(feature = "debug-secrets"))]
+impl<F: BitSliceField> core::fmt::Debug for BitSliced<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BitSliced")
+            .field("planes", &format_args!("[REDACTED; {}]", F::BITS))
+            .finish()
+    }
+}
+
+impl<F: BitSliceField> BitSliced<F> {
+    pub const ZERO: Self = Self::from_planes([0; MAX_BITS]);
+
+    const fn from_planes(planes: [u64; MAX_BITS]) -> Self {
+        Self {
+            planes,
+            _field: PhantomData,
+        }
+    }
+
+    /// Transpose up to `LANES` elements into bit planes; missing lanes are filled with zeros. Will
+    /// panic if there are too many elements
+    pub fn pack(elems: &[F]) -> Self {
+        if elems.len() > LANES {
+            panic!("too many elements for one batch");
+        }
+        let mut planes = [0; MAX_BITS];
+        for (lane, elem) in elems.iter().enumerate() {
+            let word = elem.to_word();
+            for (i, plane) in planes.iter_mut().enumerate().take(F::BITS) {
+                *plane |= (((word >> i) & 1) as u64) << lane;
+            }
+        }
+
+        Self::from_planes(planes)
+    }
+
+    /// Copy the same element into every lane
+    pub fn splat(elem: &F) -> Self {
+        let word = elem.to_word();
+        let mut planes = [0; MAX_BITS];
+        for (i, plane) in planes.iter_mut().enumerate().take(F::BITS) {
+            *plane = ((word >> i) & 1) as u64 * u64::MAX;
+        }
+
+        Self::from_planes(planes)
+    }
+
+    /// Transpose the bit planes back into `LANES` elements
+    pub fn unpack(&self) -> [F; LANES] {
+        core::array::from_fn(|lane| {
+            let word = (0..F::BITS).fold(0, |acc, i| {
+                acc | ((((self.planes[i] >> lane) & 1) as Word) << i)
+            });
+            F::from_word(word)
+        })
+    }
+
+    /// Lane-wise addition
+    pub fn add(&self, rhs: &Self) -> Self {
+        let mut planes = self.planes;
+        planes
+            .iter_mut()
+            .zip(rhs.planes.iter())
+            .for_each(|(plane, other)| *plane ^= other);
+
+        Self::from_planes(planes)
+    }
+
+    /// Lane-wise multiplication: school book multiplication of the planes followed by folding the
+    /// high planes with the sparse modulus
+    pub fn mul(&self, rhs: &Self) -> Self {
+        let mut prod = [0u64; 2 * MAX_BITS - 1];
+        for i in 0..F::BITS {
+            for j in 0..F::BITS {
+                prod[i + j] ^= self.planes[i] & rhs.planes[j];
+            }
+        }
+        // x^m is congruent to the sum of x^e, so x^k folds onto x^(k - m + e)
+        for k in (F::BITS..(2 * F::BITS - 1)).rev() {
+            for exp in F::EXPONENTS {
+                prod[k - F::BITS + exp] ^= prod[k];
+            }
+        }
+        let mut planes = [0; MAX_BITS];
+        planes[..F::BITS].copy_from_slice(&prod[..F::BITS]);
+
+        Self::from_planes(planes)
+    }
+}
+
+#[cfg(all(test, feature = "gf8"))]
+mod tests {
+    use super::*;
+    #[cfg(feature = "gf16")]
+    use rand::thread_rng;
+
+    #[test]
+    #[cfg(feature = "gf16")]
+    fn random_bitsliced_arithmetic() {
+        let lhs = (0..LANES)
+            .map(|_| GF2p16::random(&mut thread_rng()))
+            .collect::<Vec<_>>();
+        let rhs = (0..LANES)
+            .map(|_| GF2p16::random(&mut thread_rng()))
+            .collect::<Vec<_>>();
+        let (sliced_lhs, sliced_rhs) = (BitSliced::pack(&lhs), BitSliced::pack(&rhs));
+        assert_eq!(sliced_lhs.unpack().to_vec(), lhs);
+        let prod = sliced_lhs.mul(&sliced_rhs).unpack();
+        let sum = sliced_lhs.add(&sliced_rhs).unpack();
+        for lane in 0..LANES {
+            assert_eq!(prod[lane], lhs[lane].modmul(&rhs[lane]));
+            assert_eq!(sum[lane], lhs[lane].modadd(&rhs[lane]));
+        }
+
+        let lhs = (0..LANES)
+            .map(|_| GF2p8::random(&mut thread_rng()))
+            .collect::<Vec<_>>();
+        let scalar = GF2p8::random(&mut thread_rng());
+        let prod = BitSliced::pack(&lhs)
+            .mul(&BitSliced::splat(&scalar))
+            .unpack();
+        for lane in 0..LANES {
+            assert_eq!(prod[lane], lhs[lane].modmul(&scalar));
+        }
+        // 0x53 and 0xCA are inverses in the AES field
+        let prod = BitSliced::splat(&GF2p8::from_word(0x53))
+            .mul(&BitSliced::splat(&GF2p8::from_word(0xCA)))
+            .unpack();
+        assert!(prod.iter().all(|elem| elem.is_one()));
+    }
+
+    #[test]
+    fn bitsliced_partial_batch() {
+        let elems = [GF2p8::ONE; 3];
+        let unpacked = BitSliced::pack(&elems).unpack();
+        assert_eq!(unpacked[..3], elems);
+        assert!(unpacked[3..].iter().all(|elem| elem.is_zero()));
+        assert_eq!(BitSliced::<GF2p8>::ZERO.unpack(), [GF2p8::ZERO; LANES]);
+    }
+}
@@ -0,0 +1,288 @@
+//! Statistical timing-leak detection in the style of [dudect](https://eprint.iacr.org/2016/1123)
+//!
+//! An operation is timed on two classes of inputs: a fixed input and freshly sampled random
+//! inputs. The class of each measurement is chosen at random and all inputs are prepared before
+//! any timing starts. If the operation runs in constant time, the two timing distributions are the
+//! same, so Welch's t-statistic between them stays small; a statistic well above `T_THRESHOLD` is
+//! strong evidence of a timing leak. The measurements are noisy, so the harness is best run in
+//! release mode on an otherwise idle machine.
+use crate::galoisfields::FieldArithmetic;
+#[cfg(feature = "gf256")]
+use crate::galoisfields::GF2p256;
+#[cfg(feature = "gf256")]
+use crate::poly::Poly256;
+use core::fmt::Display;
+use core::hint::black_box;
+use rand::Rng;
+use rand_core::CryptoRngCore;
+use std::time::Instant;
+
+/// The t-statistic above which the timings are considered to depend on the input class
+pub const T_THRESHOLD: f64 = 4.5;
+
+/// Measurements slower than this quantile are discarded, since they are usually caused by
+/// interrupts and context switches rather than by the operation under test
+pub const CROP_QUANTILE: f64 = 0.9;
+
+/// The class of inputs a measurement was taken with
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Class {
+    Fixed,
+    Random,
+}
+
+/// Running mean and variance of a stream of samples with Welford's algorithm
+#[derive(Debug, Default, Copy, Clone)]
+struct Moments {
+    count: f64,
+    mean: f64,
+    /// The sum of squared differences from the mean
+    m2: f64,
+}
+
+impl Moments {
+    fn push(&mut self, sample: f64) {
+        self.count += 1.0;
+        let delta = sample - self.mean;
+        self.mean += delta / self.count;
+        self.m2 += delta * (sample - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        if self.count < 2.0 {
+            return 0.0;
+        }
+        self.m2 / (self.count - 1.0)
+    }
+}
+
+/// Welch's t-test between the timings of the fixed class and the random class
+#[derive(Debug, Default, Copy, Clone)]
+pub struct TimingTest {
+    fixed: Moments,
+    random: Moments,
+}
+
+impl TimingTest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the duration of one measurement, in nanoseconds
+    pub fn push(&mut self, class: Class, elapsed: f64) {
+        match class {
+            Class::Fixed => self.fixed.push(elapsed),
+            Class::Random => self.random.push(elapsed),
+        }
+    }
+
+    /// The number of measurements recorded for each class
+    pub fn samples(&self) -> (usize, usize) {
+        (self.fixed.count as usize, self.random.count as usize)
+    }
+
+    /// Welch's t-statistic, or 0 if either class has fewer than two measurements
+    pub fn t_statistic(&self) -> f64 {
+        if self.fixed.count < 2.0 || self.random.count < 2.0 {
+            return 0.0;
+        }
+        let stderr = (self.fixed.variance() / self.fixed.count
+            + self.random.variance() / self.random.count)
+            .sqrt();
+        if stderr == 0.0 {
+            return 0.0;
+        }
+        (self.fixed.mean - self.random.mean) / stderr
+    }
+
+    /// True if the magnitude of the t-statistic exceeds `T_THRESHOLD`
+    pub fn is_leaking(&self) -> bool {
+        self.t_statistic().abs() > T_THRESHOLD
+    }
+}
+
+impl Display for TimingTest {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (fixed, random) = self.samples();
+        write!(
+            f,
+            "fixed: {fixed} samples, mean {:.1}ns; random: {random} samples, mean {:.1}ns; t = {:.2}",
+            self.fixed.mean,
+            self.random.mean,
+            self.t_statistic()
+        )
+    }
+}
+
+/// Time `op` on `samples` inputs, each of which is a clone of `fixed` or drawn from `random` with
+/// equal probability, then run Welch's t-test on the cropped measurements
+pub fn measure<T: Clone, R: CryptoRngCore>(
+    samples: usize,
+    rng: &mut R,
+    fixed: &T,
+    mut random: impl FnMut(&mut R) -> T,
+    mut op: impl FnMut(&T),
+) -> TimingTest {
+    let inputs = (0..samples)
+        .map(|_| {
+            if rng.gen::<bool>() {
+                (Class::Fixed, fixed.clone())
+            } else {
+                (Class::Random, random(rng))
+            }
+        })
+        .collect::<Vec<(Class, T)>>();
+    let timings = inputs
+        .iter()
+        .map(|(class, input)| {
+            let start = Instant::now();
+            op(black_box(input));
+            (*class, start.elapsed().as_nanos() as f64)
+        })
+        .collect::<Vec<(Class, f64)>>();
+
+    let mut sorted = timings
+        .iter()
+        .map(|(_, elapsed)| *elapsed)
+        .collect::<Vec<f64>>();
+    sorted.sort_by(f64::total_cmp);
+    let cutoff = sorted
+        .get((CROP_QUANTILE * samples as f64) as usize)
+        .copied()
+        .unwrap_or(f64::INFINITY);
+
+    let mut test = TimingTest::new();
+    timings
+        .iter()
+        .filter(|(_, elapsed)| *elapsed <= cutoff)
+        .for_each(|(class, elapsed)| test.push(*class, *elapsed));
+    test
+}
+
+/// Multiplication by a fixed random element against multiplication by fresh random elements
+pub fn mul_timing<F: FieldArithmetic>(samples: usize, rng: &mut impl CryptoRngCore) -> TimingTest {
+    let rhs = F::random(rng);
+    let fixed = F::random(rng);
+    measure(samples, rng, &fixed, F::random, |lhs| {
+        black_box(lhs.modmul(&rhs));
+    })
+}
+
+/// Inversion of the multiplicative identity against inversion of random elements
+pub fn modinv_timing<F: FieldArithmetic>(
+    samples: usize,
+    rng: &mut impl CryptoRngCore,
+) -> TimingTest {
+    measure(samples, rng, &F::one(), F::random, |elem| {
+        black_box(elem.modinv());
+    })
+}
+
+/// Reconstruction of a secret polynomial from `threshold` shares at fixed points, where the
+/// evaluations are either fixed or random
+#[cfg(feature = "gf256")]
+pub fn reconstruction_timing(
+    threshold: usize,
+    samples: usize,
+    rng: &mut impl CryptoRngCore,
+) -> TimingTest {
+    let xs = (0..threshold)
+        .map(|_| GF2p256::random(rng))
+        .collect::<Vec<GF2p256>>();
+    let shares =
+        |fxs: Vec<GF2p256>| -> Vec<(GF2p256, GF2p256)> { xs.iter().copied().zip(fxs).collect() };
+    let fixed = shares(vec![GF2p256::ONE; threshold]);
+    measure(
+        samples,
+        rng,
+        &fixed,
+        |rng| shares((0..threshold).map(|_| GF2p256::random(rng)).collect()),
+        |points| {
+            black_box(Poly256::interpolate(points, threshold));
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "gf128")]
+    use crate::galoisfields::GF2p128;
+
+    mod itoh_tsujii_field {
+        crate::galois_field!(
+            GF2p128ItohTsujii,
+            128,
+            [77, 35, 11, 0],
+            crate::galoisfields::Inversion::ItohTsujii
+        );
+    }
+    use itoh_tsujii_field::GF2p128ItohTsujii;
+
+    /// The number of measurements taken by the timing tests
+    const SAMPLES: usize = 100_000;
+
+    #[test]
+    fn welch_t_statistic() {
+        let mut test = TimingTest::new();
+        for elapsed in [1.0, 2.0, 3.0, 4.0] {
+            test.push(Class::Fixed, elapsed);
+            test.push(Class::Random, elapsed);
+        }
+        assert_eq!(test.t_statistic(), 0.0);
+        assert_eq!(test.samples(), (4, 4));
+
+        // means 2.5 and 12.5, variances 5/3 each: t = -10 / sqrt(5/6)
+        let mut shifted = TimingTest::new();
+        for elapsed in [1.0, 2.0, 3.0, 4.0] {
+            shifted.push(Class::Fixed, elapsed);
+            shifted.push(Class::Random, elapsed + 10.0);
+        }
+        let expected = -10.0 / (5.0f64 / 6.0).sqrt();
+        assert!((shifted.t_statistic() - expected).abs() < 1e-9);
+        assert!(shifted.is_leaking());
+    }
+
+    #[test]
+    fn measure_detects_leak() {
+        let mut rng = rand::thread_rng();
+        // Sleeping only for the fixed class is a leak large enough to detect with few samples
+        let test = measure(
+            200,
+            &mut rng,
+            &true,
+            |_| false,
+            |slow| {
+                if *slow {
+                    std::thread::sleep(std::time::Duration::from_micros(200));
+                }
+            },
+        );
+        assert!(test.is_leaking(), "{test}");
+    }
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    #[ignore = "timing measurement; run in release mode with --ignored"]
+    fn mul_is_constant_time() {
+        let test = mul_timing::<GF2p128>(SAMPLES, &mut rand::thread_rng());
+        println!("GF2p128 modmul: {test}");
+        assert!(!test.is_leaking(), "{test}");
+    }
+
+    #[test]
+    #[ignore = "timing measurement; run in release mode with --ignored"]
+    fn itoh_tsujii_modinv_is_constant_time() {
+        let test = modinv_timing::<GF2p128ItohTsujii>(SAMPLES, &mut rand::thread_rng());
+        println!("GF2p128 Itoh-Tsujii modinv: {test}");
+        assert!(!test.is_leaking(), "{test}");
+    }
+
+    #[test]
+    #[cfg(feature = "gf256")]
+    #[ignore = "timing measurement; run in release mode with --ignored"]
+    fn reconstruction_timing_report() {
+        let test = reconstruction_timing(5, SAMPLES / 10, &mut rand::thread_rng());
+        println!("Poly256 interpolation of 5 shares: {test}");
+    }
+}
@@ -0,0 +1,146 @@
+//! A source of randomness that does not depend on any one version of rand_core
+//!
+//! The sampling functions of this crate are written against rand_core 0.6. Applications on a
+//! different major version of rand_core cannot pass their generators to those functions, because
+//! the traits of different versions are unrelated types. `EntropySource` is the one method the
+//! crate actually needs from a generator: any generator, from any version of rand_core, can
+//! implement it with a single `fill_bytes` call, and `FieldArithmetic::random_from_entropy`
+//! accepts it.
+//!
+//! `RandCore06` adapts a rand_core 0.6 generator, and `RandCore09` a rand_core 0.9 generator
+//! behind the `rand-core-09` feature; `EntropyRng` goes the other way and presents an
+//! `EntropySource` as a rand_core 0.6 generator to the rest of the crate.
+use rand_core::{CryptoRng, CryptoRngCore, RngCore};
+
+/// A cryptographically secure source of random bytes. Implementors must fill the whole
+/// destination; a source that can fail should panic rather than return partially filled bytes.
+pub trait EntropySource {
+    fn fill_entropy(&mut self, dest: &mut [u8]);
+}
+
+impl<S: EntropySource + ?Sized> EntropySource for &mut S {
+    fn fill_entropy(&mut self, dest: &mut [u8]) {
+        (**self).fill_entropy(dest)
+    }
+}
+
+/// Adapts a rand_core 0.6 generator into an `EntropySource`
+#[derive(Debug, Default, Clone)]
+pub struct RandCore06<R>(pub R);
+
+impl<R: CryptoRngCore> EntropySource for RandCore06<R> {
+    fn fill_entropy(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
+
+/// Adapts a rand_core 0.9 generator into an `EntropySource`
+#[cfg(feature = "rand-core-09")]
+#[derive(Debug, Default, Clone)]
+pub struct RandCore09<R>(pub R);
+
+#[cfg(feature = "rand-core-09")]
+impl<R: rand_core_09::CryptoRng> EntropySource for RandCore09<R> {
+    fn fill_entropy(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
+
+/// Presents an `EntropySource` as a rand_core 0.6 generator, so that it can be passed to the
+/// sampling functions of this crate
+pub struct EntropyRng<'a, S: ?Sized>(pub &'a mut S);
+
+impl<S: EntropySource + ?Sized> RngCore for EntropyRng<'_, S> {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0u8; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0u8; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_entropy(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl<S: EntropySource + ?Sized> CryptoRng for EntropyRng<'_, S> {}
+
+#[cfg(all(test, feature = "gf256"))]
+mod tests {
+    use super::*;
+    use crate::galoisfields::{FieldArithmetic, GF2p256};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// A source that does not come from rand_core at all
+    struct Counter(u8);
+
+    impl EntropySource for Counter {
+        fn fill_entropy(&mut self, dest: &mut [u8]) {
+            for byte in dest.iter_mut() {
+                *byte = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+    }
+
+    #[test]
+    fn adapters_round_trip() {
+        let mut direct = StdRng::seed_from_u64(0);
+        let mut adapted = RandCore06(StdRng::seed_from_u64(0));
+        assert_eq!(
+            GF2p256::random(&mut direct),
+            GF2p256::random_from_entropy(&mut adapted)
+        );
+
+        let mut counter = Counter(0);
+        let mut rng = EntropyRng(&mut counter);
+        assert_eq!(rng.next_u32(), u32::from_le_bytes([0, 1, 2, 3]));
+        assert_eq!(
+            rng.next_u64(),
+            u64::from_le_bytes([4, 5, 6, 7, 8, 9, 10, 11])
+        );
+        let elem = GF2p256::random_from_entropy(&mut counter);
+        assert_ne!(elem, GF2p256::random_from_entropy(&mut counter));
+    }
+
+    #[test]
+    #[cfg(feature = "rand-core-09")]
+    fn rand_core_09_adapter() {
+        /// The same counter, as a rand_core 0.9 generator
+        struct Counter09(u8);
+
+        impl rand_core_09::RngCore for Counter09 {
+            fn next_u32(&mut self) -> u32 {
+                rand_core_09::impls::next_u32_via_fill(self)
+            }
+            fn next_u64(&mut self) -> u64 {
+                rand_core_09::impls::next_u64_via_fill(self)
+            }
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                Counter(self.0).fill_entropy(dest);
+                self.0 = self.0.wrapping_add(dest.len() as u8);
+            }
+        }
+
+        impl rand_core_09::CryptoRng for Counter09 {}
+
+        let mut adapted = RandCore09(Counter09(7));
+        let mut counter = Counter(7);
+        for _ in 0..3 {
+            assert_eq!(
+                GF2p256::random_from_entropy(&mut adapted),
+                GF2p256::random_from_entropy(&mut counter)
+            );
+        }
+    }
+}
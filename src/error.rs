@@ -0,0 +1,71 @@
+//! The error type of the sharing and parsing APIs
+
+pub type SssResult<T> = Result<T, SssError>;
+
+#[derive(Debug)]
+pub enum SssError {
+    /// The threshold is not usable with this many shares
+    InvalidThreshold { threshold: usize, shares: usize },
+    /// More shares were requested than there are distinct non-zero x-coordinates
+    TooManyShares { shares: usize },
+    /// A share has the x-coordinate zero, where the polynomial evaluates to its constant term
+    ZeroShareIndex,
+    /// Two shares have the same x-coordinate
+    DuplicateShareIndex,
+    /// Fewer shares than the threshold were supplied
+    NotEnoughShares { expect: usize, has: usize },
+    /// An encoded field element is not the canonical encoding of any element
+    NonCanonicalElement,
+    /// Zero has no multiplicative inverse
+    NotInvertible,
+    /// Some encoded input is malformed; `what` names the offending part
+    FormatError { what: &'static str },
+    /// The shares disagree on the threshold, nonce, or ciphertext
+    InconsistentShares,
+    /// The secret to be shared is empty
+    EmptySecret,
+    /// There is nothing to share because nothing has been encrypted yet
+    NoCiphertext,
+    /// Encrypting or decrypting the secret with AES-GCM failed
+    #[cfg(feature = "std")]
+    AesGcmError(aes_gcm::Error),
+    /// The random number generator failed a continuous health test while dealing
+    UnhealthyRng,
+}
+
+impl core::fmt::Display for SssError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidThreshold { threshold, shares } => {
+                write!(f, "threshold {threshold} is invalid with {shares} shares")
+            }
+            Self::TooManyShares { shares } => {
+                write!(
+                    f,
+                    "{shares} shares exceed the number of distinct x-coordinates"
+                )
+            }
+            Self::ZeroShareIndex => write!(f, "a share has the x-coordinate zero"),
+            Self::DuplicateShareIndex => write!(f, "two shares have the same x-coordinate"),
+            Self::NotEnoughShares { expect, has } => {
+                write!(f, "expect at least {expect} shares, found {has}")
+            }
+            Self::NonCanonicalElement => write!(f, "field element encoding is not canonical"),
+            Self::NotInvertible => write!(f, "zero is not invertible"),
+            Self::FormatError { what } => write!(f, "malformed {what}"),
+            Self::InconsistentShares => {
+                write!(f, "shares disagree on threshold, nonce, or ciphertext")
+            }
+            Self::EmptySecret => write!(f, "the secret is empty"),
+            Self::NoCiphertext => write!(f, "no ciphertext"),
+            #[cfg(feature = "std")]
+            Self::AesGcmError(_) => write!(f, "AES-GCM encryption or decryption failed"),
+            Self::UnhealthyRng => write!(
+                f,
+                "the random number generator failed a continuous health test"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for SssError {}
@@ -1,25 +1,464 @@
 //! The polynomial ring over F2[x], efficiently encoded in an integer array
 use core::fmt::UpperHex;
+use rand_core::CryptoRngCore;
 
+/// The limb type. 16-bit limbs are the default, which suits 8-bit and 16-bit microcontrollers;
+/// the `word-u32` and `word-u64` features select wider limbs for 32-bit and 64-bit targets. If
+/// both features are enabled, the wider limb wins.
+#[cfg(not(any(feature = "word-u32", feature = "word-u64")))]
 pub type Word = u16;
+#[cfg(all(feature = "word-u32", not(feature = "word-u64")))]
+pub type Word = u32;
+#[cfg(feature = "word-u64")]
+pub type Word = u64;
+
+/// An unsigned integer twice as wide as `Word`
+#[cfg(not(any(feature = "word-u32", feature = "word-u64")))]
+type DoubleWord = u32;
+#[cfg(all(feature = "word-u32", not(feature = "word-u64")))]
+type DoubleWord = u64;
+#[cfg(feature = "word-u64")]
+type DoubleWord = u128;
+
+/// The number of bytes in each limb
+const WORD_BYTES: usize = (Word::BITS / 8) as usize;
 
 /// Carryless multiplciation of words
 /// e.g. mul(0b1111, 0b1111) = 15 * 15 = 225 = 0b11100001
 ///     clmul(0b1111, 0b1111) = 0b1010101
-/// TODO: this is not constant time!
+#[cfg(any(
+    all(target_arch = "x86_64", target_feature = "pclmulqdq"),
+    all(
+        target_arch = "aarch64",
+        target_feature = "neon",
+        target_feature = "aes"
+    )
+))]
 pub fn widening_clmul(a: Word, b: Word) -> (Word, Word) {
+    let (high, low) = widening_clmul64(a as u64, b as u64);
+    let prod = ((high as u128) << u64::BITS) | low as u128;
+    ((prod >> Word::BITS) as Word, prod as Word)
+}
+
+/// Carryless multiplciation of words
+/// e.g. mul(0b1111, 0b1111) = 15 * 15 = 225 = 0b11100001
+///     clmul(0b1111, 0b1111) = 0b1010101
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "pclmulqdq"),
+    all(
+        target_arch = "aarch64",
+        target_feature = "neon",
+        target_feature = "aes"
+    )
+)))]
+pub fn widening_clmul(a: Word, b: Word) -> (Word, Word) {
+    portable_widening_clmul(a, b)
+}
+
+/// Bit-serial carryless multiplication used when no carry-less multiply instruction is available.
+/// Every bit of b selects its shifted copy of a through a mask instead of a branch, so the
+/// sequence of operations does not depend on the operands.
+#[cfg_attr(
+    any(
+        all(target_arch = "x86_64", target_feature = "pclmulqdq"),
+        all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            target_feature = "aes"
+        )
+    ),
+    allow(dead_code)
+)]
+fn portable_widening_clmul(a: Word, b: Word) -> (Word, Word) {
     let mut prod: (Word, Word) = (0, 0);
 
     for i in 0..(Word::BITS) {
-        if ((1 << i) & b) != 0 {
-            // Need to "widening left shift" a by i positions
-            let high_bits = a.checked_shr(Word::BITS - i).unwrap_or(0);
-            let low_bits = a.checked_shl(i).unwrap_or(0);
-            prod = (prod.0 ^ high_bits, prod.1 ^ low_bits);
+        // All ones if the i-th bit of b is set, otherwise all zeros
+        let mask = ((b >> i) & 1).wrapping_neg();
+        // "Widening left shift" a by i positions; the high bits are shifted in two steps so that
+        // the shift amount stays below Word::BITS when i = 0
+        let high_bits = (a >> 1) >> (Word::BITS - 1 - i);
+        let low_bits = a << i;
+        prod = (prod.0 ^ (high_bits & mask), prod.1 ^ (low_bits & mask));
+    }
+
+    prod
+}
+
+/// Carryless multiplication of 64-bit words using PCLMULQDQ, returning (high, low)
+#[cfg(all(target_arch = "x86_64", target_feature = "pclmulqdq"))]
+fn widening_clmul64(a: u64, b: u64) -> (u64, u64) {
+    use core::arch::x86_64::{
+        _mm_clmulepi64_si128, _mm_cvtsi128_si64, _mm_cvtsi64_si128, _mm_unpackhi_epi64,
+    };
+
+    // SAFETY: the required target features are enabled at compile time
+    unsafe {
+        let prod =
+            _mm_clmulepi64_si128(_mm_cvtsi64_si128(a as i64), _mm_cvtsi64_si128(b as i64), 0);
+        let high = _mm_cvtsi128_si64(_mm_unpackhi_epi64(prod, prod));
+        let low = _mm_cvtsi128_si64(prod);
+        (high as u64, low as u64)
+    }
+}
+
+/// Carryless multiplication of 64-bit words using PMULL, returning (high, low)
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    target_feature = "aes"
+))]
+fn widening_clmul64(a: u64, b: u64) -> (u64, u64) {
+    use core::arch::aarch64::vmull_p64;
+
+    // SAFETY: the required target features are enabled at compile time
+    let prod = unsafe { vmull_p64(a, b) };
+    ((prod >> u64::BITS) as u64, prod as u64)
+}
+
+/// Carryless multiplication of 64-bit words, returning (high, low). Like `portable_widening_clmul`,
+/// the bits of b are applied as masks rather than branches
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "pclmulqdq"),
+    all(
+        target_arch = "aarch64",
+        target_feature = "neon",
+        target_feature = "aes"
+    )
+)))]
+fn widening_clmul64(a: u64, b: u64) -> (u64, u64) {
+    let prod = (0..u64::BITS).fold(0u128, |acc, i| {
+        let mask = (((b >> i) & 1) as u128).wrapping_neg();
+        acc ^ (((a as u128) << i) & mask)
+    });
+    ((prod >> u64::BITS) as u64, prod as u64)
+}
+
+/// True if `widening_clmul64` is backed by a carry-less multiply instruction (PCLMULQDQ on
+/// x86_64, PMULL on aarch64) selected through the target features at compile time
+const HARDWARE_CLMUL: bool = cfg!(any(
+    all(target_arch = "x86_64", target_feature = "pclmulqdq"),
+    all(
+        target_arch = "aarch64",
+        target_feature = "neon",
+        target_feature = "aes"
+    )
+));
+
+/// The number of limbs packed into each 64-bit operand of `widening_clmul64`
+const LIMBS_PER_U64: usize = (u64::BITS / Word::BITS) as usize;
+
+/// Interleave the bits of a word with zeros, returning (high, low). This squares the word as a
+/// polynomial over GF(2), since all cross terms cancel out.
+pub fn spread_bits(a: Word) -> (Word, Word) {
+    let mut spread = a as DoubleWord;
+    let mut shift = Word::BITS / 2;
+    while shift > 0 {
+        // Alternating runs of shift zeros and shift ones, e.g. 0x00FF_00FF for shift = 8
+        let mask = DoubleWord::MAX / ((1 << shift) + 1);
+        spread = (spread | (spread << shift)) & mask;
+        shift /= 2;
+    }
+    ((spread >> Word::BITS) as Word, spread as Word)
+}
+
+/// The smallest number of limbs for which `karatsuba_mul` splits the operands instead of falling
+/// back to school book multiplication
+pub const KARATSUBA_THRESHOLD: usize = 8;
+
+/// School book multiplication of two little-endian limb slices of the same length, writing the
+/// little-endian product into prod, which must have twice as many limbs
+fn schoolbook_mul_limbs(a: &[Word], b: &[Word], prod: &mut [Word]) {
+    prod.fill(0);
+    for (i, a_limb) in a.iter().enumerate() {
+        for (j, b_limb) in b.iter().enumerate() {
+            let (high, low) = widening_clmul(*a_limb, *b_limb);
+            prod[i + j] ^= low;
+            prod[i + j + 1] ^= high;
+        }
+    }
+}
+
+/// The smallest number of limbs for which `mul_limbs` uses the 3-way Toom split instead of
+/// Karatsuba multiplication
+pub const TOOM3_THRESHOLD: usize = 24;
+
+/// Multiplying operands of n limbs with `mul_limbs` never needs more than this many times n limbs
+/// of scratch space, so that callers can reserve it on the stack. See `mul_scratch_len`
+const MUL_SCRATCH_FACTOR: usize = 12;
+
+/// The number of scratch limbs that `mul_limbs` uses for operands of n limbs. Karatsuba needs the
+/// two half-size sums and their product on top of the scratch of one half-size multiplication;
+/// Toom-3 needs the three evaluations of each operand and the five products on top of the scratch
+/// of one third-size multiplication
+const fn mul_scratch_len(n: usize) -> usize {
+    if n < KARATSUBA_THRESHOLD {
+        0
+    } else if n < TOOM3_THRESHOLD {
+        let m = n - n / 2;
+        4 * m + mul_scratch_len(m)
+    } else {
+        let k = n.div_ceil(3) + 1;
+        16 * k + mul_scratch_len(k)
+    }
+}
+
+/// Multiply two little-endian limb slices of the same length into prod, which must have twice as
+/// many limbs, choosing school book, Karatsuba, or Toom-3 multiplication by the number of limbs.
+/// Intermediate values are kept in scratch, which must have at least `mul_scratch_len` limbs
+fn mul_limbs(a: &[Word], b: &[Word], prod: &mut [Word], scratch: &mut [Word]) {
+    match a.len() {
+        n if n < KARATSUBA_THRESHOLD => schoolbook_mul_limbs(a, b, prod),
+        n if n < TOOM3_THRESHOLD => karatsuba_mul(a, b, prod, scratch),
+        _ => toom3_mul(a, b, prod, scratch),
+    }
+}
+
+/// The number of limbs in each 128-bit vector register
+#[cfg_attr(not(feature = "simd"), allow(dead_code))]
+const LIMBS_PER_VECTOR: usize = 128 / Word::BITS as usize;
+
+/// XOR src into dst limb by limb; both slices must have the same length
+#[cfg(not(all(
+    feature = "simd",
+    any(
+        all(target_arch = "x86_64", target_feature = "sse2"),
+        all(target_arch = "aarch64", target_feature = "neon")
+    )
+)))]
+fn xor_limbs(dst: &mut [Word], src: &[Word]) {
+    dst.iter_mut()
+        .zip(src)
+        .for_each(|(limb, other)| *limb ^= other);
+}
+
+/// XOR src into dst limb by limb; both slices must have the same length. Whole 128-bit chunks are
+/// XORed with SSE2 and the remaining limbs one at a time
+#[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "sse2"))]
+fn xor_limbs(dst: &mut [Word], src: &[Word]) {
+    use core::arch::x86_64::{__m128i, _mm_loadu_si128, _mm_storeu_si128, _mm_xor_si128};
+
+    let mut dst_chunks = dst.chunks_exact_mut(LIMBS_PER_VECTOR);
+    let mut src_chunks = src.chunks_exact(LIMBS_PER_VECTOR);
+    for (dst_chunk, src_chunk) in (&mut dst_chunks).zip(&mut src_chunks) {
+        // SAFETY: both chunks are exactly 128 bits long, and the loads and stores are unaligned
+        unsafe {
+            let sum = _mm_xor_si128(
+                _mm_loadu_si128(dst_chunk.as_ptr() as *const __m128i),
+                _mm_loadu_si128(src_chunk.as_ptr() as *const __m128i),
+            );
+            _mm_storeu_si128(dst_chunk.as_mut_ptr() as *mut __m128i, sum);
         }
     }
+    dst_chunks
+        .into_remainder()
+        .iter_mut()
+        .zip(src_chunks.remainder())
+        .for_each(|(limb, other)| *limb ^= other);
+}
 
-    return prod;
+/// XOR src into dst limb by limb; both slices must have the same length. Whole 128-bit chunks are
+/// XORed with NEON and the remaining limbs one at a time
+#[cfg(all(feature = "simd", target_arch = "aarch64", target_feature = "neon"))]
+fn xor_limbs(dst: &mut [Word], src: &[Word]) {
+    use core::arch::aarch64::{veorq_u8, vld1q_u8, vst1q_u8};
+
+    let mut dst_chunks = dst.chunks_exact_mut(LIMBS_PER_VECTOR);
+    let mut src_chunks = src.chunks_exact(LIMBS_PER_VECTOR);
+    for (dst_chunk, src_chunk) in (&mut dst_chunks).zip(&mut src_chunks) {
+        // SAFETY: both chunks are exactly 16 bytes long, and NEON loads and stores of bytes have
+        // no alignment requirement
+        unsafe {
+            let sum = veorq_u8(
+                vld1q_u8(dst_chunk.as_ptr() as *const u8),
+                vld1q_u8(src_chunk.as_ptr() as *const u8),
+            );
+            vst1q_u8(dst_chunk.as_mut_ptr() as *mut u8, sum);
+        }
+    }
+    dst_chunks
+        .into_remainder()
+        .iter_mut()
+        .zip(src_chunks.remainder())
+        .for_each(|(limb, other)| *limb ^= other);
+}
+
+/// XOR src into dst starting at the specified limb offset. Limbs of src that fall outside of dst
+/// are discarded, so the caller must make sure that they are zeros
+fn xor_limbs_at(dst: &mut [Word], src: &[Word], offset: usize) {
+    let len = dst.len().saturating_sub(offset).min(src.len());
+    if len > 0 {
+        xor_limbs(&mut dst[offset..(offset + len)], &src[..len]);
+    }
+}
+
+/// XOR src, left shifted by fewer than Word::BITS bits, into dst. The bits shifted out of the
+/// highest limb of src go into the next limb of dst if there is one, and are discarded otherwise
+fn xor_shl_limbs(dst: &mut [Word], src: &[Word], bits: u32) {
+    let mut carry = 0;
+    for (i, limb) in src.iter().enumerate() {
+        dst[i] ^= (limb << bits) | carry;
+        carry = limb.checked_shr(Word::BITS - bits).unwrap_or(0);
+    }
+    if let Some(limb) = dst.get_mut(src.len()) {
+        *limb ^= carry;
+    }
+}
+
+/// Exact division of a little-endian limb slice by x, which is a right shift by one bit
+fn div_limbs_by_x(v: &mut [Word]) {
+    for i in 0..v.len() {
+        let next = v.get(i + 1).copied().unwrap_or(0);
+        v[i] = (v[i] >> 1) | (next << (Word::BITS - 1));
+    }
+}
+
+/// Exact division of a little-endian limb slice by (x + 1). Since 1 / (1 + x) = 1 + x + x^2 + ...,
+/// each coefficient of the quotient is the XOR of all coefficients of the dividend at or below it.
+fn div_limbs_by_x_plus_one(v: &mut [Word]) {
+    let mut carry: Word = 0;
+    for limb in v.iter_mut() {
+        let mut prefix = *limb;
+        let mut shift = 1;
+        while shift < Word::BITS {
+            prefix ^= prefix << shift;
+            shift *= 2;
+        }
+        // carry is all ones if the XOR of the lower limbs is 1
+        prefix ^= carry;
+        carry = (prefix >> (Word::BITS - 1)).wrapping_neg();
+        *limb = prefix;
+    }
+}
+
+/// Toom-3 multiplication of two little-endian limb slices of the same length following Bodrato's
+/// evaluation points 0, 1, x, x + 1, and infinity for GF(2)[x]. Each operand is split into three
+/// parts a = a0 + a1 y + a2 y^2, so that the product takes five multiplications of a third of the
+/// size instead of nine. The product is written into prod, which must have twice as many limbs,
+/// and scratch must have at least `mul_scratch_len` limbs.
+fn toom3_mul(a: &[Word], b: &[Word], prod: &mut [Word], scratch: &mut [Word]) {
+    let n = a.len();
+    let k = n.div_ceil(3);
+    let range = |i: usize| (i * k).min(n)..((i + 1) * k).min(n);
+    let a_parts: [&[Word]; 3] = core::array::from_fn(|i| &a[range(i)]);
+    let b_parts: [&[Word]; 3] = core::array::from_fn(|i| &b[range(i)]);
+    // Each evaluation has an extra limb to absorb the shifts when evaluating at x, so that the
+    // products of evaluations have 2(k + 1) limbs
+    let (evals, scratch) = scratch.split_at_mut(6 * (k + 1));
+    let (products, scratch) = scratch.split_at_mut(10 * (k + 1));
+    // Evaluate at 1, x, and x + 1; a(x + 1) = a(1) + a(x) + a0
+    let evaluate = |parts: &[&[Word]; 3], evals: &mut [Word]| {
+        evals.fill(0);
+        let (at_one, evals) = evals.split_at_mut(k + 1);
+        let (at_x, at_x_plus_one) = evals.split_at_mut(k + 1);
+        for (i, part) in parts.iter().enumerate() {
+            xor_limbs_at(at_one, part, 0);
+            xor_shl_limbs(at_x, part, i as u32);
+        }
+        at_x_plus_one.copy_from_slice(at_one);
+        xor_limbs_at(at_x_plus_one, at_x, 0);
+        xor_limbs_at(at_x_plus_one, parts[0], 0);
+    };
+    let (a_evals, b_evals) = evals.split_at_mut(3 * (k + 1));
+    evaluate(&a_parts, a_evals);
+    evaluate(&b_parts, b_evals);
+
+    products.fill(0);
+    let (w0, products) = products.split_at_mut(2 * (k + 1));
+    let (w4, products) = products.split_at_mut(2 * (k + 1));
+    let (w_one, products) = products.split_at_mut(2 * (k + 1));
+    let (w_x, w_x_plus_one) = products.split_at_mut(2 * (k + 1));
+    let (a_one, a_x, a_x_plus_one) = (
+        &a_evals[..=k],
+        &a_evals[(k + 1)..][..=k],
+        &a_evals[(2 * k + 2)..],
+    );
+    let (b_one, b_x, b_x_plus_one) = (
+        &b_evals[..=k],
+        &b_evals[(k + 1)..][..=k],
+        &b_evals[(2 * k + 2)..],
+    );
+    mul_limbs(
+        a_parts[0],
+        b_parts[0],
+        &mut w0[..(2 * a_parts[0].len())],
+        scratch,
+    );
+    mul_limbs(
+        a_parts[2],
+        b_parts[2],
+        &mut w4[..(2 * a_parts[2].len())],
+        scratch,
+    );
+    mul_limbs(a_one, b_one, w_one, scratch);
+    mul_limbs(a_x, b_x, w_x, scratch);
+    mul_limbs(a_x_plus_one, b_x_plus_one, w_x_plus_one, scratch);
+
+    // Interpolate the coefficients c0, ..., c4 of w(y) = c0 + c1 y + ... + c4 y^4 where c0 = w0
+    // and c4 = w4
+    // P = (w(x) - c0 - c4 x^4) / x = c1 + c2 x + c3 x^2
+    xor_limbs_at(w_x, w0, 0);
+    xor_shl_limbs(w_x, w4, 4);
+    div_limbs_by_x(w_x);
+    // Q = (w(x + 1) - c0 - c4 (x + 1)^4) / (x + 1) = c1 + c2 (x + 1) + c3 (x + 1)^2
+    xor_limbs_at(w_x_plus_one, w0, 0);
+    xor_shl_limbs(w_x_plus_one, w4, 4);
+    xor_limbs_at(w_x_plus_one, w4, 0);
+    div_limbs_by_x_plus_one(w_x_plus_one);
+    // P + Q = c2 + c3, kept where the evaluations were
+    let c2_plus_c3 = &mut evals[..(2 * (k + 1))];
+    c2_plus_c3.copy_from_slice(w_x);
+    xor_limbs_at(c2_plus_c3, w_x_plus_one, 0);
+    // c1 = w(1) - c0 - c4 - (c2 + c3)
+    let c1 = w_one;
+    xor_limbs_at(c1, w0, 0);
+    xor_limbs_at(c1, w4, 0);
+    xor_limbs_at(c1, c2_plus_c3, 0);
+    // (P - c1) / x = c2 + c3 x, so c3 = (c2 + c3 x - (c2 + c3)) / (x + 1)
+    let c3 = w_x;
+    xor_limbs_at(c3, c1, 0);
+    div_limbs_by_x(c3);
+    xor_limbs_at(c3, c2_plus_c3, 0);
+    div_limbs_by_x_plus_one(c3);
+    let c2 = c2_plus_c3;
+    xor_limbs_at(c2, c3, 0);
+
+    prod.fill(0);
+    for (i, coeff) in [&*w0, c1, c2, c3, w4].iter().enumerate() {
+        xor_limbs_at(prod, coeff, i * k);
+    }
+}
+
+/// Recursive Karatsuba multiplication of two little-endian limb slices of the same length.
+/// Writing a = a0 + a1 x^h and b = b0 + b1 x^h, the product is
+/// a0 b0 + ((a0 + a1)(b0 + b1) - a0 b0 - a1 b1) x^h + a1 b1 x^(2h), which takes three half-size
+/// multiplications instead of four. The product is written into prod, which must have twice as
+/// many limbs, and scratch must have at least `mul_scratch_len` limbs.
+fn karatsuba_mul(a: &[Word], b: &[Word], prod: &mut [Word], scratch: &mut [Word]) {
+    let n = a.len();
+    if n < KARATSUBA_THRESHOLD {
+        return schoolbook_mul_limbs(a, b, prod);
+    }
+    // The high halves have m = n - h >= h limbs; the sums are padded to the longer length
+    let (h, m) = (n / 2, n - n / 2);
+    let (a0, a1) = a.split_at(h);
+    let (b0, b1) = b.split_at(h);
+    let (a_sum, scratch) = scratch.split_at_mut(m);
+    let (b_sum, scratch) = scratch.split_at_mut(m);
+    let (z1, scratch) = scratch.split_at_mut(2 * m);
+    a_sum.copy_from_slice(a1);
+    xor_limbs_at(a_sum, a0, 0);
+    b_sum.copy_from_slice(b1);
+    xor_limbs_at(b_sum, b0, 0);
+
+    // a0 b0 and a1 b1 go straight into the low and high parts of the product
+    let (z0, z2) = prod.split_at_mut(2 * h);
+    mul_limbs(a0, b0, z0, scratch);
+    mul_limbs(a1, b1, z2, scratch);
+    mul_limbs(a_sum, b_sum, z1, scratch);
+    xor_limbs_at(z1, z0, 0);
+    xor_limbs_at(z1, z2, 0);
+    xor_limbs_at(prod, z1, h);
 }
 
 /// The degree of a polynomial is the highest power of term with non-zero coefficient
@@ -34,19 +473,18 @@ pub enum Degree {
 impl PartialOrd for Degree {
     /// Comparison for degree; it will always return Some(_)
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        match (self, other) {
-            (Self::NegativeInfinity, Self::NegativeInfinity) => Some(core::cmp::Ordering::Equal),
-            (Self::NegativeInfinity, Self::NonNegative(_)) => Some(core::cmp::Ordering::Less),
-            (Self::NonNegative(_), Self::NegativeInfinity) => Some(core::cmp::Ordering::Greater),
-            (Self::NonNegative(a), Self::NonNegative(b)) => a.partial_cmp(b),
-        }
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Degree {
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        self.partial_cmp(other)
-            .expect("Degree comparison should always be well-defined")
+        match (self, other) {
+            (Self::NegativeInfinity, Self::NegativeInfinity) => core::cmp::Ordering::Equal,
+            (Self::NegativeInfinity, Self::NonNegative(_)) => core::cmp::Ordering::Less,
+            (Self::NonNegative(_), Self::NegativeInfinity) => core::cmp::Ordering::Greater,
+            (Self::NonNegative(a), Self::NonNegative(b)) => a.cmp(b),
+        }
     }
 }
 
@@ -54,7 +492,15 @@ impl Ord for Degree {
 ///
 /// Each bit encodes the a coefficient. The most significant bit encodes the coefficient of the
 /// highest power term. F2x::<L> can encode a polynomial with degree up to (Word::BITS * L - 1).
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+///
+/// Polynomials often encode secret field elements, so `Debug` only prints the number of limbs
+/// unless the `debug-secrets` feature is enabled. Types built on `F2x` inherit the redaction.
+///
+/// `Ord` compares coefficients from the highest power down, which is the order of the integers
+/// that the bits encode. Neither `Ord` nor `Hash` is constant time, so they are meant for public
+/// values such as share indices.
+#[cfg_attr(feature = "debug-secrets", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub struct F2x<const L: usize> {
     limbs: [Word; L],
 }
@@ -73,8 +519,8 @@ impl<const L: usize> F2x<L> {
         }
         self.limbs.iter().enumerate().for_each(|(i, limb)| {
             let limb_bytes = limb.to_be_bytes();
-            let start = 2 * i;
-            let stop = 2 * i + 2;
+            let start = WORD_BYTES * i;
+            let stop = WORD_BYTES * (i + 1);
             // the length check earlier ensures this slice is valid
             dst[start..stop].copy_from_slice(&limb_bytes);
         });
@@ -91,22 +537,62 @@ impl<const L: usize> F2x<L> {
         let mut poly = Self::ZERO;
 
         poly.limbs.iter_mut().enumerate().for_each(|(i, limb)| {
-            let mut be_bytes = [0; 2];
-            be_bytes.copy_from_slice(&src[(2 * i)..(2 * i + 2)]);
+            let mut be_bytes = [0; WORD_BYTES];
+            be_bytes.copy_from_slice(&src[(WORD_BYTES * i)..(WORD_BYTES * (i + 1))]);
             *limb = Word::from_be_bytes(be_bytes);
         });
         poly
     }
 
     /// Clone into a wide polynomial of the same value
-    pub fn widen(&self) -> WideF2x<L> {
-        WideF2x::from_f2x(Self::ZERO, self.clone())
+    pub const fn widen(&self) -> WideF2x<L> {
+        WideF2x::from_f2x(Self::ZERO, *self)
     }
 
     pub const fn from_limbs(limbs: [Word; L]) -> Self {
         Self { limbs }
     }
 
+    /// Build the polynomial whose coefficients are the bits of the input, e.g. `0b10011` encodes
+    /// x^4 + x + 1. Unlike `from_limbs`, this does not depend on the limb width. Panics if the
+    /// polynomial does not fit.
+    pub const fn from_u128(value: u128) -> Self {
+        let mut limbs = [0; L];
+        let mut i = 0;
+
+        while i < L {
+            let shift = (i as u32).saturating_mul(Word::BITS);
+            limbs[L - 1 - i] = match value.checked_shr(shift) {
+                Some(chunk) => chunk as Word,
+                None => 0,
+            };
+            i += 1;
+        }
+        if Self::BITS < 128 && value >> Self::BITS != 0 {
+            panic!("value is too large");
+        }
+
+        Self::from_limbs(limbs)
+    }
+
+    /// Build the polynomial whose non-zero terms are exactly the input powers, e.g. `[4, 1, 0]`
+    /// encodes x^4 + x + 1. Repeated powers are only counted once. This is a const fn so that
+    /// moduli can be written as a list of exponents instead of limbs.
+    pub const fn from_exponents(exps: &[usize]) -> Self {
+        let mut limbs = [0; L];
+        let mut i = 0;
+
+        while i < exps.len() {
+            if exps[i] >= Self::BITS {
+                panic!("exponent is too large");
+            }
+            limbs[exps[i] / (Word::BITS as usize)] |= 1 << (exps[i] % (Word::BITS as usize));
+            i += 1;
+        }
+
+        Self::from_le_limbs(limbs)
+    }
+
     /// Get a referene to the limb at the specified location
     pub fn get_limb(&self, i: usize) -> Option<&Word> {
         self.limbs.get(i)
@@ -117,14 +603,111 @@ impl<const L: usize> F2x<L> {
         self.limbs.get_mut(i)
     }
 
+    /// Construct from limbs in little-endian order, where limb 0 holds the lowest powers. The
+    /// internal layout stays big-endian; this only reverses the limbs once at the boundary
+    pub const fn from_le_limbs(le_limbs: [Word; L]) -> Self {
+        let mut limbs = [0; L];
+        let mut i = 0;
+
+        while i < L {
+            limbs[L - 1 - i] = le_limbs[i];
+            i += 1;
+        }
+
+        Self::from_limbs(limbs)
+    }
+
+    /// The limbs in little-endian order, where limb 0 holds the lowest powers
+    pub const fn to_le_limbs(&self) -> [Word; L] {
+        let mut le_limbs = [0; L];
+        let mut i = 0;
+
+        while i < L {
+            le_limbs[i] = self.limbs[L - 1 - i];
+            i += 1;
+        }
+
+        le_limbs
+    }
+
+    /// Get the limb holding the coefficients of x^(i * Word::BITS) and up, i.e. limb i counted
+    /// from the constant term. `None` if i is out of bound
+    pub const fn le_limb(&self, i: usize) -> Option<Word> {
+        if i >= L {
+            return None;
+        }
+        Some(self.limbs[L - 1 - i])
+    }
+
+    /// Get a mutable reference to limb i counted from the constant term
+    pub fn le_limb_mut(&mut self, i: usize) -> Option<&mut Word> {
+        if i >= L {
+            return None;
+        }
+        self.limbs.get_mut(L - 1 - i)
+    }
+
     /// The 0 polynomial
     pub const fn zero() -> Self {
         Self::from_limbs([0; L])
     }
 
     /// Return true if self is the 0 polynomial
-    pub fn is_zero(&self) -> bool {
-        self.limbs == Self::ZERO.limbs
+    pub const fn is_zero(&self) -> bool {
+        let mut i = 0;
+
+        while i < L {
+            if self.limbs[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+
+        true
+    }
+
+    /// Compare self with other without short-circuiting: the differences of all limbs are
+    /// accumulated before a single comparison, so the running time does not depend on where the
+    /// operands differ
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let diff = self
+            .limbs
+            .iter()
+            .zip(other.limbs.iter())
+            .fold(0, |acc, (a, b)| acc | (a ^ b));
+        core::hint::black_box(diff) == 0
+    }
+
+    /// Constant-time version of `is_zero`. See `ct_eq`
+    pub fn ct_is_zero(&self) -> bool {
+        self.ct_eq(&Self::ZERO)
+    }
+
+    /// Constant-time check for the 1 polynomial. See `ct_eq`
+    pub fn ct_is_one(&self) -> bool {
+        self.ct_eq(&Self::ONE)
+    }
+
+    /// Return a if choice is false and b if choice is true. The choice is expanded into a mask of
+    /// all zeros or all ones that is applied to every limb, so there is no branch on it
+    pub fn ct_select(a: &Self, b: &Self, choice: bool) -> Self {
+        let mask = core::hint::black_box(choice as Word).wrapping_neg();
+        let mut limbs = a.limbs;
+        for (limb, other) in limbs.iter_mut().zip(b.limbs.iter()) {
+            *limb ^= (*limb ^ other) & mask;
+        }
+
+        Self::from_limbs(limbs)
+    }
+
+    /// Swap a and b if choice is true. See `ct_select`
+    pub fn ct_swap(a: &mut Self, b: &mut Self, choice: bool) {
+        let mask = core::hint::black_box(choice as Word).wrapping_neg();
+        for (lhs, rhs) in a.limbs.iter_mut().zip(b.limbs.iter_mut()) {
+            let diff = (*lhs ^ *rhs) & mask;
+            *lhs ^= diff;
+            *rhs ^= diff;
+        }
     }
 
     /// The 1 polynomial
@@ -137,7 +720,7 @@ impl<const L: usize> F2x<L> {
 
     /// The number of leading zeros, counting from higher power terms
     /// e.g. In GF(2^128), the polynomial "1" has 127 leading zeros
-    pub fn leading_zeros(&self) -> usize {
+    pub const fn leading_zeros(&self) -> usize {
         let mut count = 0;
         let mut i = 0;
 
@@ -149,104 +732,326 @@ impl<const L: usize> F2x<L> {
             count += self.limbs[i].leading_zeros() as usize;
         }
 
-        return count;
+        count
+    }
+
+    /// The number of trailing zeros, counting from the constant term. The zero polynomial has
+    /// `BITS` trailing zeros.
+    pub const fn trailing_zeros(&self) -> usize {
+        let mut count = 0;
+        let mut i = L;
+
+        while i > 0 && self.limbs[i - 1] == 0 {
+            count += Word::BITS as usize;
+            i -= 1;
+        }
+        if i > 0 {
+            count += self.limbs[i - 1].trailing_zeros() as usize;
+        }
+
+        count
     }
 
     /// The degree of this polynomial. See `Degree` for the mathematical definition.
-    pub fn degree(&self) -> Degree {
+    pub const fn degree(&self) -> Degree {
         if self.is_zero() {
             return Degree::NegativeInfinity;
         }
 
-        return Degree::NonNegative(Self::BITS - self.leading_zeros() - 1);
+        Degree::NonNegative(Self::BITS - self.leading_zeros() - 1)
+    }
+
+    /// The coefficient of x^i. Powers that do not fit have coefficient 0.
+    pub const fn bit(&self, i: usize) -> bool {
+        if i >= Self::BITS {
+            return false;
+        }
+        match self.le_limb(i / (Word::BITS as usize)) {
+            Some(limb) => (limb >> (i % (Word::BITS as usize))) & 1 == 1,
+            None => false,
+        }
+    }
+
+    /// Set the coefficient of x^i to 1. Will panic if x^i does not fit
+    pub fn set_bit(&mut self, i: usize) {
+        let limb = self
+            .le_limb_mut(i / (Word::BITS as usize))
+            .expect("power is too large");
+        *limb |= 1 << (i % (Word::BITS as usize));
+    }
+
+    /// Set the coefficient of x^i to 0. Will panic if x^i does not fit
+    pub fn clear_bit(&mut self, i: usize) {
+        let limb = self
+            .le_limb_mut(i / (Word::BITS as usize))
+            .expect("power is too large");
+        *limb &= !(1 << (i % (Word::BITS as usize)));
+    }
+
+    /// The number of non-zero terms
+    pub const fn count_ones(&self) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+
+        while i < L {
+            count += self.limbs[i].count_ones() as usize;
+            i += 1;
+        }
+
+        count
+    }
+
+    /// The formal derivative. Over GF(2) the derivative of x^i is i * x^(i - 1), which is
+    /// x^(i - 1) for odd i and 0 for even i, so only the odd-power terms survive, one power lower
+    pub const fn derivative(&self) -> Self {
+        self.shr(1).even_terms()
+    }
+
+    /// Keep only the terms of even power. Limbs have an even number of bits, so the same mask
+    /// applies to every limb
+    const fn even_terms(&self) -> Self {
+        let mut limbs = self.limbs;
+        let mut i = 0;
+
+        while i < L {
+            // 0x5555...
+            limbs[i] &= Word::MAX / 3;
+            i += 1;
+        }
+
+        Self::from_limbs(limbs)
     }
 
     /// Equivalent to applying the bitflip operator "!"
-    pub fn not(&self) -> Self {
+    pub const fn not(&self) -> Self {
         let mut output = Self::ZERO;
+        let mut i = 0;
 
-        self.limbs
-            .iter()
-            .enumerate()
-            .for_each(|(i, limb)| output.limbs[i] = !limb);
+        while i < L {
+            output.limbs[i] = !self.limbs[i];
+            i += 1;
+        }
 
-        return output;
+        output
     }
 
     /// Apply bitwise XOR
-    pub fn xor(&self, other: &Self) -> Self {
-        let mut limbs = [0; L];
+    pub const fn xor(&self, other: &Self) -> Self {
+        let mut limbs = self.limbs;
+        let mut i = 0;
 
-        for i in 0..L {
-            // No need for bound check; guaranteed to be within bounds.
-            limbs[i] = self.limbs[i] ^ other.limbs[i];
+        while i < L {
+            limbs[i] ^= other.limbs[i];
+            i += 1;
         }
 
-        return Self::from_limbs(limbs);
+        Self::from_limbs(limbs)
     }
 
     /// Addition in GF(2^m) is a simple XOR and will never overflow
-    pub fn add(&self, other: &Self) -> Self {
+    pub const fn add(&self, other: &Self) -> Self {
         self.xor(other)
     }
 
     /// Subtraction is identical to addition in GF(2^m) because -1 = 1
-    pub fn sub(&self, other: &Self) -> Self {
+    pub const fn sub(&self, other: &Self) -> Self {
         self.xor(other)
     }
 
-    /// School book multiplication with L^2 steps
+    /// Apply bitwise XOR in place
+    pub fn xor_assign(&mut self, other: &Self) {
+        xor_limbs(&mut self.limbs, &other.limbs);
+    }
+
+    /// In-place version of `add`
+    pub fn add_assign(&mut self, other: &Self) {
+        self.xor_assign(other);
+    }
+
+    /// In-place version of `sub`
+    pub fn sub_assign(&mut self, other: &Self) {
+        self.xor_assign(other);
+    }
+
+    /// Widening multiplication. With a hardware carry-less multiply instruction the limbs are
+    /// multiplied 64 bits at a time. Otherwise the `windowed-mul` feature selects
+    /// `windowed_widening_mul`, and by default wide operands are split with Karatsuba or Toom-3
+    /// multiplication and narrow ones fall back to school book multiplication
     pub fn widening_mul(&self, other: &Self) -> WideF2x<L> {
-        let (mut high, mut low) = (Self::ZERO, Self::ZERO);
-        for i in 0..L {
-            for j in 0..L {
-                let (high_limb, low_limb) =
-                    widening_clmul(self.limbs[L - i - 1], other.limbs[L - j - 1]);
-                if (i + j) < L {
-                    low.limbs[L - (i + j) - 1] ^= low_limb;
-                } else {
-                    high.limbs[L - (i + j - L) - 1] ^= low_limb;
+        if HARDWARE_CLMUL {
+            self.widening_mul_u64(other)
+        } else if cfg!(feature = "windowed-mul") {
+            self.windowed_widening_mul(other)
+        } else if L >= KARATSUBA_THRESHOLD {
+            self.split_widening_mul(other)
+        } else {
+            self.schoolbook_widening_mul(other)
+        }
+    }
+
+    /// Precompute the 16 multiples of self by polynomials of degree less than 4, then process
+    /// other four bits at a time starting from the highest power terms, in the style of GHASH
+    /// table implementations. This needs no carry-less multiplication at all. Each lookup reads
+    /// all 16 entries and keeps the one selected by a mask, so the memory access pattern does not
+    /// depend on other.
+    fn windowed_widening_mul(&self, other: &Self) -> WideF2x<L> {
+        let mut table = [WideF2x::<L>::ZERO; 16];
+        for nibble in 1..16 {
+            table[nibble] = if nibble % 2 == 0 {
+                table[nibble / 2].shl(1)
+            } else {
+                table[nibble - 1].add(&self.widen())
+            };
+        }
+        let mut prod = WideF2x::<L>::ZERO;
+        for limb in other.limbs.iter() {
+            for shift in (0..Word::BITS).step_by(4).rev() {
+                let nibble = (limb >> shift) & 0xF;
+                let mut entry = WideF2x::<L>::ZERO;
+                for (i, multiple) in table.iter().enumerate() {
+                    // All ones exactly when i equals nibble, since only 0 - 1 sets the top bit
+                    let diff = core::hint::black_box(nibble ^ i as Word);
+                    let mask = (diff.wrapping_sub(1) >> (Word::BITS - 1)).wrapping_neg();
+                    entry = entry.add(&multiple.mask(mask));
                 }
-                if (i + j + 1) < L {
-                    low.limbs[L - (i + j + 1) - 1] ^= high_limb;
-                } else {
-                    high.limbs[L - (i + j + 1 - L) - 1] ^= high_limb;
+                prod = prod.shl(4).add(&entry);
+            }
+        }
+
+        prod
+    }
+
+    /// Karatsuba or Toom-3 multiplication depending on the number of limbs. See `mul_limbs`. The
+    /// product and the intermediate values are kept on the stack
+    fn split_widening_mul(&self, other: &Self) -> WideF2x<L> {
+        debug_assert!(mul_scratch_len(L) <= MUL_SCRATCH_FACTOR * L);
+        let (lhs, rhs) = (self.to_le_limbs(), other.to_le_limbs());
+        let mut prod = [[0; L]; 2];
+        let mut scratch = [[0; L]; MUL_SCRATCH_FACTOR];
+        mul_limbs(
+            &lhs,
+            &rhs,
+            prod.as_flattened_mut(),
+            scratch.as_flattened_mut(),
+        );
+        let [low, high] = prod;
+
+        WideF2x::<L>::from_f2x(Self::from_le_limbs(high), Self::from_le_limbs(low))
+    }
+
+    /// Squaring is linear over GF(2), so it only needs to interleave the bits with zeros
+    pub fn widening_square(&self) -> WideF2x<L> {
+        let mut square = WideF2x::<L>::ZERO;
+        for (i, limb) in self.limbs.iter().enumerate() {
+            let (high, low) = spread_bits(*limb);
+            *square.get_mut_limb(2 * i).unwrap() = high;
+            *square.get_mut_limb(2 * i + 1).unwrap() = low;
+        }
+
+        square
+    }
+
+    /// The k-th 64-bit chunk of self, counting from the lowest power terms
+    fn get_u64(&self, k: usize) -> u64 {
+        (0..LIMBS_PER_U64).fold(0, |acc, t| match L.checked_sub(LIMBS_PER_U64 * k + t + 1) {
+            Some(loc) => acc | ((self.limbs[loc] as u64) << (t * Word::BITS as usize)),
+            None => acc,
+        })
+    }
+
+    /// School book multiplication over 64-bit chunks using `widening_clmul64`
+    fn widening_mul_u64(&self, other: &Self) -> WideF2x<L> {
+        let chunks = L.div_ceil(LIMBS_PER_U64);
+        let mut prod = WideF2x::<L>::ZERO;
+        // XOR a 64-bit chunk into prod at the k-th chunk position
+        let mut xor_u64 = |k: usize, chunk: u64| {
+            for t in 0..LIMBS_PER_U64 {
+                if let Some(loc) = (2 * L).checked_sub(LIMBS_PER_U64 * k + t + 1) {
+                    *prod.get_mut_limb(loc).unwrap() ^=
+                        (chunk >> (t * Word::BITS as usize)) as Word;
                 }
             }
+        };
+        for i in 0..chunks {
+            for j in 0..chunks {
+                let (high, low) = widening_clmul64(self.get_u64(i), other.get_u64(j));
+                xor_u64(i + j, low);
+                xor_u64(i + j + 1, high);
+            }
         }
 
-        WideF2x::<L>::from_f2x(high, low)
+        prod
+    }
+
+    /// School book multiplication with L^2 steps
+    fn schoolbook_widening_mul(&self, other: &Self) -> WideF2x<L> {
+        let (lhs, rhs) = (self.to_le_limbs(), other.to_le_limbs());
+        let (mut high, mut low) = ([0; L], [0; L]);
+        let mut xor_limb = |k: usize, limb: Word| {
+            if k < L {
+                low[k] ^= limb;
+            } else {
+                high[k - L] ^= limb;
+            }
+        };
+        for (i, lhs_limb) in lhs.iter().enumerate() {
+            for (j, rhs_limb) in rhs.iter().enumerate() {
+                let (high_limb, low_limb) = widening_clmul(*lhs_limb, *rhs_limb);
+                xor_limb(i + j, low_limb);
+                xor_limb(i + j + 1, high_limb);
+            }
+        }
+
+        WideF2x::<L>::from_f2x(Self::from_le_limbs(high), Self::from_le_limbs(low))
+    }
+
+    /// Bit-serial widening multiplication that adds a shifted copy of self for every non-zero term
+    /// of other. This is much slower than `widening_mul`, but it is a const fn, so that products
+    /// can be computed at compile time.
+    pub const fn widening_mul_const(&self, other: &Self) -> WideF2x<L> {
+        let wide = self.widen();
+        let mut prod = WideF2x::<L>::ZERO;
+        let mut i = 0;
+
+        while i < Self::BITS {
+            if other.bit(i) {
+                prod = prod.add(&wide.shl(i));
+            }
+            i += 1;
+        }
+
+        prod
     }
 
     /// School book polynomial multiplication.
     /// The second returned value will be true if there is overflow; overflowing values are
     /// discarded.
     pub fn overflowing_mul(&self, rhs: &Self) -> (Self, bool) {
-        let mut prod = Self::ZERO;
+        let (lhs, rhs) = (self.to_le_limbs(), rhs.to_le_limbs());
+        let mut prod = [0; L];
         let mut overflowed = false;
-        for i in 0..L {
-            for j in 0..L {
-                let (high_limb, low_limb) =
-                    widening_clmul(self.limbs[L - i - 1], rhs.limbs[L - j - 1]);
-                if (low_limb != 0 && i + j >= L) || (high_limb != 0 && i + j + 1 >= L) {
-                    // Overflowed
-                    overflowed = true;
-                }
-                if i + j < L {
-                    prod.limbs[L - (i + j) - 1] ^= low_limb;
-                }
-                if i + j + 1 < L {
-                    prod.limbs[L - (i + j + 1) - 1] ^= high_limb;
-                }
+        let mut xor_limb = |k: usize, limb: Word| {
+            if k < L {
+                prod[k] ^= limb;
+            } else if limb != 0 {
+                // Overflowed
+                overflowed = true;
+            }
+        };
+        for (i, lhs_limb) in lhs.iter().enumerate() {
+            for (j, rhs_limb) in rhs.iter().enumerate() {
+                let (high_limb, low_limb) = widening_clmul(*lhs_limb, *rhs_limb);
+                xor_limb(i + j, low_limb);
+                xor_limb(i + j + 1, high_limb);
             }
         }
 
-        (prod, overflowed)
+        (Self::from_le_limbs(prod), overflowed)
     }
 
     /// School book polynomial multiplication. Return None upon overflow.
     pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
-        let (prod, overflowed) = self.overflowing_mul(&rhs);
+        let (prod, overflowed) = self.overflowing_mul(rhs);
 
         if overflowed {
             None
@@ -257,14 +1062,14 @@ impl<const L: usize> F2x<L> {
 
     /// School book polynomial multiplication. Panic on overflow
     pub fn mul(&self, rhs: &Self) -> Self {
-        self.checked_mul(&rhs)
+        self.checked_mul(rhs)
             .expect("attempt to multiply with overflow")
     }
 
     /// Attempt to left shift (e.g. 0xFFFF.overflowing_shl(4) = 0xFFF0)
     /// If the shift will cause overflow, this method will panic. This is consistent with integer
     /// arithmetics
-    pub fn shl(&self, rhs: usize) -> Self {
+    pub const fn shl(&self, rhs: usize) -> Self {
         let mut shifted = Self::ZERO;
 
         if rhs >= Self::BITS {
@@ -273,13 +1078,15 @@ impl<const L: usize> F2x<L> {
 
         let limb_offset = rhs / (Word::BITS as usize);
         let limb_fraction = rhs % (Word::BITS as usize);
-        self.limbs.iter().enumerate().for_each(|(i, limb)| {
+        let mut i = 0;
+        while i < L {
+            let limb = self.limbs[i];
             if limb_offset <= i {
                 let near_loc = i - limb_offset;
                 let near_limb = limb << limb_fraction;
                 shifted.limbs[near_loc] ^= near_limb;
             }
-            if limb_offset + 1 <= i {
+            if limb_offset < i {
                 let far_loc = i - limb_offset - 1;
                 let far_limb = if limb_fraction == 0 {
                     0
@@ -288,7 +1095,8 @@ impl<const L: usize> F2x<L> {
                 };
                 shifted.limbs[far_loc] ^= far_limb;
             }
-        });
+            i += 1;
+        }
 
         shifted
     }
@@ -296,7 +1104,7 @@ impl<const L: usize> F2x<L> {
     /// Attempt to shift right by the specified number of bits
     /// e.g. 0xFFFF.overflowing_shr(4) = 0x0FFF
     /// Attempt to shift with overflow will cause panic
-    pub fn shr(&self, rhs: usize) -> Self {
+    pub const fn shr(&self, rhs: usize) -> Self {
         let mut shifted = Self::ZERO;
 
         if rhs >= Self::BITS {
@@ -305,7 +1113,9 @@ impl<const L: usize> F2x<L> {
 
         let limb_offset = rhs / Word::BITS as usize;
         let limb_fraction = rhs % Word::BITS as usize;
-        self.limbs.iter().enumerate().for_each(|(i, limb)| {
+        let mut i = 0;
+        while i < L {
+            let limb = self.limbs[i];
             if (i + limb_offset) < L {
                 let near_loc = i + limb_offset;
                 let near_limb = limb >> limb_fraction;
@@ -320,9 +1130,28 @@ impl<const L: usize> F2x<L> {
                 };
                 shifted.limbs[far_loc] ^= far_limb;
             }
-        });
+            i += 1;
+        }
+
+        shifted
+    }
 
-        return shifted;
+    /// Multiply by x^rhs for any rhs, keeping the terms that fit. The flag is set if a non-zero
+    /// term was shifted out. Unlike `shl`, shifting by `BITS` or more does not panic.
+    pub const fn overflowing_shl(&self, rhs: usize) -> (Self, bool) {
+        if rhs >= Self::BITS {
+            return (Self::ZERO, !self.is_zero());
+        }
+        (self.shl(rhs), rhs > self.leading_zeros())
+    }
+
+    /// Divide by x^rhs for any rhs, discarding the remainder. The flag is set if the remainder
+    /// is not zero. Unlike `shr`, shifting by `BITS` or more does not panic.
+    pub const fn overflowing_shr(&self, rhs: usize) -> (Self, bool) {
+        if rhs >= Self::BITS {
+            return (Self::ZERO, !self.is_zero());
+        }
+        (self.shr(rhs), rhs > self.trailing_zeros())
     }
 
     /// Euclidean long division, will panic if rhs is zero
@@ -331,7 +1160,7 @@ impl<const L: usize> F2x<L> {
             panic!("attempt to divide by zero");
         }
         let mut quot = Self::ZERO;
-        let mut rem = self.clone();
+        let mut rem = *self;
 
         while rem.degree() >= rhs.degree() {
             // Both rem and rhs are guaranteed to be NonNegative
@@ -348,7 +1177,7 @@ impl<const L: usize> F2x<L> {
             rem = rem.sub(&rhs.shl(degree_diff));
         }
 
-        return (quot, rem);
+        (quot, rem)
     }
 
     /// Multiplication followed by modulus reduction. This function assumes that the remainder will
@@ -371,7 +1200,7 @@ impl<const L: usize> F2x<L> {
     /// Use [Euclid's algorithm](https://en.wikipedia.org/wiki/Euclidean_algorithm) to compute the
     /// highest-degree polynomial that divides both lhs and rhs.
     pub fn gcd(lhs: &Self, rhs: &Self) -> Self {
-        let (mut a, mut b): (Self, Self) = (lhs.clone(), rhs.clone());
+        let (mut a, mut b): (Self, Self) = (*lhs, *rhs);
 
         while !b.is_zero() {
             let (_, rem) = a.div_rem(&b);
@@ -388,7 +1217,7 @@ impl<const L: usize> F2x<L> {
         // less than b, than the first iteration of the while loop will swap them automatically.
         // This is helpful when implementing Extended Euclidean Algorithm because it makes sure
         // that the output is not swapped
-        let (mut rr, mut r): (Self, Self) = (lhs.clone(), rhs.clone());
+        let (mut rr, mut r): (Self, Self) = (*lhs, *rhs);
         let (mut ss, mut s): (Self, Self) = (Self::ONE, Self::ZERO);
         let (mut tt, mut t): (Self, Self) = (Self::ZERO, Self::ONE);
 
@@ -402,6 +1231,48 @@ impl<const L: usize> F2x<L> {
         (ss, tt, rr)
     }
 
+    /// Run the Extended Euclidean Algorithm on lhs and rhs only until the remainder has degree less
+    /// than the input degree, and return (s, t, r) such that s * lhs + t * rhs = r. r is the first
+    /// remainder, starting from lhs itself, whose degree is below the bound; if every non-zero
+    /// remainder has a higher degree, then r is zero. Stopping half-way gives the rational
+    /// approximations used by key equation solvers, e.g. t / r approximates lhs / rhs.
+    pub fn partial_xgcd(lhs: &Self, rhs: &Self, degree: usize) -> (Self, Self, Self) {
+        let (mut rr, mut r): (Self, Self) = (*lhs, *rhs);
+        let (mut ss, mut s): (Self, Self) = (Self::ONE, Self::ZERO);
+        let (mut tt, mut t): (Self, Self) = (Self::ZERO, Self::ONE);
+
+        while rr.degree() >= Degree::NonNegative(degree) {
+            if r.is_zero() {
+                return (s, t, r);
+            }
+            let (quot, rem) = rr.div_rem(&r);
+            (rr, r) = (r, rem);
+            (ss, s) = (s, ss.sub(&quot.mul(&s)));
+            (tt, t) = (t, tt.sub(&quot.mul(&t)));
+        }
+
+        (ss, tt, rr)
+    }
+
+    /// Return true if self is irreducible over GF(2). See `WideF2x::is_irreducible`
+    pub fn is_irreducible(&self) -> bool {
+        self.widen().is_irreducible()
+    }
+
+    /// Return true if self is primitive over GF(2). See `WideF2x::is_primitive`
+    pub fn is_primitive(&self) -> bool {
+        self.widen().is_primitive()
+    }
+
+    /// Sample a uniformly random irreducible polynomial of the specified degree. See
+    /// `WideF2x::random_irreducible`
+    pub fn random_irreducible(degree: usize, rng: &mut impl CryptoRngCore) -> Self {
+        if degree >= Self::BITS {
+            panic!("degree is too large");
+        }
+        WideF2x::<L>::random_irreducible(degree, rng).truncate()
+    }
+
     /// Find and return the inverse of self under the input modulus. Return None if self is not
     /// invertible under the given modulus
     pub fn modinv(&self, modulus: &WideF2x<L>) -> Option<Self> {
@@ -418,17 +1289,126 @@ impl<const L: usize> F2x<L> {
     }
 }
 
+#[cfg(not(feature = "debug-secrets"))]
+impl<const L: usize> core::fmt::Debug for F2x<L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("F2x")
+            .field("limbs", &format_args!("[REDACTED; {L}]"))
+            .finish()
+    }
+}
+
 impl<const L: usize> UpperHex for F2x<L> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let width = WORD_BYTES * 2;
         for limb in self.limbs {
-            write!(f, "{limb:04X}")?;
+            write!(f, "{limb:0width$X}")?;
         }
         Ok(())
     }
 }
 
-/// Wide F2[x] is useful for performing reduction after widening multiplication
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Implement a binary operator and its assigning form for owned and borrowed F2x operands by
+/// delegating to the inherent method of the same meaning. The inherent methods are called with
+/// fully qualified paths, since method call syntax on an owned F2x would resolve to the operator.
+macro_rules! f2x_binop {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $inherent:ident) => {
+        impl<const L: usize> core::ops::$op<&F2x<L>> for &F2x<L> {
+            type Output = F2x<L>;
+
+            fn $method(self, rhs: &F2x<L>) -> F2x<L> {
+                F2x::$inherent(self, rhs)
+            }
+        }
+
+        impl<const L: usize> core::ops::$op<&F2x<L>> for F2x<L> {
+            type Output = F2x<L>;
+
+            fn $method(self, rhs: &F2x<L>) -> F2x<L> {
+                F2x::$inherent(&self, rhs)
+            }
+        }
+
+        impl<const L: usize> core::ops::$op<F2x<L>> for &F2x<L> {
+            type Output = F2x<L>;
+
+            fn $method(self, rhs: F2x<L>) -> F2x<L> {
+                F2x::$inherent(self, &rhs)
+            }
+        }
+
+        impl<const L: usize> core::ops::$op<F2x<L>> for F2x<L> {
+            type Output = F2x<L>;
+
+            fn $method(self, rhs: F2x<L>) -> F2x<L> {
+                F2x::$inherent(&self, &rhs)
+            }
+        }
+
+        impl<const L: usize> core::ops::$op_assign<&F2x<L>> for F2x<L> {
+            fn $method_assign(&mut self, rhs: &F2x<L>) {
+                *self = F2x::$inherent(self, rhs);
+            }
+        }
+
+        impl<const L: usize> core::ops::$op_assign<F2x<L>> for F2x<L> {
+            fn $method_assign(&mut self, rhs: F2x<L>) {
+                *self = F2x::$inherent(self, &rhs);
+            }
+        }
+    };
+}
+
+// Multiplication panics on overflow, like `F2x::mul` and integer multiplication
+f2x_binop!(Add, add, AddAssign, add_assign, add);
+f2x_binop!(Sub, sub, SubAssign, sub_assign, sub);
+f2x_binop!(Mul, mul, MulAssign, mul_assign, mul);
+
+#[cfg(feature = "subtle")]
+impl<const L: usize> subtle::ConstantTimeEq for F2x<L> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&self.limbs[..], &other.limbs[..])
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<const L: usize> subtle::ConditionallySelectable for F2x<L> {
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        let mut limbs = [0; L];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            *limb = subtle::ConditionallySelectable::conditional_select(
+                &a.limbs[i],
+                &b.limbs[i],
+                choice,
+            );
+        }
+        Self::from_limbs(limbs)
+    }
+}
+
+impl<const L: usize> zeroize::Zeroize for F2x<L> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.limbs);
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, const L: usize> arbitrary::Arbitrary<'a> for F2x<L> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_limbs(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[Word; L] as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+/// The largest degree supported by `WideF2x::is_primitive`
+pub const PRIMITIVITY_MAX_DEGREE: usize = 32;
+
+/// Wide F2[x] is useful for performing reduction after widening multiplication. It is ordered
+/// and hashed like `F2x`, with the high half compared first.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct WideF2x<const L: usize> {
     /// The higher power terms
     high: F2x<L>,
@@ -446,6 +1426,29 @@ impl<const L: usize> WideF2x<L> {
         Self { high, low }
     }
 
+    /// Build the polynomial whose non-zero terms are exactly the input powers. See
+    /// `F2x::from_exponents`.
+    pub const fn from_exponents(exps: &[usize]) -> Self {
+        let (mut high, mut low) = ([0; L], [0; L]);
+        let mut i = 0;
+
+        while i < exps.len() {
+            if exps[i] >= Self::BITS {
+                panic!("exponent is too large");
+            }
+            let loc = 2 * L - 1 - exps[i] / (Word::BITS as usize);
+            let bit = 1 << (exps[i] % (Word::BITS as usize));
+            if loc < L {
+                high[loc] |= bit;
+            } else {
+                low[loc - L] |= bit;
+            }
+            i += 1;
+        }
+
+        Self::from_f2x(F2x::from_limbs(high), F2x::from_limbs(low))
+    }
+
     /// Get the limb at the specified location if it exists
     pub fn get_limb(&self, loc: usize) -> Option<&Word> {
         if loc < L {
@@ -453,7 +1456,7 @@ impl<const L: usize> WideF2x<L> {
         } else if loc < 2 * L {
             return self.low.limbs.get(loc - L);
         }
-        return None;
+        None
     }
 
     /// Get a mutable reference to the limb at the specified location
@@ -463,7 +1466,7 @@ impl<const L: usize> WideF2x<L> {
         } else if loc < 2 * L {
             return self.low.limbs.get_mut(loc - L);
         }
-        return None;
+        None
     }
 
     pub const fn zero() -> Self {
@@ -480,11 +1483,16 @@ impl<const L: usize> WideF2x<L> {
         }
     }
 
-    pub fn is_zero(&self) -> bool {
+    pub const fn is_zero(&self) -> bool {
         self.high.is_zero() && self.low.is_zero()
     }
 
-    pub fn leading_zeros(&self) -> usize {
+    /// Constant-time version of `is_zero`. See `F2x::ct_eq`
+    pub fn ct_is_zero(&self) -> bool {
+        self.high.ct_is_zero() & self.low.ct_is_zero()
+    }
+
+    pub const fn leading_zeros(&self) -> usize {
         if self.high.is_zero() {
             self.high.leading_zeros() + self.low.leading_zeros()
         } else {
@@ -492,21 +1500,97 @@ impl<const L: usize> WideF2x<L> {
         }
     }
 
-    pub fn degree(&self) -> Degree {
+    pub const fn trailing_zeros(&self) -> usize {
+        if self.low.is_zero() {
+            self.low.trailing_zeros() + self.high.trailing_zeros()
+        } else {
+            self.low.trailing_zeros()
+        }
+    }
+
+    pub const fn degree(&self) -> Degree {
         if self.is_zero() {
             return Degree::NegativeInfinity;
         }
-        return Degree::NonNegative(Self::BITS - self.leading_zeros() - 1);
+        Degree::NonNegative(Self::BITS - self.leading_zeros() - 1)
+    }
+
+    /// The coefficient of x^i. See `F2x::bit`
+    pub const fn bit(&self, i: usize) -> bool {
+        if i < F2x::<L>::BITS {
+            self.low.bit(i)
+        } else {
+            self.high.bit(i - F2x::<L>::BITS)
+        }
+    }
+
+    /// Set the coefficient of x^i to 1. Will panic if x^i does not fit
+    pub fn set_bit(&mut self, i: usize) {
+        if i < F2x::<L>::BITS {
+            self.low.set_bit(i)
+        } else {
+            self.high.set_bit(i - F2x::<L>::BITS)
+        }
     }
 
-    pub fn add(&self, rhs: &Self) -> Self {
+    /// Set the coefficient of x^i to 0. Will panic if x^i does not fit
+    pub fn clear_bit(&mut self, i: usize) {
+        if i < F2x::<L>::BITS {
+            self.low.clear_bit(i)
+        } else {
+            self.high.clear_bit(i - F2x::<L>::BITS)
+        }
+    }
+
+    /// The number of non-zero terms
+    pub const fn count_ones(&self) -> usize {
+        self.high.count_ones() + self.low.count_ones()
+    }
+
+    /// The formal derivative; see `F2x::derivative`
+    pub const fn derivative(&self) -> Self {
+        let shifted = self.shr(1);
+        Self::from_f2x(shifted.high.even_terms(), shifted.low.even_terms())
+    }
+
+    pub const fn add(&self, rhs: &Self) -> Self {
         Self::from_f2x(self.high.add(&rhs.high), self.low.add(&rhs.low))
     }
 
-    pub fn sub(&self, rhs: &Self) -> Self {
+    pub const fn sub(&self, rhs: &Self) -> Self {
         self.add(rhs)
     }
 
+    /// In-place version of `add`
+    pub fn add_assign(&mut self, rhs: &Self) {
+        self.high.add_assign(&rhs.high);
+        self.low.add_assign(&rhs.low);
+    }
+
+    /// In-place version of `sub`
+    pub fn sub_assign(&mut self, rhs: &Self) {
+        self.add_assign(rhs);
+    }
+
+    /// The limb at the specified location, which must be less than 2L. Unlike `get_limb`, this
+    /// can be called in const fn
+    const fn limb(&self, loc: usize) -> Word {
+        if loc < L {
+            self.high.limbs[loc]
+        } else {
+            self.low.limbs[loc - L]
+        }
+    }
+
+    /// XOR the input into the limb at the specified location, which must be less than 2L
+    const fn xor_limb(&mut self, loc: usize, value: Word) {
+        if loc < L {
+            self.high.limbs[loc] ^= value;
+        } else {
+            self.low.limbs[loc - L] ^= value;
+        }
+    }
+
     /// School book polynomial multiplication
     /// The second returned value will be true if there is overflow; overflowing limbs will be
     /// discarded.
@@ -545,7 +1629,7 @@ impl<const L: usize> WideF2x<L> {
 
     /// School book polynomial multiplication. Return None upon overflow.
     pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
-        let (prod, overflowed) = self.overflowing_mul(&rhs);
+        let (prod, overflowed) = self.overflowing_mul(rhs);
 
         if overflowed {
             None
@@ -556,12 +1640,12 @@ impl<const L: usize> WideF2x<L> {
 
     /// School book polynomial multiplication. Panic on overflow
     pub fn mul(&self, rhs: &Self) -> Self {
-        self.checked_mul(&rhs)
+        self.checked_mul(rhs)
             .expect("attempt to multiply with overflow")
     }
 
     /// Left shift by the specified amount. Panic if rhs is greater than or equal to Self::BITS
-    pub fn shl(&self, rhs: usize) -> Self {
+    pub const fn shl(&self, rhs: usize) -> Self {
         let mut shifted = Self::ZERO;
 
         if rhs >= Self::BITS {
@@ -570,34 +1654,23 @@ impl<const L: usize> WideF2x<L> {
 
         let limb_offset = rhs / (Word::BITS as usize);
         let limb_fraction = rhs % (Word::BITS as usize);
-        for i in 0..(2 * L) {
-            let limb = self.get_limb(i).expect("unexpected out-of-bound");
+        let mut i = 0;
+        while i < 2 * L {
+            let limb = self.limb(i);
             if limb_offset <= i {
-                let near_loc = i - limb_offset;
-                let near_limb = (*limb) << limb_fraction;
-                let shifted_limb = shifted
-                    .get_mut_limb(near_loc)
-                    .expect("unexpected out-of-bound");
-                *shifted_limb ^= near_limb;
+                shifted.xor_limb(i - limb_offset, limb << limb_fraction);
             }
-            if limb_offset + 1 <= i {
-                let far_loc = i - limb_offset - 1;
-                let far_limb = if limb_fraction == 0 {
-                    0
-                } else {
-                    (*limb) >> (Word::BITS as usize - limb_fraction)
-                };
-                let shifted_limb = shifted
-                    .get_mut_limb(far_loc)
-                    .expect("unexpected out-of-bound");
-                *shifted_limb ^= far_limb;
+            if limb_offset < i && limb_fraction != 0 {
+                let far_limb = limb >> (Word::BITS as usize - limb_fraction);
+                shifted.xor_limb(i - limb_offset - 1, far_limb);
             }
+            i += 1;
         }
 
         shifted
     }
 
-    pub fn shr(&self, rhs: usize) -> Self {
+    pub const fn shr(&self, rhs: usize) -> Self {
         let mut shifted = Self::ZERO;
 
         if rhs >= Self::BITS {
@@ -606,64 +1679,146 @@ impl<const L: usize> WideF2x<L> {
 
         let limb_offset = rhs / Word::BITS as usize;
         let limb_fraction = rhs % Word::BITS as usize;
-        for i in 0..(2 * L) {
-            let limb = self.get_limb(i).expect("unexpected out-of-bound");
+        let mut i = 0;
+        while i < 2 * L {
+            let limb = self.limb(i);
             if (i + limb_offset) < 2 * L {
-                let near_loc = i + limb_offset;
-                let near_limb = *limb >> limb_fraction;
-                let shifted_limb = shifted
-                    .get_mut_limb(near_loc)
-                    .expect("unexpected out-of-bound");
-                *shifted_limb ^= near_limb;
+                shifted.xor_limb(i + limb_offset, limb >> limb_fraction);
             }
-            if (i + limb_offset + 1) < 2 * L {
-                let far_loc = i + limb_offset + 1;
-                let far_limb = if limb_fraction == 0 {
-                    0
-                } else {
-                    *limb << (Word::BITS as usize - limb_fraction)
-                };
-                let shifted_limb = shifted
-                    .get_mut_limb(far_loc)
-                    .expect("unexpected out-of-bound");
-                *shifted_limb ^= far_limb;
+            if (i + limb_offset + 1) < 2 * L && limb_fraction != 0 {
+                let far_limb = limb << (Word::BITS as usize - limb_fraction);
+                shifted.xor_limb(i + limb_offset + 1, far_limb);
             }
+            i += 1;
+        }
+
+        shifted
+    }
+
+    /// Multiply by x^rhs for any rhs. See `F2x::overflowing_shl`
+    pub const fn overflowing_shl(&self, rhs: usize) -> (Self, bool) {
+        if rhs >= Self::BITS {
+            return (Self::ZERO, !self.is_zero());
         }
+        (self.shl(rhs), rhs > self.leading_zeros())
+    }
 
-        return shifted;
+    /// Divide by x^rhs for any rhs. See `F2x::overflowing_shr`
+    pub const fn overflowing_shr(&self, rhs: usize) -> (Self, bool) {
+        if rhs >= Self::BITS {
+            return (Self::ZERO, !self.is_zero());
+        }
+        (self.shr(rhs), rhs > self.trailing_zeros())
     }
 
     /// Euclidean division, returning (quotient, remainder)
     /// Will panic if divisor is zero
-    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
-        if rhs.is_zero() {
-            panic!("attempt to divide by zero");
-        }
+    pub const fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let rhs_degree = match rhs.degree() {
+            Degree::NonNegative(degree) => degree,
+            Degree::NegativeInfinity => panic!("attempt to divide by zero"),
+        };
         let mut quot = Self::ZERO;
-        let mut rem = self.clone();
+        let mut rem = *self;
 
-        while rem.degree() >= rhs.degree() {
-            // Both rem and rhs are guaranteed to be NonNegative
-            let rem_degree: usize = match rem.degree() {
-                Degree::NonNegative(degree) => degree,
-                _ => panic!("Remainder is unexpectedly zero"),
-            };
-            let rhs_degree: usize = match rhs.degree() {
-                Degree::NonNegative(degree) => degree,
-                _ => panic!("Divisor is unexpectedly zero"),
-            };
+        while let Degree::NonNegative(rem_degree) = rem.degree() {
+            if rem_degree < rhs_degree {
+                break;
+            }
             let degree_diff = rem_degree - rhs_degree;
             quot = quot.add(&Self::ONE.shl(degree_diff));
             rem = rem.sub(&rhs.shl(degree_diff));
         }
 
-        return (quot, rem);
+        (quot, rem)
+    }
+
+    /// Euclidean division whose running time depends on the degree of the divisor but not on the
+    /// dividend: every bit position from the top down to the degree of rhs is visited, and the
+    /// shifted divisor is masked by the coefficient at that position instead of being subtracted
+    /// under a branch. Will panic if divisor is zero
+    pub const fn div_rem_ct(&self, rhs: &Self) -> (Self, Self) {
+        let rhs_degree = match rhs.degree() {
+            Degree::NonNegative(degree) => degree,
+            Degree::NegativeInfinity => panic!("attempt to divide by zero"),
+        };
+        let mut quot = Self::ZERO;
+        let mut rem = *self;
+        let mut shift = Self::BITS - rhs_degree;
+
+        while shift > 0 {
+            shift -= 1;
+            let loc = rhs_degree + shift;
+            let bit = (rem.limb(2 * L - 1 - loc / Word::BITS as usize)
+                >> (loc % Word::BITS as usize))
+                & 1;
+            let mask = bit.wrapping_neg();
+            quot = quot.add(&Self::ONE.shl(shift).mask(mask));
+            rem = rem.sub(&rhs.shl(shift).mask(mask));
+        }
+
+        (quot, rem)
+    }
+
+    /// AND every limb with the input mask, which is either all zeros or all ones in practice
+    const fn mask(&self, mask: Word) -> Self {
+        let mut masked = *self;
+        let mut i = 0;
+        while i < L {
+            masked.high.limbs[i] &= mask;
+            masked.low.limbs[i] &= mask;
+            i += 1;
+        }
+
+        masked
+    }
+
+    /// The remainder of self modulo the sparse polynomial x^degree + sum(x^e for e in exps). Since
+    /// x^degree is congruent to the sum of the lower terms, the high part H above x^degree is folded
+    /// back as H x^e for every e, which takes a few shifts and XORs per term instead of the
+    /// bit-by-bit long division of `div_rem`. Will panic if some exponent is not below the degree.
+    ///
+    /// Each fold lowers the degree of the high part by at least (degree - max(exps)), so the number
+    /// of folds is fixed by the modulus and does not depend on the value being reduced.
+    pub const fn reduce_sparse(&self, degree: usize, exps: &[usize]) -> Self {
+        let mut max_exp = 0;
+        let mut i = 0;
+        while i < exps.len() {
+            if exps[i] >= degree {
+                panic!("exponents must be lower than the degree");
+            }
+            if exps[i] > max_exp {
+                max_exp = exps[i];
+            }
+            i += 1;
+        }
+        if degree >= Self::BITS {
+            return *self;
+        }
+        if degree == 0 {
+            return Self::ZERO;
+        }
+        let mut rem = *self;
+        let mut folds = (Self::BITS - 1 - degree) / (degree - max_exp) + 1;
+
+        while folds > 0 {
+            let high = rem.shr(degree);
+            rem = rem.sub(&high.shl(degree));
+            let mut i = 0;
+            while i < exps.len() {
+                rem = rem.add(&high.shl(exps[i]));
+                i += 1;
+            }
+            folds -= 1;
+        }
+
+        rem
     }
 
     /// Use [Euclid's algorithm](https://en.wikipedia.org/wiki/Euclidean_algorithm) to compute the
     /// highest-degree polynomial that divides both lhs and rhs.
     pub fn gcd(lhs: &Self, rhs: &Self) -> Self {
-        let (mut a, mut b): (Self, Self) = (lhs.clone(), rhs.clone());
+        let (mut a, mut b): (Self, Self) = (*lhs, *rhs);
 
         while !b.is_zero() {
             let (_, rem) = a.div_rem(&b);
@@ -676,26 +1831,220 @@ impl<const L: usize> WideF2x<L> {
     /// Use [Extended Euclid's algorithm](https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm)
     /// to compute (s, t, d) such that s * lhs + t * rhs = d and d is the GCD between lhs and rhs
     pub fn xgcd(lhs: &Self, rhs: &Self) -> (Self, Self, Self) {
-        let (mut rr, mut r): (Self, Self) = (lhs.clone(), rhs.clone());
+        let (mut rr, mut r): (Self, Self) = (*lhs, *rhs);
         let (mut ss, mut s): (Self, Self) = (Self::ONE, Self::ZERO);
         let (mut tt, mut t): (Self, Self) = (Self::ZERO, Self::ONE);
 
-        while !r.is_zero() {
-            let (quot, rem) = rr.div_rem(&r);
-            (rr, r) = (r, rem);
-            (ss, s) = (s, ss.sub(&quot.mul(&s)));
-            (tt, t) = (t, tt.sub(&quot.mul(&t)));
+        while !r.is_zero() {
+            let (quot, rem) = rr.div_rem(&r);
+            (rr, r) = (r, rem);
+            (ss, s) = (s, ss.sub(&quot.mul(&s)));
+            (tt, t) = (t, tt.sub(&quot.mul(&t)));
+        }
+
+        (ss, tt, rr)
+    }
+
+    /// Run the Extended Euclidean Algorithm on lhs and rhs only until the remainder has degree less
+    /// than the input degree, and return (s, t, r) such that s * lhs + t * rhs = r. r is the first
+    /// remainder, starting from lhs itself, whose degree is below the bound; if every non-zero
+    /// remainder has a higher degree, then r is zero. Stopping half-way gives the rational
+    /// approximations used by key equation solvers, e.g. t / r approximates lhs / rhs.
+    pub fn partial_xgcd(lhs: &Self, rhs: &Self, degree: usize) -> (Self, Self, Self) {
+        let (mut rr, mut r): (Self, Self) = (*lhs, *rhs);
+        let (mut ss, mut s): (Self, Self) = (Self::ONE, Self::ZERO);
+        let (mut tt, mut t): (Self, Self) = (Self::ZERO, Self::ONE);
+
+        while rr.degree() >= Degree::NonNegative(degree) {
+            if r.is_zero() {
+                return (s, t, r);
+            }
+            let (quot, rem) = rr.div_rem(&r);
+            (rr, r) = (r, rem);
+            (ss, s) = (s, ss.sub(&quot.mul(&s)));
+            (tt, t) = (t, tt.sub(&quot.mul(&t)));
+        }
+
+        (ss, tt, rr)
+    }
+
+    /// Use [Rabin's test](https://en.wikipedia.org/wiki/Factorization_of_polynomials_over_finite_fields#Rabin's_test_of_irreducibility)
+    /// to check if self is irreducible over GF(2): a polynomial f of degree n is irreducible if and
+    /// only if f divides x^(2^n) - x and gcd(f, x^(2^(n/p)) - x) = 1 for every prime p dividing n.
+    /// Constants are not irreducible.
+    pub fn is_irreducible(&self) -> bool {
+        let degree = match self.degree() {
+            Degree::NonNegative(degree) if degree >= 1 => degree,
+            _ => return false,
+        };
+        let (_, x) = Self::ONE.shl(1).div_rem(self);
+        let frobenius = |k: usize| (0..k).fold(x, |acc, _| acc.shift_add_modmul(&acc, self));
+
+        for p in crate::galoisfields::prime_factors(degree as u64) {
+            let h = frobenius(degree / (p as usize)).sub(&x);
+            if Self::gcd(self, &h) != Self::ONE {
+                return false;
+            }
+        }
+        frobenius(degree) == x
+    }
+
+    /// Return true if self is primitive, meaning that it is irreducible and that x generates the
+    /// multiplicative group of GF(2)[x] / self, which has order 2^n - 1 where n is the degree.
+    /// Factoring 2^n - 1 is done by trial division, so this method panics if n exceeds
+    /// `PRIMITIVITY_MAX_DEGREE`.
+    pub fn is_primitive(&self) -> bool {
+        let degree = match self.degree() {
+            Degree::NonNegative(degree) => degree,
+            Degree::NegativeInfinity => return false,
+        };
+        if degree > PRIMITIVITY_MAX_DEGREE {
+            panic!("degree is too large for primitivity test");
+        }
+        if !self.is_irreducible() {
+            return false;
+        }
+        let (_, x) = Self::ONE.shl(1).div_rem(self);
+        let group_order = (1u64 << degree) - 1;
+        let modexp = |exp: u64| {
+            (0..u64::BITS).rev().fold(Self::ONE, |acc, i| {
+                let acc = acc.shift_add_modmul(&acc, self);
+                if (exp >> i) & 1 == 1 {
+                    acc.shift_add_modmul(&x, self)
+                } else {
+                    acc
+                }
+            })
+        };
+
+        crate::galoisfields::prime_factors(group_order)
+            .all(|p| modexp(group_order / p) != Self::ONE)
+    }
+
+    /// Sample a uniformly random irreducible polynomial of the specified degree by rejection
+    /// sampling monic polynomials. Roughly one in every n polynomials of degree n is irreducible,
+    /// so the expected number of irreducibility tests is about n.
+    pub fn random_irreducible(degree: usize, rng: &mut impl CryptoRngCore) -> Self {
+        if degree == 0 {
+            panic!("constants are not irreducible");
+        }
+        if degree >= Self::BITS {
+            panic!("degree is too large");
+        }
+        let unused = Self::BITS - degree;
+        let leading = Self::ONE.shl(degree);
+
+        loop {
+            let (mut high, mut low) = ([0; L], [0; L]);
+            high.iter_mut()
+                .chain(low.iter_mut())
+                .for_each(|limb| *limb = rng.next_u64() as Word);
+            let candidate = Self::from_f2x(F2x::from_limbs(high), F2x::from_limbs(low))
+                .shl(unused)
+                .shr(unused)
+                .add(&leading);
+            if candidate.is_irreducible() {
+                return candidate;
+            }
+        }
+    }
+
+    /// Multiply two remainders of the modulus, reducing after every shift so that the
+    /// intermediate values never exceed the degree of the modulus
+    fn shift_add_modmul(&self, rhs: &Self, modulus: &Self) -> Self {
+        let degree = match modulus.degree() {
+            Degree::NonNegative(degree) => degree,
+            Degree::NegativeInfinity => panic!("attempt to divide by zero"),
+        };
+        let word_bits = Word::BITS as usize;
+        let mut prod = Self::ZERO;
+
+        for i in (0..Self::BITS).rev() {
+            prod = prod.shl(1);
+            if prod.degree() == Degree::NonNegative(degree) {
+                prod = prod.sub(modulus);
+            }
+            let limb = self.get_limb(2 * L - 1 - i / word_bits).unwrap();
+            if (limb >> (i % word_bits)) & 1 == 1 {
+                prod = prod.add(rhs);
+            }
         }
 
-        (ss, tt, rr)
+        prod
     }
 
     /// Return the low-order limbs if high-order limbs are all zeros; otherwise panic
-    pub fn truncate(&self) -> F2x<L> {
+    pub const fn truncate(&self) -> F2x<L> {
         if !self.high.is_zero() {
             panic!("high-order limbs are not zeros");
         }
-        self.low.clone()
+        self.low
+    }
+}
+
+/// Precomputed reciprocal for [Barrett reduction](https://en.wikipedia.org/wiki/Barrett_reduction)
+/// modulo a fixed polynomial M of degree m. With mu = x^(2m) div M, the quotient of any A of
+/// degree less than 2m is exactly ((A div x^m) * mu) div x^m, so reducing takes two
+/// multiplications instead of a long division.
+///
+/// Both mu and M have degree m, which might not fit into F2x<L>, so only their lower terms are
+/// stored and the leading x^m is applied as a shift.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Barrett<const L: usize> {
+    modulus: WideF2x<L>,
+    degree: usize,
+
+    /// mu - x^m
+    reciprocal: F2x<L>,
+
+    /// M - x^m
+    tail: F2x<L>,
+}
+
+impl<const L: usize> Barrett<L> {
+    /// Precompute the reciprocal of the modulus. Will panic if the modulus has degree 0 or does not
+    /// fit into the limbs
+    pub const fn new(modulus: &WideF2x<L>) -> Self {
+        let degree = match modulus.degree() {
+            Degree::NonNegative(degree) if 0 < degree && degree <= F2x::<L>::BITS => degree,
+            _ => panic!("modulus degree is out of range"),
+        };
+        let tail = modulus.sub(&WideF2x::ONE.shl(degree)).truncate();
+        // x^(2m) div M = x^m + (x^m * tail) div M
+        let (reciprocal, _) = tail.widen().shl(degree).div_rem(modulus);
+
+        Self {
+            modulus: *modulus,
+            degree,
+            reciprocal: reciprocal.truncate(),
+            tail,
+        }
+    }
+
+    /// The modulus M
+    pub fn modulus(&self) -> &WideF2x<L> {
+        &self.modulus
+    }
+
+    /// Compute the remainder of the input modulo M. Inputs of degree 2m or higher fall back to
+    /// constant-time long division. The range check does not scan for the leading term, so inputs
+    /// in range, such as the product of two reduced elements, are reduced in constant time.
+    pub fn reduce(&self, value: &WideF2x<L>) -> F2x<L> {
+        if 2 * self.degree < WideF2x::<L>::BITS && !value.shr(2 * self.degree).ct_is_zero() {
+            return value.div_rem_ct(&self.modulus).1.truncate();
+        }
+        let high = value.shr(self.degree).truncate();
+        let quot = high
+            .widen()
+            .shl(self.degree)
+            .add(&high.widening_mul(&self.reciprocal))
+            .shr(self.degree)
+            .truncate();
+
+        value
+            .sub(&quot.widen().shl(self.degree))
+            .sub(&quot.widening_mul(&self.tail))
+            .truncate()
     }
 }
 
@@ -707,171 +2056,610 @@ impl<const L: usize> UpperHex for WideF2x<L> {
     }
 }
 
+#[cfg(feature = "subtle")]
+impl<const L: usize> subtle::ConstantTimeEq for WideF2x<L> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&self.high, &other.high)
+            & subtle::ConstantTimeEq::ct_eq(&self.low, &other.low)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<const L: usize> subtle::ConditionallySelectable for WideF2x<L> {
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        Self {
+            high: subtle::ConditionallySelectable::conditional_select(&a.high, &b.high, choice),
+            low: subtle::ConditionallySelectable::conditional_select(&a.low, &b.low, choice),
+        }
+    }
+}
+
+impl<const L: usize> zeroize::Zeroize for WideF2x<L> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.high);
+        zeroize::Zeroize::zeroize(&mut self.low);
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, const L: usize> arbitrary::Arbitrary<'a> for WideF2x<L> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_f2x(u.arbitrary()?, u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let half = <F2x<L> as arbitrary::Arbitrary>::size_hint(depth);
+        arbitrary::size_hint::and(half, half)
+    }
+}
+
 // TODO: what if I need to implement GF(2^12), such as in classic McEliece
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::Rng;
 
-    type F2_128 = F2x<8>;
+    /// The number of limbs of a 128-bit polynomial, so that the test vectors below, which are
+    /// written as 128-bit integers, do not depend on the width of `Word`
+    const LIMBS_128: usize = 128 / Word::BITS as usize;
+    type F2_128 = F2x<LIMBS_128>;
 
     #[test]
     fn read_write_random_bytes() {
-        let lhs = F2_128::from_limbs([
-            0xA95D, 0x01B0, 0xD0A6, 0x81A9, 0x92A5, 0xA216, 0xC971, 0x961A,
-        ]);
+        let lhs = F2_128::from_u128(0xA95D_01B0_D0A6_81A9_92A5_A216_C971_961A);
         let mut buf = [0; F2_128::BYTES];
         lhs.write_to_be_bytes(&mut buf);
         let rhs = F2_128::read_from_be_bytes(&buf);
         assert_eq!(lhs, rhs);
     }
 
+    #[test]
+    fn f2x_from_exponents() {
+        assert_eq!(F2_128::from_exponents(&[]), F2_128::ZERO);
+        assert_eq!(F2_128::from_exponents(&[0]), F2_128::ONE);
+        assert_eq!(
+            F2_128::from_exponents(&[127, 77, 35, 11, 0]),
+            F2_128::from_u128(0x8000_0000_0000_2000_0000_0008_0000_0801)
+        );
+        assert_eq!(
+            WideF2x::<LIMBS_128>::from_exponents(&[128, 77, 35, 11, 0]),
+            WideF2x::from_f2x(
+                F2_128::ONE,
+                F2_128::from_u128(0x0000_0000_0000_2000_0000_0008_0000_0801),
+            )
+        );
+    }
+
+    #[test]
+    fn f2x_is_irreducible() {
+        assert!(!F2x::<1>::ZERO.is_irreducible());
+        assert!(!F2x::<1>::ONE.is_irreducible());
+        assert!(F2x::<1>::from_exponents(&[1]).is_irreducible());
+        assert!(F2x::<1>::from_exponents(&[1, 0]).is_irreducible());
+        // (x^2 + x + 1)^2 has no roots but is reducible
+        assert!(!F2x::<1>::from_exponents(&[4, 2, 0]).is_irreducible());
+        // The 9th cyclotomic polynomial is irreducible because 2 has order 6 modulo 9
+        assert!(F2x::<1>::from_exponents(&[6, 3, 0]).is_irreducible());
+        assert!(WideF2x::<LIMBS_128>::from_exponents(&[128, 7, 2, 1, 0]).is_irreducible());
+        assert!(!WideF2x::<LIMBS_128>::from_exponents(&[128, 7, 2, 0]).is_irreducible());
+
+        // There are (2^8 - 2^4) / 8 = 30 irreducible polynomials of degree 8 and
+        // (2^10 - 2^5 - 2^2 + 2) / 10 = 99 of degree 10
+        let count = |degree: usize| {
+            (0..(1u16 << degree))
+                .filter(|low| {
+                    let poly = F2x::<1>::from_u128((low | (1 << degree)) as u128);
+                    poly.is_irreducible()
+                })
+                .count()
+        };
+        assert_eq!(count(8), 30);
+        assert_eq!(count(10), 99);
+    }
+
+    #[test]
+    fn f2x_is_primitive() {
+        assert!(F2x::<1>::from_exponents(&[1, 0]).is_primitive());
+        assert!(F2x::<1>::from_exponents(&[8, 4, 3, 2, 0]).is_primitive());
+        // The AES modulus is irreducible, but x has order 51
+        assert!(!F2x::<1>::from_exponents(&[8, 4, 3, 1, 0]).is_primitive());
+        // x^4 + x^3 + x^2 + x + 1 divides x^5 - 1
+        assert!(!F2x::<1>::from_exponents(&[4, 3, 2, 1, 0]).is_primitive());
+        assert!(!F2x::<1>::from_exponents(&[4, 2, 0]).is_primitive());
+        assert!(F2x::<2>::from_exponents(&[20, 3, 0]).is_primitive());
+        // The modulus of GF2p16 is irreducible, but x has order (2^16 - 1) / 3
+        assert!(!WideF2x::<1>::from_exponents(&[16, 5, 3, 1, 0]).is_primitive());
+
+        // There are phi(255) / 8 = 16 primitive polynomials of degree 8
+        let count = (0..256u16)
+            .filter(|low| F2x::<1>::from_u128((low | 0x100) as u128).is_primitive())
+            .count();
+        assert_eq!(count, 16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn f2x_is_primitive_degree_too_large() {
+        WideF2x::<LIMBS_128>::from_exponents(&[128, 7, 2, 1, 0]).is_primitive();
+    }
+
+    #[test]
+    fn random_reduce_sparse() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let value = WideF2x::<LIMBS_128>::from_f2x(
+                F2_128::from_limbs(rng.gen()),
+                F2_128::from_limbs(rng.gen()),
+            );
+            let modulus = WideF2x::<LIMBS_128>::from_exponents(&[128, 77, 35, 11, 0]);
+            assert_eq!(
+                value.reduce_sparse(128, &[77, 35, 11, 0]),
+                value.div_rem(&modulus).1
+            );
+            // x^127 + x^126 + 1 needs many folding rounds
+            let modulus = WideF2x::<LIMBS_128>::from_exponents(&[127, 126, 0]);
+            assert_eq!(
+                value.reduce_sparse(127, &[126, 0]),
+                value.div_rem(&modulus).1
+            );
+        }
+        // The fixed number of folds must be enough for the highest possible degree
+        let ones = WideF2x::from_f2x(F2_128::ZERO.not(), F2_128::ZERO.not());
+        let modulus = WideF2x::<LIMBS_128>::from_exponents(&[127, 126, 0]);
+        assert_eq!(ones.reduce_sparse(127, &[126, 0]), ones.div_rem(&modulus).1);
+        assert_eq!(
+            WideF2x::<1>::ONE.reduce_sparse(16, &[5, 3, 1, 0]),
+            WideF2x::<1>::ONE
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn reduce_sparse_invalid_exponents() {
+        WideF2x::<1>::ONE.reduce_sparse(4, &[4, 0]);
+    }
+
+    #[test]
+    fn random_barrett_reduction() {
+        let mut rng = rand::thread_rng();
+        let moduli = [
+            WideF2x::<LIMBS_128>::from_exponents(&[128, 77, 35, 11, 0]),
+            WideF2x::<LIMBS_128>::random_irreducible(113, &mut rng),
+            WideF2x::<LIMBS_128>::from_exponents(&[1]),
+        ];
+        for modulus in moduli {
+            let barrett = Barrett::new(&modulus);
+            for _ in 0..10 {
+                let value =
+                    F2_128::from_limbs(rng.gen()).widening_mul(&F2_128::from_limbs(rng.gen()));
+                let (_, rem) = value.div_rem(&modulus);
+                assert_eq!(barrett.reduce(&value), rem.truncate());
+            }
+        }
+        // Inputs of degree 2m or higher fall back to long division
+        let barrett = Barrett::new(&WideF2x::<LIMBS_128>::from_exponents(&[5, 0]));
+        let value = WideF2x::from_f2x(F2_128::ZERO.not(), F2_128::ZERO.not());
+        let (_, rem) = value.div_rem(barrett.modulus());
+        assert_eq!(barrett.reduce(&value), rem.truncate());
+    }
+
+    #[test]
+    #[should_panic]
+    fn barrett_constant_modulus() {
+        Barrett::new(&WideF2x::<LIMBS_128>::ONE);
+    }
+
+    #[test]
+    fn random_irreducible_polynomials() {
+        let mut rng = rand::thread_rng();
+        for degree in [1, 2, 8, 15] {
+            let poly = F2x::<1>::random_irreducible(degree, &mut rng);
+            assert_eq!(poly.degree(), Degree::NonNegative(degree));
+            assert!(poly.is_irreducible());
+        }
+        let poly = WideF2x::<LIMBS_128>::random_irreducible(128, &mut rng);
+        assert_eq!(poly.degree(), Degree::NonNegative(128));
+        assert!(poly.is_irreducible());
+    }
+
     #[test]
     fn word_widening_clmul() {
+        // Split a product of 16-bit operands into the high and low words
+        let split = |prod: u128| ((prod >> Word::BITS) as Word, prod as Word);
         assert_eq!(widening_clmul(15, 15), (0, 0b1010101));
-        assert_eq!(widening_clmul(0xFFFF, 0xFFFF), (0x5555, 0x5555));
-        assert_eq!(widening_clmul(0xE223, 0x672F), (0x267B, 0xB291));
+        assert_eq!(widening_clmul(0xFFFF, 0xFFFF), split(0x5555_5555));
+        assert_eq!(widening_clmul(0xE223, 0x672F), split(0x267B_B291));
         assert_eq!(widening_clmul(0, 0), (0, 0));
         assert_eq!(widening_clmul(0, 1), (0, 0));
         assert_eq!(widening_clmul(1, 0), (0, 0));
     }
 
+    #[test]
+    fn random_widening_clmul_backends() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let (a, b): (Word, Word) = (rng.gen(), rng.gen());
+            assert_eq!(widening_clmul(a, b), portable_widening_clmul(a, b));
+            assert_eq!(portable_widening_clmul(a, a), spread_bits(a));
+            let (high, low) = widening_clmul64(a as u64, b as u64);
+            assert_eq!(((high as u128) << u64::BITS) | low as u128, {
+                let (high, low) = portable_widening_clmul(a, b);
+                ((high as u128) << Word::BITS) | low as u128
+            });
+        }
+        assert_eq!(widening_clmul64(u64::MAX, 2), (1, u64::MAX - 1));
+
+        // Limb counts that are and are not multiples of the chunk width
+        let lhs = F2x::<8>::from_limbs(rng.gen());
+        let rhs = F2x::<8>::from_limbs(rng.gen());
+        assert_eq!(
+            lhs.widening_mul_u64(&rhs),
+            lhs.schoolbook_widening_mul(&rhs)
+        );
+        let lhs = F2x::<15>::from_limbs(rng.gen());
+        let rhs = F2x::<15>::from_limbs(rng.gen());
+        assert_eq!(
+            lhs.widening_mul_u64(&rhs),
+            lhs.schoolbook_widening_mul(&rhs)
+        );
+        let lhs = F2x::<1>::from_limbs(rng.gen());
+        let rhs = F2x::<1>::from_limbs(rng.gen());
+        assert_eq!(
+            lhs.widening_mul_u64(&rhs),
+            lhs.schoolbook_widening_mul(&rhs)
+        );
+    }
+
+    #[test]
+    fn random_karatsuba_mul() {
+        let mut rng = rand::thread_rng();
+        macro_rules! check {
+            ($limbs:literal) => {
+                let lhs = F2x::<$limbs>::from_limbs(rng.gen());
+                let rhs = F2x::<$limbs>::from_limbs(rng.gen());
+                assert_eq!(
+                    lhs.split_widening_mul(&rhs),
+                    lhs.schoolbook_widening_mul(&rhs)
+                );
+            };
+        }
+        check!(1);
+        check!(8);
+        check!(12);
+        check!(15);
+        check!(16);
+        check!(18);
+        check!(26);
+        check!(32);
+        let ones = F2x::<36>::ZERO.not();
+        assert_eq!(
+            ones.split_widening_mul(&ones),
+            ones.schoolbook_widening_mul(&ones)
+        );
+    }
+
+    #[test]
+    fn random_widening_square() {
+        assert_eq!(spread_bits(Word::MAX), (Word::MAX / 3, Word::MAX / 3));
+        assert_eq!(
+            spread_bits(1 | (1 << (Word::BITS - 1))),
+            (1 << (Word::BITS - 2), 1)
+        );
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let poly = F2_128::from_limbs(rng.gen());
+            assert_eq!(poly.widening_square(), poly.widening_mul(&poly));
+            let poly = F2x::<15>::from_limbs(rng.gen());
+            assert_eq!(poly.widening_square(), poly.widening_mul(&poly));
+        }
+    }
+
+    #[test]
+    fn random_windowed_mul() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let lhs = F2_128::from_limbs(rng.gen());
+            let rhs = F2_128::from_limbs(rng.gen());
+            assert_eq!(
+                lhs.windowed_widening_mul(&rhs),
+                lhs.schoolbook_widening_mul(&rhs)
+            );
+            let lhs = F2x::<1>::from_limbs(rng.gen());
+            let rhs = F2x::<1>::from_limbs(rng.gen());
+            assert_eq!(
+                lhs.windowed_widening_mul(&rhs),
+                lhs.schoolbook_widening_mul(&rhs)
+            );
+        }
+        let ones = F2x::<15>::ZERO.not();
+        assert_eq!(
+            ones.windowed_widening_mul(&ones),
+            ones.schoolbook_widening_mul(&ones)
+        );
+    }
+
+    /// A limb multiplication taking (a, b, prod, scratch)
+    type MulLimbs = fn(&[Word], &[Word], &mut [Word], &mut [Word]);
+
+    /// Run one of the limb multiplications with as much scratch space as `split_widening_mul`
+    /// reserves, returning the product
+    fn mul_with(mul: MulLimbs, a: &[Word], b: &[Word]) -> Vec<Word> {
+        let mut prod = vec![Word::MAX; 2 * a.len()];
+        let mut scratch = vec![Word::MAX; MUL_SCRATCH_FACTOR * a.len()];
+        mul(a, b, &mut prod, &mut scratch);
+        prod
+    }
+
+    #[test]
+    fn random_toom3_mul() {
+        let mut rng = rand::thread_rng();
+        let schoolbook = |a: &[Word], b: &[Word], prod: &mut [Word], _: &mut [Word]| {
+            schoolbook_mul_limbs(a, b, prod)
+        };
+        for n in [3, 4, 5, 24, 25, 26, 36, 37, 80] {
+            let a = (0..n).map(|_| rng.gen()).collect::<Vec<Word>>();
+            let b = (0..n).map(|_| rng.gen()).collect::<Vec<Word>>();
+            let expected = mul_with(schoolbook, &a, &b);
+            assert_eq!(mul_with(toom3_mul, &a, &b), expected);
+            assert_eq!(mul_with(mul_limbs, &a, &b), expected);
+        }
+        let ones = vec![Word::MAX; 36];
+        assert_eq!(
+            mul_with(toom3_mul, &ones, &ones),
+            mul_with(schoolbook, &ones, &ones)
+        );
+    }
+
+    #[test]
+    fn mul_scratch_fits_on_stack() {
+        for n in 1..2000 {
+            assert!(mul_scratch_len(n) <= MUL_SCRATCH_FACTOR * n);
+        }
+    }
+
+    #[test]
+    fn random_assign_ops() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let (lhs, rhs) = (F2_128::from_limbs(rng.gen()), F2_128::from_limbs(rng.gen()));
+            let mut sum = lhs;
+            sum.add_assign(&rhs);
+            assert_eq!(sum, lhs.add(&rhs));
+            sum.sub_assign(&rhs);
+            assert_eq!(sum, lhs);
+            sum.xor_assign(&lhs);
+            assert!(sum.is_zero());
+
+            let (lhs, rhs) = (lhs.widening_mul(&rhs), rhs.widen());
+            let mut sum = lhs;
+            sum.add_assign(&rhs);
+            assert_eq!(sum, lhs.add(&rhs));
+            sum.sub_assign(&rhs);
+            assert_eq!(sum, lhs);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn random_operators() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let (lhs, rhs) = (F2_128::from_limbs(rng.gen()), F2_128::from_limbs(rng.gen()));
+            assert_eq!(lhs + rhs, F2_128::add(&lhs, &rhs));
+            assert_eq!(&lhs - &rhs, F2_128::sub(&lhs, &rhs));
+            let mut sum = lhs;
+            sum += &rhs;
+            sum -= rhs;
+            assert_eq!(sum, lhs);
+
+            // Products of polynomials of less than half the width cannot overflow
+            let (lhs, rhs) = (lhs.shr(F2_128::BITS / 2), rhs.shr(F2_128::BITS / 2));
+            assert_eq!(lhs * &rhs, lhs.widening_mul(&rhs).truncate());
+            let mut prod = lhs;
+            prod *= rhs;
+            assert_eq!(prod, &lhs * rhs);
+        }
+    }
+
+    #[test]
+    fn random_widening_mul_const() {
+        const PROD: WideF2x<1> = F2x::<1>::from_u128(0b1111).widening_mul_const(&F2x::ONE.shl(3));
+        assert_eq!(PROD, WideF2x::from_exponents(&[6, 5, 4, 3]));
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let lhs = F2_128::from_limbs(rng.gen());
+            let rhs = F2_128::from_limbs(rng.gen());
+            assert_eq!(lhs.widening_mul_const(&rhs), lhs.widening_mul(&rhs));
+            let lhs = F2x::<15>::from_limbs(rng.gen());
+            let rhs = F2x::<15>::from_limbs(rng.gen());
+            assert_eq!(lhs.widening_mul_const(&rhs), lhs.widening_mul(&rhs));
+        }
+    }
+
+    #[test]
+    fn f2x_from_u128() {
+        assert_eq!(
+            F2x::<1>::from_u128(0b10011),
+            F2x::from_exponents(&[4, 1, 0])
+        );
+        assert_eq!(
+            F2_128::from_u128(u128::MAX),
+            F2_128::from_exponents(&(0..128).collect::<Vec<usize>>())
+        );
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let value = rng.gen::<u128>();
+            let mut buf = [0u8; F2x::<{ 128 / Word::BITS as usize }>::BYTES];
+            F2x::<{ 128 / Word::BITS as usize }>::from_u128(value).write_to_be_bytes(&mut buf);
+            assert_eq!(u128::from_be_bytes(buf), value);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn f2x_from_u128_too_large() {
+        F2x::<1>::from_u128(1 << Word::BITS);
+    }
+
+    #[test]
+    fn random_xor_limbs() {
+        let mut rng = rand::thread_rng();
+        for len in [0, 1, 7, 8, 9, 16, 36] {
+            let a = (0..len).map(|_| rng.gen()).collect::<Vec<Word>>();
+            let b = (0..len).map(|_| rng.gen()).collect::<Vec<Word>>();
+            let mut sum = a.clone();
+            xor_limbs(&mut sum, &b);
+            for i in 0..len {
+                assert_eq!(sum[i], a[i] ^ b[i]);
+            }
+        }
+        let mut dst = vec![0; 4];
+        xor_limbs_at(&mut dst, &[1, 2, 3], 2);
+        assert_eq!(dst, vec![0, 0, 1, 2]);
+        xor_limbs_at(&mut dst, &[1], 5);
+        assert_eq!(dst, vec![0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn limbs_exact_division() {
+        // The little-endian limbs of a 128-bit polynomial
+        let le_limbs = |value: u128| F2_128::from_u128(value).to_le_limbs().to_vec();
+        // (x^17 + x + 1)(x + 1) = x^18 + x^17 + x^2 + 1
+        let mut v = le_limbs(0x6_0005);
+        div_limbs_by_x_plus_one(&mut v);
+        assert_eq!(v, le_limbs(0x2_0003));
+        let mut v = le_limbs(0x1_0000);
+        div_limbs_by_x(&mut v);
+        assert_eq!(v, le_limbs(0x8000));
+    }
+
     #[test]
     fn f2x_widening_mul() {
         assert_eq!(
             F2_128::ZERO.not().widening_mul(&F2_128::ZERO),
             WideF2x::from_f2x(F2_128::ZERO, F2_128::ZERO)
         );
-        // 0xFFFF * 0xFFFF = 0x5555,0x5555
-        let fives = F2_128::from_limbs([0x5555; 8]);
+        // The square of all ones has every other bit set
+        let fives = F2_128::from_u128(u128::MAX / 3);
         assert_eq!(
             F2_128::ZERO.not().widening_mul(&F2_128::ZERO.not()),
             WideF2x::from_f2x(fives, fives)
         );
 
         // Random cases generated by SymPy
-        let lhs = F2_128::from_limbs([
-            0x3DCC, 0x5CE2, 0x8A9D, 0x3FE3, 0x5309, 0x07F3, 0xC9FD, 0x43B6,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x8370, 0x7DA9, 0x108D, 0xF5B7, 0x30C9, 0xAEB8, 0x719A, 0xEDB5,
-        ]);
+        let lhs = F2_128::from_u128(0x3DCC_5CE2_8A9D_3FE3_5309_07F3_C9FD_43B6);
+        let rhs = F2_128::from_u128(0x8370_7DA9_108D_F5B7_30C9_AEB8_719A_EDB5);
         let prod = WideF2x::from_f2x(
-            F2_128::from_limbs([
-                0x1EAB, 0x66E7, 0x4160, 0x869E, 0xA3A7, 0x038E, 0x03AB, 0x25BF,
-            ]),
-            F2_128::from_limbs([
-                0x77C9, 0xE332, 0x2107, 0x2707, 0x8AFD, 0x8E14, 0xE779, 0x45CE,
-            ]),
+            F2_128::from_u128(0x1EAB_66E7_4160_869E_A3A7_038E_03AB_25BF),
+            F2_128::from_u128(0x77C9_E332_2107_2707_8AFD_8E14_E779_45CE),
         );
         assert_eq!(lhs.widening_mul(&rhs), prod);
 
-        let lhs = F2_128::from_limbs([
-            0x102D, 0x2BD4, 0x66AC, 0xBCB1, 0xF7C7, 0x5FE9, 0xBBC2, 0x335D,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0xEB90, 0xC40B, 0xFD14, 0xE019, 0xDFC5, 0xE087, 0x23EF, 0xA19F,
-        ]);
+        let lhs = F2_128::from_u128(0x102D_2BD4_66AC_BCB1_F7C7_5FE9_BBC2_335D);
+        let rhs = F2_128::from_u128(0xEB90_C40B_FD14_E019_DFC5_E087_23EF_A19F);
         let prod = WideF2x::from_f2x(
-            F2_128::from_limbs([
-                0x0EA0, 0x7C5D, 0xFBA7, 0x0792, 0x1B33, 0x323D, 0xE533, 0x6BF7,
-            ]),
-            F2_128::from_limbs([
-                0x19B6, 0xA88E, 0x62E5, 0xBB2E, 0x06AF, 0xAB14, 0x6A88, 0xE42B,
-            ]),
+            F2_128::from_u128(0x0EA0_7C5D_FBA7_0792_1B33_323D_E533_6BF7),
+            F2_128::from_u128(0x19B6_A88E_62E5_BB2E_06AF_AB14_6A88_E42B),
         );
         assert_eq!(lhs.widening_mul(&rhs), prod);
 
-        let lhs = F2_128::from_limbs([
-            0xA95D, 0x01B0, 0xD0A6, 0x81A9, 0x92A5, 0xA216, 0xC971, 0x961A,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x0817, 0x2EE5, 0xB309, 0x150F, 0x2BF1, 0x5A62, 0x2197, 0xB1C8,
-        ]);
+        let lhs = F2_128::from_u128(0xA95D_01B0_D0A6_81A9_92A5_A216_C971_961A);
+        let rhs = F2_128::from_u128(0x0817_2EE5_B309_150F_2BF1_5A62_2197_B1C8);
         let prod = WideF2x::from_f2x(
-            F2_128::from_limbs([
-                0x0543, 0x30B1, 0x8B03, 0x4A8E, 0x43F7, 0x29DB, 0x10A6, 0xBCB3,
-            ]),
-            F2_128::from_limbs([
-                0x7D9B, 0x512D, 0x94F5, 0x12B3, 0x8D64, 0xE68F, 0xEAFD, 0xC150,
-            ]),
+            F2_128::from_u128(0x0543_30B1_8B03_4A8E_43F7_29DB_10A6_BCB3),
+            F2_128::from_u128(0x7D9B_512D_94F5_12B3_8D64_E68F_EAFD_C150),
         );
         assert_eq!(lhs.widening_mul(&rhs), prod);
     }
 
     #[test]
     fn f2x_overflowing_mul() {
-        let lhs = F2_128::from_limbs([
-            0x0001, 0x0000, 0x0000, 0x0000, 0x06B1, 0xE8EB, 0x32EF, 0x6AAA,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x0001, 0x0000, 0x0000, 0x0000, 0x0186, 0xA0FD, 0x9642, 0x4426,
-        ]);
+        let lhs = F2_128::from_u128(0x0001_0000_0000_0000_06B1_E8EB_32EF_6AAA);
+        let rhs = F2_128::from_u128(0x0001_0000_0000_0000_0186_A0FD_9642_4426);
         assert!(lhs.overflowing_mul(&rhs).1);
 
-        let lhs = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x06B1, 0xE8EB, 0x32EF, 0x6AAA,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0186, 0xA0FD, 0x9642, 0x4426,
-        ]);
-        let prod = F2_128::from_limbs([
-            0x0005, 0xFD34, 0x46C8, 0xF72E, 0xCE4C, 0x24DA, 0xB75A, 0x02BC,
-        ]);
+        let lhs = F2_128::from_u128(0x0000_0000_0000_0000_06B1_E8EB_32EF_6AAA);
+        let rhs = F2_128::from_u128(0x0000_0000_0000_0000_0186_A0FD_9642_4426);
+        let prod = F2_128::from_u128(0x0005_FD34_46C8_F72E_CE4C_24DA_B75A_02BC);
         assert_eq!(lhs.overflowing_mul(&rhs), (prod, false));
 
-        let lhs = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x525A, 0x9736, 0x2694, 0x5DE5,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x1F37, 0x7287, 0x3F4B, 0xA664,
-        ]);
-        let prod = F2_128::from_limbs([
-            0x0606, 0x3225, 0x5465, 0x0782, 0x70A6, 0x76F4, 0x2208, 0xCC74,
-        ]);
+        let lhs = F2_128::from_u128(0x0000_0000_0000_0000_525A_9736_2694_5DE5);
+        let rhs = F2_128::from_u128(0x0000_0000_0000_0000_1F37_7287_3F4B_A664);
+        let prod = F2_128::from_u128(0x0606_3225_5465_0782_70A6_76F4_2208_CC74);
         assert_eq!(lhs.overflowing_mul(&rhs), (prod, false));
 
-        let lhs = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0xD2B8, 0xB8A6, 0x991B, 0x535A,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0xE421, 0x3447, 0x3878, 0xC838,
-        ]);
-        let prod = F2_128::from_limbs([
-            0x44F5, 0x1623, 0xF34C, 0xA777, 0x0929, 0x5A9E, 0xFF54, 0x9430,
-        ]);
+        let lhs = F2_128::from_u128(0x0000_0000_0000_0000_D2B8_B8A6_991B_535A);
+        let rhs = F2_128::from_u128(0x0000_0000_0000_0000_E421_3447_3878_C838);
+        let prod = F2_128::from_u128(0x44F5_1623_F34C_A777_0929_5A9E_FF54_9430);
         assert_eq!(lhs.overflowing_mul(&rhs), (prod, false));
 
-        let lhs = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0xF848, 0x5267, 0x8733, 0xC042,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x6C48, 0x5650, 0xA0CA, 0x8504,
-        ]);
-        let prod = F2_128::from_limbs([
-            0x2501, 0x9EEF, 0x6C78, 0x03EF, 0x1E6E, 0xB1FC, 0xF33B, 0x4B08,
-        ]);
+        let lhs = F2_128::from_u128(0x0000_0000_0000_0000_F848_5267_8733_C042);
+        let rhs = F2_128::from_u128(0x0000_0000_0000_0000_6C48_5650_A0CA_8504);
+        let prod = F2_128::from_u128(0x2501_9EEF_6C78_03EF_1E6E_B1FC_F33B_4B08);
         assert_eq!(lhs.overflowing_mul(&rhs), (prod, false));
 
-        let lhs = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0xFD5B, 0x6635, 0x077F, 0x52AD,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0xCA34, 0x8F3E, 0x59DE, 0x2856,
-        ]);
-        let prod = F2_128::from_limbs([
-            0x47F8, 0xF77D, 0x796D, 0xA04F, 0x7E0F, 0x87BA, 0x5374, 0xA67E,
-        ]);
+        let lhs = F2_128::from_u128(0x0000_0000_0000_0000_FD5B_6635_077F_52AD);
+        let rhs = F2_128::from_u128(0x0000_0000_0000_0000_CA34_8F3E_59DE_2856);
+        let prod = F2_128::from_u128(0x47F8_F77D_796D_A04F_7E0F_87BA_5374_A67E);
         assert_eq!(lhs.overflowing_mul(&rhs), (prod, false));
     }
 
+    #[test]
+    #[cfg(not(feature = "debug-secrets"))]
+    fn f2x_debug_is_redacted() {
+        let poly = F2_128::ONE.shl(100);
+        let limbs = format!("F2x {{ limbs: [REDACTED; {LIMBS_128}] }}");
+        assert_eq!(format!("{poly:?}"), limbs);
+        assert_eq!(
+            format!("{:?}", poly.widen()),
+            format!("WideF2x {{ high: {limbs}, low: {limbs} }}")
+        );
+    }
+
+    /// Every limb is padded to its full width, so the output does not depend on the limb size;
+    /// the word-u32 and word-u64 CI jobs check the wider limbs
+    #[test]
+    fn f2x_upper_hex() {
+        let poly = F2_128::from_u128(0x0000_0001_0000_00AB_0000_0000_00C0_FFEE);
+        let hex = "00000001000000AB0000000000C0FFEE";
+        assert_eq!(format!("{poly:X}"), hex);
+        assert_eq!(format!("{:X}", poly.widen()), format!("{:0>64}", hex));
+    }
+
+    #[test]
+    fn f2x_ct_select_swap() {
+        let mut rng = rand::thread_rng();
+        let (lhs, rhs) = (F2_128::from_limbs(rng.gen()), F2_128::from_limbs(rng.gen()));
+        assert_eq!(F2_128::ct_select(&lhs, &rhs, false), lhs);
+        assert_eq!(F2_128::ct_select(&lhs, &rhs, true), rhs);
+
+        let (mut a, mut b) = (lhs, rhs);
+        F2_128::ct_swap(&mut a, &mut b, false);
+        assert_eq!((a, b), (lhs, rhs));
+        F2_128::ct_swap(&mut a, &mut b, true);
+        assert_eq!((a, b), (rhs, lhs));
+    }
+
+    #[test]
+    fn f2x_ct_eq() {
+        let lhs = F2_128::from_u128(0xA95D_01B0_D0A6_81A9_92A5_A216_C971_961A);
+        assert!(lhs.ct_eq(&lhs));
+        assert!(!lhs.ct_eq(&lhs.add(&F2_128::ONE)));
+        assert!(!lhs.ct_eq(&lhs.add(&F2_128::ONE.shl(127))));
+        assert!(F2_128::ZERO.ct_is_zero());
+        assert!(!F2_128::ONE.ct_is_zero());
+        assert!(F2_128::ONE.ct_is_one());
+        assert!(!F2_128::ONE.shl(16).ct_is_one());
+        assert!(!F2_128::ZERO.ct_is_one());
+    }
+
     #[test]
     fn f2x_leading_zeros() {
         assert_eq!(F2_128::ZERO.leading_zeros(), 128);
         assert_eq!(F2_128::ONE.leading_zeros(), 127);
         assert_eq!(F2_128::ZERO.not().leading_zeros(), 0);
         assert_eq!(
-            F2_128::from_limbs([0x0000, 0x01B0, 0xD0A6, 0x81A9, 0x92A5, 0xA216, 0xC971, 0x961A,])
-                .leading_zeros(),
+            F2_128::from_u128(0x0000_01B0_D0A6_81A9_92A5_A216_C971_961A).leading_zeros(),
             23
         );
     }
@@ -882,41 +2670,161 @@ mod tests {
         assert_eq!(F2_128::ONE.degree(), Degree::NonNegative(0));
         assert_eq!(F2_128::ZERO.not().degree(), Degree::NonNegative(127));
         assert_eq!(
-            F2_128::from_limbs([0x0000, 0x01B0, 0xD0A6, 0x81A9, 0x92A5, 0xA216, 0xC971, 0x961A,])
-                .degree(),
+            F2_128::from_u128(0x0000_01B0_D0A6_81A9_92A5_A216_C971_961A).degree(),
             Degree::NonNegative(104)
         );
     }
 
+    #[test]
+    fn random_overflowing_shifts() {
+        let mut rng = rand::thread_rng();
+        let poly = F2_128::from_limbs(rng.gen());
+        let wide = WideF2x::from_f2x(F2_128::ZERO, poly.shr(F2_128::BITS / 2));
+        for rhs in 0..(2 * WideF2x::<LIMBS_128>::BITS + 3) {
+            let (shifted, overflow) = poly.overflowing_shl(rhs);
+            (0..F2_128::BITS).for_each(|i| {
+                assert_eq!(shifted.bit(i), i >= rhs && poly.bit(i - rhs));
+            });
+            let lost = (0..F2_128::BITS).any(|i| poly.bit(i) && i + rhs >= F2_128::BITS);
+            assert_eq!(overflow, lost);
+
+            let (shifted, remainder) = poly.overflowing_shr(rhs);
+            (0..F2_128::BITS).for_each(|i| assert_eq!(shifted.bit(i), poly.bit(i + rhs)));
+            assert_eq!(remainder, (0..rhs).any(|i| poly.bit(i)));
+
+            let (shifted, overflow) = wide.overflowing_shl(rhs);
+            if rhs < WideF2x::<LIMBS_128>::BITS {
+                assert_eq!(shifted, wide.shl(rhs));
+            }
+            let lost = (0..WideF2x::<LIMBS_128>::BITS)
+                .any(|i| wide.bit(i) && i + rhs >= WideF2x::<LIMBS_128>::BITS);
+            assert_eq!(overflow, lost);
+            let (shifted, remainder) = wide.overflowing_shr(rhs);
+            (0..WideF2x::<LIMBS_128>::BITS)
+                .for_each(|i| assert_eq!(shifted.bit(i), wide.bit(i + rhs)));
+            assert_eq!(remainder, (0..rhs).any(|i| wide.bit(i)));
+        }
+        assert_eq!(
+            F2_128::ZERO.overflowing_shl(F2_128::BITS),
+            (F2_128::ZERO, false)
+        );
+        assert_eq!(F2_128::ZERO.trailing_zeros(), F2_128::BITS);
+        assert_eq!(
+            WideF2x::<LIMBS_128>::ZERO.trailing_zeros(),
+            WideF2x::<LIMBS_128>::BITS
+        );
+        assert_eq!(F2_128::from_u128(0b1000).trailing_zeros(), 3);
+    }
+
+    #[test]
+    fn f2x_ord_and_hash() {
+        let values = [0u128, 1, 2, 0x13, 0xFFFF, 1 << 64, u128::MAX];
+        let mut polys = values
+            .iter()
+            .rev()
+            .map(|value| F2_128::from_u128(*value))
+            .collect::<Vec<F2_128>>();
+        polys.sort();
+        assert_eq!(polys, values.map(F2_128::from_u128).to_vec());
+
+        let wide = |high: u128, low: u128| {
+            WideF2x::from_f2x(F2_128::from_u128(high), F2_128::from_u128(low))
+        };
+        assert!(wide(1, 0) > wide(0, u128::MAX));
+        assert!(wide(1, 2) < wide(1, 3));
+
+        let set = polys
+            .iter()
+            .chain(polys.iter())
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(set.len(), values.len());
+    }
+
+    #[test]
+    fn f2x_bits() {
+        let poly = F2_128::from_u128(0x8000_0000_0000_0000_0000_0000_0001_0013);
+        let set = [127, 16, 4, 1, 0];
+        (0..F2_128::BITS + 8).for_each(|i| assert_eq!(poly.bit(i), set.contains(&i)));
+        assert_eq!(poly.count_ones(), set.len());
+
+        let mut rebuilt = F2_128::ZERO;
+        set.iter().for_each(|i| rebuilt.set_bit(*i));
+        assert_eq!(rebuilt, poly);
+        rebuilt.clear_bit(127);
+        rebuilt.clear_bit(126);
+        assert_eq!(rebuilt, F2_128::from_u128(0x0001_0013));
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let wide =
+                WideF2x::from_f2x(F2_128::from_limbs(rng.gen()), F2_128::from_limbs(rng.gen()));
+            let ones = (0..WideF2x::<LIMBS_128>::BITS)
+                .filter(|i| wide.bit(*i))
+                .count();
+            assert_eq!(wide.count_ones(), ones);
+            let mut copy = wide;
+            copy.clear_bit(200);
+            copy.set_bit(3);
+            assert!(!copy.bit(200) && copy.bit(3));
+            match wide.degree() {
+                Degree::NonNegative(degree) => {
+                    assert!(wide.bit(degree));
+                    assert!((degree + 1..WideF2x::<LIMBS_128>::BITS).all(|i| !wide.bit(i)));
+                }
+                Degree::NegativeInfinity => assert!(wide.is_zero()),
+            }
+        }
+    }
+
+    #[test]
+    fn le_limbs() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let poly = F2_128::from_limbs(rng.gen());
+            let le_limbs = poly.to_le_limbs();
+            assert_eq!(F2_128::from_le_limbs(le_limbs), poly);
+            for (i, limb) in le_limbs.iter().enumerate() {
+                assert_eq!(poly.le_limb(i), Some(*limb));
+                assert_eq!(poly.get_limb(le_limbs.len() - 1 - i), Some(limb));
+            }
+            assert_eq!(poly.le_limb(le_limbs.len()), None);
+
+            // The byte serialization does not depend on how the limbs are indexed
+            let mut buf = vec![0u8; F2_128::BITS / 8];
+            poly.write_to_be_bytes(&mut buf);
+            let low_bytes = le_limbs[0].to_be_bytes();
+            assert_eq!(buf[buf.len() - low_bytes.len()..], low_bytes);
+
+            let mut copy = poly;
+            *copy.le_limb_mut(0).unwrap() ^= 1;
+            assert_eq!(copy, poly.add(&F2_128::ONE));
+            assert!(copy.le_limb_mut(le_limbs.len()).is_none());
+        }
+    }
+
     #[test]
     fn f2x_shl() {
-        let expected_poly = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0002,
-        ]);
+        let expected_poly = F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0002);
         assert_eq!(F2_128::ONE.shl(1), expected_poly);
         assert_eq!(
-            F2_128::from_limbs([0x0000, 0x01B0, 0xD0A6, 0x81A9, 0x92A5, 0xA216, 0xC971, 0x961A,])
-                .shl(4),
-            F2_128::from_limbs([0x0000, 0x1B0D, 0x0A68, 0x1A99, 0x2A5A, 0x216C, 0x9719, 0x61A0]),
+            F2_128::from_u128(0x0000_01B0_D0A6_81A9_92A5_A216_C971_961A).shl(4),
+            F2_128::from_u128(0x0000_1B0D_0A68_1A99_2A5A_216C_9719_61A0),
         );
         assert_eq!(
-            F2_128::from_limbs([0x0000, 0x01B0, 0xD0A6, 0x81A9, 0x92A5, 0xA216, 0xC971, 0x961A,])
-                .shl(16),
-            F2_128::from_limbs([0x01B0, 0xD0A6, 0x81A9, 0x92A5, 0xA216, 0xC971, 0x961A, 0x0000]),
+            F2_128::from_u128(0x0000_01B0_D0A6_81A9_92A5_A216_C971_961A).shl(16),
+            F2_128::from_u128(0x01B0_D0A6_81A9_92A5_A216_C971_961A_0000),
         );
     }
 
     #[test]
     fn f2x_shr() {
         assert_eq!(
-            F2_128::from_limbs([0x0000, 0x01B0, 0xD0A6, 0x81A9, 0x92A5, 0xA216, 0xC971, 0x961A,])
-                .shr(4),
-            F2_128::from_limbs([0x0000, 0x001B, 0x0D0A, 0x681A, 0x992A, 0x5A21, 0x6C97, 0x1961,]),
+            F2_128::from_u128(0x0000_01B0_D0A6_81A9_92A5_A216_C971_961A).shr(4),
+            F2_128::from_u128(0x0000_001B_0D0A_681A_992A_5A21_6C97_1961),
         );
         assert_eq!(
-            F2_128::from_limbs([0x0000, 0x01B0, 0xD0A6, 0x81A9, 0x92A5, 0xA216, 0xC971, 0x961A,])
-                .shr(16),
-            F2_128::from_limbs([0x0000, 0x0000, 0x01B0, 0xD0A6, 0x81A9, 0x92A5, 0xA216, 0xC971,]),
+            F2_128::from_u128(0x0000_01B0_D0A6_81A9_92A5_A216_C971_961A).shr(16),
+            F2_128::from_u128(0x0000_0000_01B0_D0A6_81A9_92A5_A216_C971),
         );
     }
 
@@ -937,45 +2845,30 @@ mod tests {
 
         // Random test cases
         assert_eq!(
-            F2_128::from_limbs([0x6F88, 0x6586, 0x5701, 0x2619, 0x964B, 0x2BCD, 0x0A5E, 0xAD0C])
-                .div_rem(&F2_128::from_limbs([
-                    0x1A02, 0x3B04, 0xCBB4, 0x3729, 0x5B9B, 0x4756, 0x35A0, 0x530B
-                ])),
+            F2_128::from_u128(0x6F88_6586_5701_2619_964B_2BCD_0A5E_AD0C).div_rem(
+                &F2_128::from_u128(0x1A02_3B04_CBB4_3729_5B9B_4756_35A0_530B)
+            ),
             (
-                F2_128::from_limbs([
-                    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0004
-                ]),
-                F2_128::from_limbs([
-                    0x0780, 0x8995, 0x79D1, 0xFABC, 0xF826, 0x3695, 0xDCDF, 0xE120
-                ])
+                F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0004),
+                F2_128::from_u128(0x0780_8995_79D1_FABC_F826_3695_DCDF_E120)
             )
         );
         assert_eq!(
-            F2_128::from_limbs([0x7AB3, 0xC7AC, 0x5F7B, 0xC3DC, 0x29AD, 0x137D, 0xAAD0, 0x7920])
-                .div_rem(&F2_128::from_limbs([
-                    0x4374, 0xFB75, 0x7A4D, 0x1BBC, 0xD872, 0xF253, 0x9CE8, 0x3F10
-                ])),
+            F2_128::from_u128(0x7AB3_C7AC_5F7B_C3DC_29AD_137D_AAD0_7920).div_rem(
+                &F2_128::from_u128(0x4374_FB75_7A4D_1BBC_D872_F253_9CE8_3F10)
+            ),
             (
-                F2_128::from_limbs([
-                    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001
-                ]),
-                F2_128::from_limbs([
-                    0x39C7, 0x3CD9, 0x2536, 0xD860, 0xF1DF, 0xE12E, 0x3638, 0x4630
-                ])
+                F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001),
+                F2_128::from_u128(0x39C7_3CD9_2536_D860_F1DF_E12E_3638_4630)
             )
         );
         assert_eq!(
-            F2_128::from_limbs([0x3C84, 0xBCBF, 0xFAB8, 0xAC77, 0xDBC8, 0xA478, 0x1D91, 0xBC64])
-                .div_rem(&F2_128::from_limbs([
-                    0x0000, 0x0000, 0x0000, 0x0000, 0xF943, 0xE449, 0xAF54, 0xEA20
-                ])),
+            F2_128::from_u128(0x3C84_BCBF_FAB8_AC77_DBC8_A478_1D91_BC64).div_rem(
+                &F2_128::from_u128(0x0000_0000_0000_0000_F943_E449_AF54_EA20)
+            ),
             (
-                F2_128::from_limbs([
-                    0x0000, 0x0000, 0x0000, 0x0000, 0x474D, 0x1926, 0x9A35, 0x429A
-                ]),
-                F2_128::from_limbs([
-                    0x0000, 0x0000, 0x0000, 0x0000, 0x4C23, 0xF643, 0x1158, 0xCB24
-                ])
+                F2_128::from_u128(0x0000_0000_0000_0000_474D_1926_9A35_429A),
+                F2_128::from_u128(0x0000_0000_0000_0000_4C23_F643_1158_CB24)
             )
         );
     }
@@ -1009,37 +2902,25 @@ mod tests {
     fn widef2x_overflowing_mul() {
         let lhs = WideF2x::from_f2x(
             F2_128::ZERO,
-            F2_128::from_limbs([
-                0x3DCC, 0x5CE2, 0x8A9D, 0x3FE3, 0x5309, 0x07F3, 0xC9FD, 0x43B6,
-            ]),
+            F2_128::from_u128(0x3DCC_5CE2_8A9D_3FE3_5309_07F3_C9FD_43B6),
         );
         let rhs = WideF2x::from_f2x(
             F2_128::ZERO,
-            F2_128::from_limbs([
-                0x8370, 0x7DA9, 0x108D, 0xF5B7, 0x30C9, 0xAEB8, 0x719A, 0xEDB5,
-            ]),
+            F2_128::from_u128(0x8370_7DA9_108D_F5B7_30C9_AEB8_719A_EDB5),
         );
         let prod = WideF2x::from_f2x(
-            F2_128::from_limbs([
-                0x1EAB, 0x66E7, 0x4160, 0x869E, 0xA3A7, 0x038E, 0x03AB, 0x25BF,
-            ]),
-            F2_128::from_limbs([
-                0x77C9, 0xE332, 0x2107, 0x2707, 0x8AFD, 0x8E14, 0xE779, 0x45CE,
-            ]),
+            F2_128::from_u128(0x1EAB_66E7_4160_869E_A3A7_038E_03AB_25BF),
+            F2_128::from_u128(0x77C9_E332_2107_2707_8AFD_8E14_E779_45CE),
         );
         assert_eq!(lhs.overflowing_mul(&rhs), (prod, false));
 
         let lhs = WideF2x::from_f2x(
             F2_128::ONE,
-            F2_128::from_limbs([
-                0x3DCC, 0x5CE2, 0x8A9D, 0x3FE3, 0x5309, 0x07F3, 0xC9FD, 0x43B6,
-            ]),
+            F2_128::from_u128(0x3DCC_5CE2_8A9D_3FE3_5309_07F3_C9FD_43B6),
         );
         let rhs = WideF2x::from_f2x(
             F2_128::ONE,
-            F2_128::from_limbs([
-                0x8370, 0x7DA9, 0x108D, 0xF5B7, 0x30C9, 0xAEB8, 0x719A, 0xEDB5,
-            ]),
+            F2_128::from_u128(0x8370_7DA9_108D_F5B7_30C9_AEB8_719A_EDB5),
         );
         assert!(lhs.overflowing_mul(&rhs).1);
     }
@@ -1062,63 +2943,61 @@ mod tests {
 
     #[test]
     fn widef2x_div_rem() {
-        let lhs = F2_128::from_limbs([
-            0x3DCC, 0x5CE2, 0x8A9D, 0x3FE3, 0x5309, 0x07F3, 0xC9FD, 0x43B6,
-        ])
-        .widening_mul(&F2_128::from_limbs([
-            0x8370, 0x7DA9, 0x108D, 0xF5B7, 0x30C9, 0xAEB8, 0x719A, 0xEDB5,
-        ]));
+        let lhs = F2_128::from_u128(0x3DCC_5CE2_8A9D_3FE3_5309_07F3_C9FD_43B6).widening_mul(
+            &F2_128::from_u128(0x8370_7DA9_108D_F5B7_30C9_AEB8_719A_EDB5),
+        );
         let quot = WideF2x::from_f2x(
             F2_128::ZERO,
-            F2_128::from_limbs([
-                0x1EAB, 0x66E7, 0x4160, 0x869E, 0xA3A7, 0x038E, 0x03AB, 0x25BF,
-            ]),
+            F2_128::from_u128(0x1EAB_66E7_4160_869E_A3A7_038E_03AB_25BF),
         );
         let rem = WideF2x::from_f2x(
             F2_128::ZERO,
-            F2_128::from_limbs([
-                0x77C9, 0xE332, 0x2107, 0x2707, 0x8AFD, 0x8E14, 0xE779, 0x45CE,
-            ]),
+            F2_128::from_u128(0x77C9_E332_2107_2707_8AFD_8E14_E779_45CE),
         );
         let rhs = WideF2x::from_f2x(F2_128::ONE, F2_128::ZERO);
         assert_eq!(lhs.div_rem(&rhs), (quot, rem));
     }
 
+    #[test]
+    fn random_div_rem_ct() {
+        let mut rng = rand::thread_rng();
+        let divisors = [
+            WideF2x::<LIMBS_128>::from_exponents(&[128, 77, 35, 11, 0]),
+            WideF2x::<LIMBS_128>::from_exponents(&[255, 0]),
+            WideF2x::<LIMBS_128>::ONE,
+        ];
+        for _ in 0..10 {
+            let value = WideF2x::<LIMBS_128>::from_f2x(
+                F2_128::from_limbs(rng.gen()),
+                F2_128::from_limbs(rng.gen()),
+            );
+            for divisor in divisors {
+                assert_eq!(value.div_rem_ct(&divisor), value.div_rem(&divisor));
+            }
+        }
+        let ones = WideF2x::from_f2x(F2_128::ZERO.not(), F2_128::ZERO.not());
+        assert_eq!(ones.div_rem_ct(&divisors[0]), ones.div_rem(&divisors[0]));
+    }
+
     #[test]
     fn f2x_modmul() {
-        let lhs = F2_128::from_limbs([
-            0x1A02, 0xC5D5, 0x3035, 0x2794, 0xBAE0, 0x9A71, 0x0B95, 0xD81A,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0xC9BE, 0xD58C, 0x706B, 0x3D2B, 0xF2FE, 0x1C1B, 0xAFAA, 0x1F84,
-        ]);
+        let lhs = F2_128::from_u128(0x1A02_C5D5_3035_2794_BAE0_9A71_0B95_D81A);
+        let rhs = F2_128::from_u128(0xC9BE_D58C_706B_3D2B_F2FE_1C1B_AFAA_1F84);
         let modulus = WideF2x::from_f2x(
             F2_128::ONE,
-            F2_128::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0085,
-            ]),
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0085),
         );
-        let rem = F2_128::from_limbs([
-            0x2CD6, 0x23A4, 0x9F7D, 0xEA15, 0x62DD, 0x69E1, 0x63D9, 0xB940,
-        ]);
+        let rem = F2_128::from_u128(0x2CD6_23A4_9F7D_EA15_62DD_69E1_63D9_B940);
 
         assert_eq!(lhs.modmul(&rhs, &modulus), rem);
 
-        let lhs = F2_128::from_limbs([
-            0x292C, 0xFAE0, 0x70BB, 0xC697, 0xEF2B, 0x325B, 0x3CE3, 0x1AEF,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x212F, 0x8E8A, 0xCF1E, 0x5621, 0x12C1, 0xADE4, 0x326B, 0x3D5F,
-        ]);
+        let lhs = F2_128::from_u128(0x292C_FAE0_70BB_C697_EF2B_325B_3CE3_1AEF);
+        let rhs = F2_128::from_u128(0x212F_8E8A_CF1E_5621_12C1_ADE4_326B_3D5F);
         let modulus = WideF2x::from_f2x(
             F2_128::ONE,
-            F2_128::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0085,
-            ]),
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0085),
         );
-        let rem = F2_128::from_limbs([
-            0x248A, 0xC6E8, 0xEF4F, 0xD895, 0x5C44, 0xC8A9, 0x7F71, 0x3518,
-        ]);
+        let rem = F2_128::from_u128(0x248A_C6E8_EF4F_D895_5C44_C8A9_7F71_3518);
 
         assert_eq!(lhs.modmul(&rhs, &modulus), rem);
     }
@@ -1128,613 +3007,392 @@ mod tests {
     fn random_f2x_gcd() {
         assert_eq!(
             F2_128::gcd(
-                &F2_128::from_limbs([
-                    0x88D3, 0xC663, 0x66EC, 0x77EC, 0xF526, 0x6510, 0x5C19, 0x6517
-                ]),
-                &F2_128::from_limbs([
-                    0x23E6, 0x86AB, 0x9E60, 0x2E74, 0x6BE2, 0x87A8, 0x3D6B, 0x651D
-                ]),
+                &F2_128::from_u128(0x88D3_C663_66EC_77EC_F526_6510_5C19_6517),
+                &F2_128::from_u128(0x23E6_86AB_9E60_2E74_6BE2_87A8_3D6B_651D),
             ),
-            F2_128::from_limbs([0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x000D])
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_000D)
         );
 
         assert_eq!(
             F2_128::gcd(
-                &F2_128::from_limbs([
-                    0xF0D9, 0x2027, 0xBC65, 0x0F0D, 0xE5EB, 0xD78D, 0x967D, 0x489C
-                ]),
-                &F2_128::from_limbs([
-                    0x8F6A, 0xD588, 0xC423, 0xA33D, 0x6819, 0x6C4E, 0x676C, 0xE06F
-                ]),
+                &F2_128::from_u128(0xF0D9_2027_BC65_0F0D_E5EB_D78D_967D_489C),
+                &F2_128::from_u128(0x8F6A_D588_C423_A33D_6819_6C4E_676C_E06F),
             ),
-            F2_128::from_limbs([0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001])
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001)
         );
 
         assert_eq!(
             F2_128::gcd(
-                &F2_128::from_limbs([
-                    0xB3D5, 0x9549, 0xCF5D, 0x897A, 0xC79E, 0xC029, 0xB84B, 0xAC6A
-                ]),
-                &F2_128::from_limbs([
-                    0xA495, 0xDB48, 0x0E91, 0x2ED4, 0x6B75, 0xF726, 0xBC16, 0xF311
-                ]),
+                &F2_128::from_u128(0xB3D5_9549_CF5D_897A_C79E_C029_B84B_AC6A),
+                &F2_128::from_u128(0xA495_DB48_0E91_2ED4_6B75_F726_BC16_F311),
             ),
-            F2_128::from_limbs([0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0007])
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0007)
         );
 
         assert_eq!(
             F2_128::gcd(
-                &F2_128::from_limbs([
-                    0xC6D2, 0xA56B, 0xFEBC, 0x0BC0, 0xFAB8, 0x54AC, 0xF72B, 0x8EE6
-                ]),
-                &F2_128::from_limbs([
-                    0x1585, 0xD7E5, 0x319B, 0x34E5, 0x0628, 0xD2B8, 0x84CB, 0x9DB3
-                ]),
+                &F2_128::from_u128(0xC6D2_A56B_FEBC_0BC0_FAB8_54AC_F72B_8EE6),
+                &F2_128::from_u128(0x1585_D7E5_319B_34E5_0628_D2B8_84CB_9DB3),
             ),
-            F2_128::from_limbs([0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x000B])
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_000B)
         );
 
         assert_eq!(
             F2_128::gcd(
-                &F2_128::from_limbs([
-                    0xA2D7, 0xB6DF, 0xECE2, 0xF5F9, 0x9358, 0x001F, 0xC58B, 0x6998
-                ]),
-                &F2_128::from_limbs([
-                    0x53B3, 0xDE89, 0x8495, 0x65F2, 0x2746, 0x2197, 0xF043, 0x0E20
-                ]),
+                &F2_128::from_u128(0xA2D7_B6DF_ECE2_F5F9_9358_001F_C58B_6998),
+                &F2_128::from_u128(0x53B3_DE89_8495_65F2_2746_2197_F043_0E20),
             ),
-            F2_128::from_limbs([0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0308])
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0308)
         );
 
         assert_eq!(
             F2_128::gcd(
-                &F2_128::from_limbs([
-                    0xC01A, 0x9409, 0xDF5B, 0x988E, 0x427B, 0xA208, 0x6DEE, 0x5082
-                ]),
-                &F2_128::from_limbs([
-                    0x80C7, 0xDA18, 0x255E, 0xC295, 0x4796, 0x263A, 0x0263, 0x1603
-                ]),
+                &F2_128::from_u128(0xC01A_9409_DF5B_988E_427B_A208_6DEE_5082),
+                &F2_128::from_u128(0x80C7_DA18_255E_C295_4796_263A_0263_1603),
             ),
-            F2_128::from_limbs([0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001])
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001)
         );
 
         assert_eq!(
             F2_128::gcd(
-                &F2_128::from_limbs([
-                    0x1BA5, 0x59C7, 0x51A8, 0x8633, 0x6996, 0xDDE2, 0xFD11, 0xBC10
-                ]),
-                &F2_128::from_limbs([
-                    0x3873, 0x9182, 0xEAC5, 0x205D, 0x7D38, 0x04AE, 0x972C, 0x906C
-                ]),
+                &F2_128::from_u128(0x1BA5_59C7_51A8_8633_6996_DDE2_FD11_BC10),
+                &F2_128::from_u128(0x3873_9182_EAC5_205D_7D38_04AE_972C_906C),
             ),
-            F2_128::from_limbs([0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0004])
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0004)
         );
 
         assert_eq!(
             F2_128::gcd(
-                &F2_128::from_limbs([
-                    0x478F, 0x4705, 0x220D, 0xAC34, 0x171F, 0xAF89, 0x15AC, 0x1700
-                ]),
-                &F2_128::from_limbs([
-                    0xC9C4, 0xCE2D, 0x7266, 0xC78B, 0x3DBB, 0x589C, 0xDCDF, 0x8929
-                ]),
+                &F2_128::from_u128(0x478F_4705_220D_AC34_171F_AF89_15AC_1700),
+                &F2_128::from_u128(0xC9C4_CE2D_7266_C78B_3DBB_589C_DCDF_8929),
             ),
-            F2_128::from_limbs([0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001])
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001)
         );
 
         assert_eq!(
             F2_128::gcd(
-                &F2_128::from_limbs([
-                    0x6480, 0x2AA3, 0x2F8A, 0x8B81, 0x1371, 0x75ED, 0x7B98, 0x17D9
-                ]),
-                &F2_128::from_limbs([
-                    0xF899, 0x9ABB, 0xD6F7, 0x2998, 0xB5BF, 0xBB6D, 0xF0EC, 0xABA9
-                ]),
+                &F2_128::from_u128(0x6480_2AA3_2F8A_8B81_1371_75ED_7B98_17D9),
+                &F2_128::from_u128(0xF899_9ABB_D6F7_2998_B5BF_BB6D_F0EC_ABA9),
             ),
-            F2_128::from_limbs([0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001])
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001)
         );
 
         assert_eq!(
             F2_128::gcd(
-                &F2_128::from_limbs([
-                    0xD641, 0x00B5, 0xC808, 0x3961, 0x2CD7, 0xD722, 0xD9C9, 0x3C09
-                ]),
-                &F2_128::from_limbs([
-                    0x2E55, 0xB6A1, 0x3FB4, 0x2C56, 0x5E1C, 0xEE4D, 0xA7A2, 0x6130
-                ]),
+                &F2_128::from_u128(0xD641_00B5_C808_3961_2CD7_D722_D9C9_3C09),
+                &F2_128::from_u128(0x2E55_B6A1_3FB4_2C56_5E1C_EE4D_A7A2_6130),
             ),
-            F2_128::from_limbs([0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001])
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001)
         );
     }
 
     #[test]
     fn random_f2x_xgcd() {
-        let lhs = F2_128::from_limbs([
-            0x4746, 0x4DE9, 0xB867, 0x3F15, 0xDB58, 0xBFE4, 0xAE50, 0x9F35,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0xCD9E, 0x5830, 0x5EDB, 0x11F2, 0x9232, 0x419A, 0xB9E3, 0xA334,
-        ]);
-        let expected_s = F2_128::from_limbs([
-            0x0DA5, 0x30C5, 0x52A7, 0x9083, 0x5354, 0x1B1F, 0xCA7C, 0xE235,
-        ]);
-        let expected_t = F2_128::from_limbs([
-            0x04A4, 0xF401, 0x528E, 0xB496, 0xE555, 0x39AE, 0xB7A9, 0xE274,
-        ]);
-        let expected_d = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001,
-        ]);
+        let lhs = F2_128::from_u128(0x4746_4DE9_B867_3F15_DB58_BFE4_AE50_9F35);
+        let rhs = F2_128::from_u128(0xCD9E_5830_5EDB_11F2_9232_419A_B9E3_A334);
+        let expected_s = F2_128::from_u128(0x0DA5_30C5_52A7_9083_5354_1B1F_CA7C_E235);
+        let expected_t = F2_128::from_u128(0x04A4_F401_528E_B496_E555_39AE_B7A9_E274);
+        let expected_d = F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001);
         let (s, t, divisor) = F2_128::xgcd(&lhs, &rhs);
         assert_eq!((s, t, divisor), (expected_s, expected_t, expected_d));
 
-        let lhs = F2_128::from_limbs([
-            0xDC28, 0x1585, 0x36FC, 0x9CE3, 0xE075, 0x7C5B, 0x0B0F, 0xEE28,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x8456, 0xF362, 0x3C86, 0x0D2F, 0x2A71, 0x2C57, 0x42EA, 0xBEF3,
-        ]);
-        let expected_s = F2_128::from_limbs([
-            0x7760, 0xB406, 0xE230, 0x3311, 0x2E48, 0x971D, 0xE703, 0xCA4E,
-        ]);
-        let expected_t = F2_128::from_limbs([
-            0x44E5, 0xDF01, 0xAFCB, 0x32F7, 0x610A, 0x6AA8, 0x1E8E, 0xA15F,
-        ]);
-        let expected_d = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001,
-        ]);
+        let lhs = F2_128::from_u128(0xDC28_1585_36FC_9CE3_E075_7C5B_0B0F_EE28);
+        let rhs = F2_128::from_u128(0x8456_F362_3C86_0D2F_2A71_2C57_42EA_BEF3);
+        let expected_s = F2_128::from_u128(0x7760_B406_E230_3311_2E48_971D_E703_CA4E);
+        let expected_t = F2_128::from_u128(0x44E5_DF01_AFCB_32F7_610A_6AA8_1E8E_A15F);
+        let expected_d = F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001);
         let (s, t, divisor) = F2_128::xgcd(&lhs, &rhs);
         assert_eq!((s, t, divisor), (expected_s, expected_t, expected_d));
 
-        let lhs = F2_128::from_limbs([
-            0x0A0B, 0xA3AD, 0x178E, 0x4E43, 0xA028, 0x0178, 0xE8C1, 0xBA6E,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0xC3FA, 0x7569, 0x4509, 0xC88A, 0xA16B, 0x0121, 0x2A00, 0xD254,
-        ]);
-        let expected_s = F2_128::from_limbs([
-            0x13A6, 0xE946, 0xFF13, 0x6807, 0x329C, 0x936D, 0xDEF4, 0x8027,
-        ]);
-        let expected_t = F2_128::from_limbs([
-            0x01A1, 0x86C5, 0x07BB, 0x927E, 0x9837, 0x8143, 0xB62B, 0xAFFA,
-        ]);
-        let expected_d = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0002,
-        ]);
+        let lhs = F2_128::from_u128(0x0A0B_A3AD_178E_4E43_A028_0178_E8C1_BA6E);
+        let rhs = F2_128::from_u128(0xC3FA_7569_4509_C88A_A16B_0121_2A00_D254);
+        let expected_s = F2_128::from_u128(0x13A6_E946_FF13_6807_329C_936D_DEF4_8027);
+        let expected_t = F2_128::from_u128(0x01A1_86C5_07BB_927E_9837_8143_B62B_AFFA);
+        let expected_d = F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0002);
         let (s, t, divisor) = F2_128::xgcd(&lhs, &rhs);
         assert_eq!((s, t, divisor), (expected_s, expected_t, expected_d));
 
-        let lhs = F2_128::from_limbs([
-            0xAE40, 0x001C, 0x1F77, 0x6239, 0x1B8D, 0xDDBD, 0x4010, 0x571B,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x3F65, 0x16D7, 0x80C3, 0x4D2F, 0xE702, 0xA2AC, 0xF894, 0xF8B2,
-        ]);
-        let expected_s = F2_128::from_limbs([
-            0x0ECE, 0xC430, 0xA308, 0x7B8A, 0x61B4, 0x9431, 0xE44F, 0x9D61,
-        ]);
-        let expected_t = F2_128::from_limbs([
-            0x2C21, 0xF0E7, 0xEA61, 0x023D, 0x05AC, 0x45D7, 0x6AB9, 0x09FC,
-        ]);
-        let expected_d = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0003,
-        ]);
+        let lhs = F2_128::from_u128(0xAE40_001C_1F77_6239_1B8D_DDBD_4010_571B);
+        let rhs = F2_128::from_u128(0x3F65_16D7_80C3_4D2F_E702_A2AC_F894_F8B2);
+        let expected_s = F2_128::from_u128(0x0ECE_C430_A308_7B8A_61B4_9431_E44F_9D61);
+        let expected_t = F2_128::from_u128(0x2C21_F0E7_EA61_023D_05AC_45D7_6AB9_09FC);
+        let expected_d = F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0003);
         let (s, t, divisor) = F2_128::xgcd(&lhs, &rhs);
         assert_eq!((s, t, divisor), (expected_s, expected_t, expected_d));
 
-        let lhs = F2_128::from_limbs([
-            0x70C3, 0x2E36, 0xC1F8, 0x0858, 0x9668, 0x4B8B, 0x7DC7, 0x8C0B,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x8B91, 0x441A, 0x1C82, 0x83F5, 0x0BC4, 0x499A, 0x9BAE, 0xEC2E,
-        ]);
-        let expected_s = F2_128::from_limbs([
-            0x1AB7, 0x6742, 0x08B8, 0x4488, 0x159F, 0xC45A, 0x8A1F, 0x07BF,
-        ]);
-        let expected_t = F2_128::from_limbs([
-            0x080E, 0xE174, 0xA328, 0x94B8, 0x8266, 0x4093, 0xC73B, 0x9BD9,
-        ]);
-        let expected_d = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0007,
-        ]);
+        let lhs = F2_128::from_u128(0x70C3_2E36_C1F8_0858_9668_4B8B_7DC7_8C0B);
+        let rhs = F2_128::from_u128(0x8B91_441A_1C82_83F5_0BC4_499A_9BAE_EC2E);
+        let expected_s = F2_128::from_u128(0x1AB7_6742_08B8_4488_159F_C45A_8A1F_07BF);
+        let expected_t = F2_128::from_u128(0x080E_E174_A328_94B8_8266_4093_C73B_9BD9);
+        let expected_d = F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0007);
         let (s, t, divisor) = F2_128::xgcd(&lhs, &rhs);
         assert_eq!((s, t, divisor), (expected_s, expected_t, expected_d));
 
-        let lhs = F2_128::from_limbs([
-            0x94DF, 0xEE78, 0x4A39, 0x9065, 0x4ECE, 0xE5CC, 0x57D4, 0x5C90,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0xE79F, 0xE6A0, 0xAA2C, 0x5817, 0x68A9, 0x2F9F, 0x5660, 0x73EF,
-        ]);
-        let expected_s = F2_128::from_limbs([
-            0x277D, 0x4C20, 0x7788, 0xBB9F, 0x5912, 0x8E66, 0xD790, 0x5957,
-        ]);
-        let expected_t = F2_128::from_limbs([
-            0x34F2, 0xE96F, 0x5AD0, 0x5CC2, 0x37FB, 0xFA75, 0xFE74, 0x1F43,
-        ]);
-        let expected_d = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001,
-        ]);
+        let lhs = F2_128::from_u128(0x94DF_EE78_4A39_9065_4ECE_E5CC_57D4_5C90);
+        let rhs = F2_128::from_u128(0xE79F_E6A0_AA2C_5817_68A9_2F9F_5660_73EF);
+        let expected_s = F2_128::from_u128(0x277D_4C20_7788_BB9F_5912_8E66_D790_5957);
+        let expected_t = F2_128::from_u128(0x34F2_E96F_5AD0_5CC2_37FB_FA75_FE74_1F43);
+        let expected_d = F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001);
         let (s, t, divisor) = F2_128::xgcd(&lhs, &rhs);
         assert_eq!((s, t, divisor), (expected_s, expected_t, expected_d));
 
-        let lhs = F2_128::from_limbs([
-            0x95DF, 0x4A39, 0x5AEA, 0xD3E9, 0x3B31, 0xCF9E, 0xA082, 0x0C02,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x60F3, 0x0B74, 0xB7AB, 0x8B48, 0x9D1F, 0x693B, 0xEFE8, 0xAFAC,
-        ]);
-        let expected_s = F2_128::from_limbs([
-            0x1A1D, 0xBED7, 0x7BD9, 0x99BE, 0x5491, 0x182E, 0x7AB1, 0x3DF1,
-        ]);
-        let expected_t = F2_128::from_limbs([
-            0x223E, 0x1D1E, 0x8229, 0xF29F, 0xF020, 0x2121, 0x2CEB, 0x1B68,
-        ]);
-        let expected_d = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0002,
-        ]);
+        let lhs = F2_128::from_u128(0x95DF_4A39_5AEA_D3E9_3B31_CF9E_A082_0C02);
+        let rhs = F2_128::from_u128(0x60F3_0B74_B7AB_8B48_9D1F_693B_EFE8_AFAC);
+        let expected_s = F2_128::from_u128(0x1A1D_BED7_7BD9_99BE_5491_182E_7AB1_3DF1);
+        let expected_t = F2_128::from_u128(0x223E_1D1E_8229_F29F_F020_2121_2CEB_1B68);
+        let expected_d = F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0002);
         let (s, t, divisor) = F2_128::xgcd(&lhs, &rhs);
         assert_eq!((s, t, divisor), (expected_s, expected_t, expected_d));
 
-        let lhs = F2_128::from_limbs([
-            0xB2AC, 0x5425, 0xD230, 0x1A5B, 0x847A, 0xF133, 0x37BD, 0xC23B,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x5FD9, 0xF185, 0x1193, 0x6CD9, 0x0277, 0xEDAA, 0xD520, 0xCDBE,
-        ]);
-        let expected_s = F2_128::from_limbs([
-            0x31CA, 0x7C63, 0x8B6E, 0x9028, 0xF895, 0x9009, 0xEA7D, 0xDB1D,
-        ]);
-        let expected_t = F2_128::from_limbs([
-            0x6784, 0x1600, 0x6CD1, 0xDC98, 0x9808, 0x1058, 0x18C9, 0x7801,
-        ]);
-        let expected_d = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001,
-        ]);
+        let lhs = F2_128::from_u128(0xB2AC_5425_D230_1A5B_847A_F133_37BD_C23B);
+        let rhs = F2_128::from_u128(0x5FD9_F185_1193_6CD9_0277_EDAA_D520_CDBE);
+        let expected_s = F2_128::from_u128(0x31CA_7C63_8B6E_9028_F895_9009_EA7D_DB1D);
+        let expected_t = F2_128::from_u128(0x6784_1600_6CD1_DC98_9808_1058_18C9_7801);
+        let expected_d = F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001);
         let (s, t, divisor) = F2_128::xgcd(&lhs, &rhs);
         assert_eq!((s, t, divisor), (expected_s, expected_t, expected_d));
 
-        let lhs = F2_128::from_limbs([
-            0x4253, 0x5575, 0x05C3, 0x2E2F, 0x47BB, 0xDD26, 0x156C, 0x9397,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x65E3, 0xA49E, 0x4049, 0xA7E7, 0xA96D, 0xC55C, 0xB7F2, 0x975F,
-        ]);
-        let expected_s = F2_128::from_limbs([
-            0x01D3, 0x574C, 0xE524, 0x0E33, 0xA6C1, 0xB763, 0x65BD, 0xCD90,
-        ]);
-        let expected_t = F2_128::from_limbs([
-            0x0176, 0xAB05, 0x0265, 0x4A3F, 0x6D58, 0xEF03, 0xA981, 0xE445,
-        ]);
-        let expected_d = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0013,
-        ]);
+        let lhs = F2_128::from_u128(0x4253_5575_05C3_2E2F_47BB_DD26_156C_9397);
+        let rhs = F2_128::from_u128(0x65E3_A49E_4049_A7E7_A96D_C55C_B7F2_975F);
+        let expected_s = F2_128::from_u128(0x01D3_574C_E524_0E33_A6C1_B763_65BD_CD90);
+        let expected_t = F2_128::from_u128(0x0176_AB05_0265_4A3F_6D58_EF03_A981_E445);
+        let expected_d = F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0013);
         let (s, t, divisor) = F2_128::xgcd(&lhs, &rhs);
         assert_eq!((s, t, divisor), (expected_s, expected_t, expected_d));
 
-        let lhs = F2_128::from_limbs([
-            0x2E46, 0x1AB1, 0x8719, 0xB670, 0x49D4, 0x3EE3, 0xD927, 0x019E,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x3FA7, 0xAA63, 0xE426, 0x84EA, 0x03BB, 0xB6F5, 0x8E43, 0xF797,
-        ]);
-        let expected_s = F2_128::from_limbs([
-            0x1F46, 0xF302, 0x24E0, 0x27EA, 0xFADB, 0x5FEA, 0x37B8, 0xA278,
-        ]);
-        let expected_t = F2_128::from_limbs([
-            0x17DD, 0x9394, 0xD239, 0x14F8, 0x0868, 0xD45D, 0x9347, 0x217B,
-        ]);
-        let expected_d = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001,
-        ]);
+        let lhs = F2_128::from_u128(0x2E46_1AB1_8719_B670_49D4_3EE3_D927_019E);
+        let rhs = F2_128::from_u128(0x3FA7_AA63_E426_84EA_03BB_B6F5_8E43_F797);
+        let expected_s = F2_128::from_u128(0x1F46_F302_24E0_27EA_FADB_5FEA_37B8_A278);
+        let expected_t = F2_128::from_u128(0x17DD_9394_D239_14F8_0868_D45D_9347_217B);
+        let expected_d = F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001);
         let (s, t, divisor) = F2_128::xgcd(&lhs, &rhs);
         assert_eq!((s, t, divisor), (expected_s, expected_t, expected_d));
     }
 
     #[test]
     fn random_widef2x_gcd() {
-        let lhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0xA84F, 0xED17, 0xFF3E, 0x9B60, 0x2EA8, 0x3480, 0xC4E6, 0x6981,
-            ]),
-            F2x::<8>::from_limbs([
-                0x8174, 0x57C6, 0x54ED, 0x250C, 0xC8A6, 0x3D3B, 0x6F64, 0x7A27,
-            ]),
-        );
-        let rhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x7139, 0x9134, 0x3CAA, 0x16DD, 0xD47E, 0x83CF, 0x7006, 0xF923,
-            ]),
-            F2x::<8>::from_limbs([
-                0x203A, 0xEF7E, 0x0745, 0x6DC9, 0x003B, 0xF271, 0xF403, 0xCF9F,
-            ]),
-        );
-        let gcd = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-            ]),
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001,
-            ]),
-        );
-        assert_eq!(WideF2x::<8>::gcd(&lhs, &rhs), gcd);
-
-        let lhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0xEC10, 0x1284, 0x0415, 0x8B0E, 0xD842, 0x5F1C, 0xCCC3, 0x2903,
-            ]),
-            F2x::<8>::from_limbs([
-                0xBC0E, 0xFBFC, 0x0430, 0x7C73, 0x40AB, 0x5717, 0x3F59, 0xCBA5,
-            ]),
-        );
-        let rhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x5C85, 0xE20D, 0x1371, 0x9C06, 0x02C2, 0x7CD7, 0xCF41, 0xD5BB,
-            ]),
-            F2x::<8>::from_limbs([
-                0xEFC2, 0x1B46, 0xCBCD, 0x1887, 0xCC3E, 0x08D7, 0xBC57, 0x429D,
-            ]),
-        );
-        let gcd = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-            ]),
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001,
-            ]),
-        );
-        assert_eq!(WideF2x::<8>::gcd(&lhs, &rhs), gcd);
-
-        let lhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0xACC6, 0x7E82, 0x1082, 0x4A1A, 0xE365, 0x2B0B, 0x5092, 0x64C8,
-            ]),
-            F2x::<8>::from_limbs([
-                0x9A5D, 0x8B28, 0xAFC0, 0x78A5, 0x995A, 0x724B, 0x73D2, 0xC1C0,
-            ]),
-        );
-        let rhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x9BDF, 0x3946, 0x97B7, 0x9D84, 0x9DBF, 0x79C9, 0x9317, 0x25B9,
-            ]),
-            F2x::<8>::from_limbs([
-                0xC8CB, 0x2F7F, 0xD8D6, 0xFF02, 0xD357, 0x83D6, 0x2A59, 0xCBED,
-            ]),
-        );
-        let gcd = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-            ]),
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001,
-            ]),
-        );
-        assert_eq!(WideF2x::<8>::gcd(&lhs, &rhs), gcd);
-
-        let lhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x2A28, 0x37B7, 0xB61E, 0x8DE7, 0x53C3, 0xAA1F, 0x293B, 0xE8AA,
-            ]),
-            F2x::<8>::from_limbs([
-                0xBA21, 0x04EE, 0x9E59, 0x90EA, 0x0DB1, 0xA340, 0x6193, 0xD7CD,
-            ]),
-        );
-        let rhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0xE463, 0x29E4, 0xCF47, 0x6F8F, 0x1C3F, 0x06C2, 0x133B, 0xB016,
-            ]),
-            F2x::<8>::from_limbs([
-                0x2AF6, 0xD239, 0xC37D, 0xC04F, 0x12BB, 0x7879, 0x1DBA, 0x1D84,
-            ]),
-        );
-        let gcd = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-            ]),
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0003,
-            ]),
-        );
-        assert_eq!(WideF2x::<8>::gcd(&lhs, &rhs), gcd);
-
-        let lhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x15AB, 0x501E, 0xB2A5, 0x382C, 0x98A4, 0x42F9, 0xC3BC, 0x9003,
-            ]),
-            F2x::<8>::from_limbs([
-                0x1749, 0x6784, 0xE98B, 0x35A8, 0xC618, 0x1915, 0x625C, 0x8ACF,
-            ]),
-        );
-        let rhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0xBB17, 0x7D58, 0x7663, 0x6B7B, 0x05EE, 0x0271, 0x3316, 0x0462,
-            ]),
-            F2x::<8>::from_limbs([
-                0x9E0A, 0x2111, 0xA529, 0x1CE7, 0x4E60, 0x8467, 0xBA0C, 0x01AE,
-            ]),
-        );
-        let gcd = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-            ]),
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001,
-            ]),
-        );
-        assert_eq!(WideF2x::<8>::gcd(&lhs, &rhs), gcd);
-
-        let lhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x58B4, 0x0CF0, 0x4912, 0x6384, 0xFAC8, 0x9F45, 0x6DA5, 0x7FF5,
-            ]),
-            F2x::<8>::from_limbs([
-                0x88B5, 0xE58C, 0x412A, 0x54B3, 0xE38D, 0xF939, 0xBAEE, 0x9242,
-            ]),
-        );
-        let rhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x0AAD, 0xBF23, 0x3AAB, 0x5B91, 0x27A5, 0xE9CD, 0xD58C, 0x4D15,
-            ]),
-            F2x::<8>::from_limbs([
-                0xE926, 0xFCF8, 0x7674, 0x2312, 0xDCA7, 0xE614, 0x1191, 0x4412,
-            ]),
-        );
-        let gcd = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-            ]),
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0002,
-            ]),
-        );
-        assert_eq!(WideF2x::<8>::gcd(&lhs, &rhs), gcd);
-
-        let lhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x8A8D, 0x0230, 0x0EE1, 0x50AB, 0x474C, 0x9828, 0xF8A8, 0x41AE,
-            ]),
-            F2x::<8>::from_limbs([
-                0x0C45, 0x2A82, 0x9E81, 0x0FD4, 0x98D9, 0x8CF1, 0x9889, 0xD500,
-            ]),
-        );
-        let rhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0xE0BB, 0x71DD, 0xEB35, 0xD698, 0xEF9D, 0x86DB, 0xABAC, 0x8FCF,
-            ]),
-            F2x::<8>::from_limbs([
-                0x6C0D, 0x748C, 0x6EE4, 0x64C8, 0xE6D1, 0xD8D8, 0xC366, 0x3F66,
-            ]),
-        );
-        let gcd = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-            ]),
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0002,
-            ]),
-        );
-        assert_eq!(WideF2x::<8>::gcd(&lhs, &rhs), gcd);
-
-        let lhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x1AA4, 0x210A, 0xA6E5, 0x8D2E, 0x33B9, 0x803D, 0x1E66, 0x033D,
-            ]),
-            F2x::<8>::from_limbs([
-                0x7413, 0x3B1E, 0x288D, 0x644C, 0x305F, 0xFA3D, 0x72BD, 0x5066,
-            ]),
-        );
-        let rhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0xE651, 0x233D, 0xF98D, 0xDDDB, 0x718F, 0x4E9B, 0x0BFC, 0x00CC,
-            ]),
-            F2x::<8>::from_limbs([
-                0xFBEB, 0x8F7B, 0x4B61, 0x16EF, 0x5585, 0x195B, 0xA4B2, 0x5693,
-            ]),
-        );
-        let gcd = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-            ]),
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0003,
-            ]),
-        );
-        assert_eq!(WideF2x::<8>::gcd(&lhs, &rhs), gcd);
-
-        let lhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0xCDDF, 0x1FC0, 0xA77B, 0x92ED, 0x195C, 0xBD3E, 0x1A50, 0x715F,
-            ]),
-            F2x::<8>::from_limbs([
-                0x0D74, 0x3B00, 0x6B22, 0x4722, 0x92EE, 0xA275, 0x08BD, 0xD36B,
-            ]),
-        );
-        let rhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0xAB9D, 0x6D37, 0xC731, 0xDD1F, 0x6BEC, 0x0DD6, 0xC930, 0x8821,
-            ]),
-            F2x::<8>::from_limbs([
-                0x858E, 0x9FA6, 0x1BE4, 0x231D, 0xE4C3, 0x10E8, 0xF6AE, 0x71BC,
-            ]),
-        );
-        let gcd = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-            ]),
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001,
-            ]),
-        );
-        assert_eq!(WideF2x::<8>::gcd(&lhs, &rhs), gcd);
-
-        let lhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0xD88A, 0xF734, 0x5A39, 0xA5F3, 0x7F9C, 0xB4D1, 0x06BF, 0x452A,
-            ]),
-            F2x::<8>::from_limbs([
-                0xE550, 0xC758, 0x63DC, 0x431F, 0x0D26, 0xF96B, 0x5C4B, 0xC30E,
-            ]),
-        );
-        let rhs = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x1D9D, 0x91C4, 0x8EA4, 0xBBDA, 0x8EF7, 0x9270, 0x50D5, 0x6712,
-            ]),
-            F2x::<8>::from_limbs([
-                0xA228, 0xBD5A, 0x6662, 0x28F3, 0x6322, 0x9D24, 0x4E86, 0x34C6,
-            ]),
-        );
-        let gcd = WideF2x::<8>::from_f2x(
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-            ]),
-            F2x::<8>::from_limbs([
-                0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0002,
-            ]),
-        );
-        assert_eq!(WideF2x::<8>::gcd(&lhs, &rhs), gcd);
+        let lhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0xA84F_ED17_FF3E_9B60_2EA8_3480_C4E6_6981),
+            F2_128::from_u128(0x8174_57C6_54ED_250C_C8A6_3D3B_6F64_7A27),
+        );
+        let rhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x7139_9134_3CAA_16DD_D47E_83CF_7006_F923),
+            F2_128::from_u128(0x203A_EF7E_0745_6DC9_003B_F271_F403_CF9F),
+        );
+        let gcd = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0000),
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001),
+        );
+        assert_eq!(WideF2x::<LIMBS_128>::gcd(&lhs, &rhs), gcd);
+
+        let lhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0xEC10_1284_0415_8B0E_D842_5F1C_CCC3_2903),
+            F2_128::from_u128(0xBC0E_FBFC_0430_7C73_40AB_5717_3F59_CBA5),
+        );
+        let rhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x5C85_E20D_1371_9C06_02C2_7CD7_CF41_D5BB),
+            F2_128::from_u128(0xEFC2_1B46_CBCD_1887_CC3E_08D7_BC57_429D),
+        );
+        let gcd = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0000),
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001),
+        );
+        assert_eq!(WideF2x::<LIMBS_128>::gcd(&lhs, &rhs), gcd);
+
+        let lhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0xACC6_7E82_1082_4A1A_E365_2B0B_5092_64C8),
+            F2_128::from_u128(0x9A5D_8B28_AFC0_78A5_995A_724B_73D2_C1C0),
+        );
+        let rhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x9BDF_3946_97B7_9D84_9DBF_79C9_9317_25B9),
+            F2_128::from_u128(0xC8CB_2F7F_D8D6_FF02_D357_83D6_2A59_CBED),
+        );
+        let gcd = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0000),
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001),
+        );
+        assert_eq!(WideF2x::<LIMBS_128>::gcd(&lhs, &rhs), gcd);
+
+        let lhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x2A28_37B7_B61E_8DE7_53C3_AA1F_293B_E8AA),
+            F2_128::from_u128(0xBA21_04EE_9E59_90EA_0DB1_A340_6193_D7CD),
+        );
+        let rhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0xE463_29E4_CF47_6F8F_1C3F_06C2_133B_B016),
+            F2_128::from_u128(0x2AF6_D239_C37D_C04F_12BB_7879_1DBA_1D84),
+        );
+        let gcd = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0000),
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0003),
+        );
+        assert_eq!(WideF2x::<LIMBS_128>::gcd(&lhs, &rhs), gcd);
+
+        let lhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x15AB_501E_B2A5_382C_98A4_42F9_C3BC_9003),
+            F2_128::from_u128(0x1749_6784_E98B_35A8_C618_1915_625C_8ACF),
+        );
+        let rhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0xBB17_7D58_7663_6B7B_05EE_0271_3316_0462),
+            F2_128::from_u128(0x9E0A_2111_A529_1CE7_4E60_8467_BA0C_01AE),
+        );
+        let gcd = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0000),
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001),
+        );
+        assert_eq!(WideF2x::<LIMBS_128>::gcd(&lhs, &rhs), gcd);
+
+        let lhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x58B4_0CF0_4912_6384_FAC8_9F45_6DA5_7FF5),
+            F2_128::from_u128(0x88B5_E58C_412A_54B3_E38D_F939_BAEE_9242),
+        );
+        let rhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x0AAD_BF23_3AAB_5B91_27A5_E9CD_D58C_4D15),
+            F2_128::from_u128(0xE926_FCF8_7674_2312_DCA7_E614_1191_4412),
+        );
+        let gcd = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0000),
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0002),
+        );
+        assert_eq!(WideF2x::<LIMBS_128>::gcd(&lhs, &rhs), gcd);
+
+        let lhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x8A8D_0230_0EE1_50AB_474C_9828_F8A8_41AE),
+            F2_128::from_u128(0x0C45_2A82_9E81_0FD4_98D9_8CF1_9889_D500),
+        );
+        let rhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0xE0BB_71DD_EB35_D698_EF9D_86DB_ABAC_8FCF),
+            F2_128::from_u128(0x6C0D_748C_6EE4_64C8_E6D1_D8D8_C366_3F66),
+        );
+        let gcd = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0000),
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0002),
+        );
+        assert_eq!(WideF2x::<LIMBS_128>::gcd(&lhs, &rhs), gcd);
+
+        let lhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x1AA4_210A_A6E5_8D2E_33B9_803D_1E66_033D),
+            F2_128::from_u128(0x7413_3B1E_288D_644C_305F_FA3D_72BD_5066),
+        );
+        let rhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0xE651_233D_F98D_DDDB_718F_4E9B_0BFC_00CC),
+            F2_128::from_u128(0xFBEB_8F7B_4B61_16EF_5585_195B_A4B2_5693),
+        );
+        let gcd = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0000),
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0003),
+        );
+        assert_eq!(WideF2x::<LIMBS_128>::gcd(&lhs, &rhs), gcd);
+
+        let lhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0xCDDF_1FC0_A77B_92ED_195C_BD3E_1A50_715F),
+            F2_128::from_u128(0x0D74_3B00_6B22_4722_92EE_A275_08BD_D36B),
+        );
+        let rhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0xAB9D_6D37_C731_DD1F_6BEC_0DD6_C930_8821),
+            F2_128::from_u128(0x858E_9FA6_1BE4_231D_E4C3_10E8_F6AE_71BC),
+        );
+        let gcd = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0000),
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001),
+        );
+        assert_eq!(WideF2x::<LIMBS_128>::gcd(&lhs, &rhs), gcd);
+
+        let lhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0xD88A_F734_5A39_A5F3_7F9C_B4D1_06BF_452A),
+            F2_128::from_u128(0xE550_C758_63DC_431F_0D26_F96B_5C4B_C30E),
+        );
+        let rhs = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x1D9D_91C4_8EA4_BBDA_8EF7_9270_50D5_6712),
+            F2_128::from_u128(0xA228_BD5A_6662_28F3_6322_9D24_4E86_34C6),
+        );
+        let gcd = WideF2x::<LIMBS_128>::from_f2x(
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0000),
+            F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0002),
+        );
+        assert_eq!(WideF2x::<LIMBS_128>::gcd(&lhs, &rhs), gcd);
     }
 
     #[test]
     fn widef2x_xgcd() {
-        let lhs = F2_128::from_limbs([
-            0x2E46, 0x1AB1, 0x8719, 0xB670, 0x49D4, 0x3EE3, 0xD927, 0x019E,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x3FA7, 0xAA63, 0xE426, 0x84EA, 0x03BB, 0xB6F5, 0x8E43, 0xF797,
-        ]);
-        let expected_s = F2_128::from_limbs([
-            0x1F46, 0xF302, 0x24E0, 0x27EA, 0xFADB, 0x5FEA, 0x37B8, 0xA278,
-        ])
-        .widen();
-        let expected_t = F2_128::from_limbs([
-            0x17DD, 0x9394, 0xD239, 0x14F8, 0x0868, 0xD45D, 0x9347, 0x217B,
-        ])
-        .widen();
-        let expected_d = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001,
-        ])
-        .widen();
+        let lhs = F2_128::from_u128(0x2E46_1AB1_8719_B670_49D4_3EE3_D927_019E);
+        let rhs = F2_128::from_u128(0x3FA7_AA63_E426_84EA_03BB_B6F5_8E43_F797);
+        let expected_s = F2_128::from_u128(0x1F46_F302_24E0_27EA_FADB_5FEA_37B8_A278).widen();
+        let expected_t = F2_128::from_u128(0x17DD_9394_D239_14F8_0868_D45D_9347_217B).widen();
+        let expected_d = F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001).widen();
         let (s, t, divisor) = WideF2x::xgcd(&lhs.widen(), &rhs.widen());
         assert_eq!((s, t, divisor), (expected_s, expected_t, expected_d));
 
-        let lhs = F2_128::from_limbs([
-            0x95DF, 0x4A39, 0x5AEA, 0xD3E9, 0x3B31, 0xCF9E, 0xA082, 0x0C02,
-        ]);
-        let rhs = F2_128::from_limbs([
-            0x60F3, 0x0B74, 0xB7AB, 0x8B48, 0x9D1F, 0x693B, 0xEFE8, 0xAFAC,
-        ]);
-        let expected_s = F2_128::from_limbs([
-            0x1A1D, 0xBED7, 0x7BD9, 0x99BE, 0x5491, 0x182E, 0x7AB1, 0x3DF1,
-        ])
-        .widen();
-        let expected_t = F2_128::from_limbs([
-            0x223E, 0x1D1E, 0x8229, 0xF29F, 0xF020, 0x2121, 0x2CEB, 0x1B68,
-        ])
-        .widen();
-        let expected_d = F2_128::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0002,
-        ])
-        .widen();
+        let lhs = F2_128::from_u128(0x95DF_4A39_5AEA_D3E9_3B31_CF9E_A082_0C02);
+        let rhs = F2_128::from_u128(0x60F3_0B74_B7AB_8B48_9D1F_693B_EFE8_AFAC);
+        let expected_s = F2_128::from_u128(0x1A1D_BED7_7BD9_99BE_5491_182E_7AB1_3DF1).widen();
+        let expected_t = F2_128::from_u128(0x223E_1D1E_8229_F29F_F020_2121_2CEB_1B68).widen();
+        let expected_d = F2_128::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0002).widen();
         let (s, t, divisor) = WideF2x::xgcd(&lhs.widen(), &rhs.widen());
         assert_eq!((s, t, divisor), (expected_s, expected_t, expected_d));
     }
+
+    #[test]
+    fn random_partial_xgcd() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            // Small enough that the products s * lhs and t * rhs do not overflow
+            let lhs = F2_128::from_u128(rng.gen::<u64>() as u128);
+            let rhs = F2_128::from_u128(rng.gen::<u64>() as u128);
+            let (_, _, gcd) = F2_128::xgcd(&lhs, &rhs);
+            for degree in [0, 1, 7, 20, 40, 70] {
+                let (s, t, r) = F2_128::partial_xgcd(&lhs, &rhs, degree);
+                assert_eq!(s.mul(&lhs).add(&t.mul(&rhs)), r);
+                if gcd.degree() < Degree::NonNegative(degree) {
+                    // r is a non-zero remainder, which is a multiple of the GCD
+                    assert!(r.degree() < Degree::NonNegative(degree));
+                    assert!(r.degree() >= gcd.degree());
+                } else {
+                    assert!(r.is_zero());
+                }
+            }
+            if gcd == F2_128::ONE {
+                assert_eq!(
+                    F2_128::partial_xgcd(&lhs, &rhs, 1),
+                    F2_128::xgcd(&lhs, &rhs)
+                );
+            }
+
+            let (lhs, rhs) = (lhs.widen(), rhs.widen());
+            let (s, t, r) = WideF2x::partial_xgcd(&lhs, &rhs, 10);
+            assert_eq!(s.mul(&lhs).add(&t.mul(&rhs)), r);
+            assert!(r.degree() < Degree::NonNegative(10));
+        }
+
+        // lhs is already below the bound
+        let lhs = F2_128::from_exponents(&[3, 0]);
+        let (s, t, r) = F2_128::partial_xgcd(&lhs, &F2_128::ONE, 5);
+        assert_eq!((s, t, r), (F2_128::ONE, F2_128::ZERO, lhs));
+    }
+
+    #[test]
+    fn random_derivative() {
+        let poly = F2_128::from_exponents(&[5, 4, 1, 0]);
+        assert_eq!(poly.derivative(), F2_128::from_exponents(&[4, 0]));
+        assert_eq!(F2_128::ONE.derivative(), F2_128::ZERO);
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            // The product rule (fg)' = f'g + fg'
+            let lhs = F2_128::from_u128(rng.gen::<u64>() as u128);
+            let rhs = F2_128::from_u128(rng.gen::<u64>() as u128);
+            assert_eq!(
+                lhs.mul(&rhs).derivative(),
+                lhs.derivative().mul(&rhs).add(&lhs.mul(&rhs.derivative()))
+            );
+            // The derivative of a square is zero
+            assert!(lhs.mul(&lhs).derivative().is_zero());
+
+            let wide =
+                WideF2x::from_f2x(F2_128::from_limbs(rng.gen()), F2_128::from_limbs(rng.gen()));
+            let derivative = wide.derivative();
+            for i in 0..WideF2x::<LIMBS_128>::BITS - 1 {
+                assert_eq!(derivative.bit(i), i % 2 == 0 && wide.bit(i + 1));
+            }
+        }
+    }
 }
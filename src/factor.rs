@@ -0,0 +1,525 @@
+//! Factorization of polynomials over binary fields GF(2^m)
+//!
+//! `factor` runs the three stages of the
+//! [Cantor-Zassenhaus](https://en.wikipedia.org/wiki/Factorization_of_polynomials_over_finite_fields)
+//! algorithm: square-free factorization splits off repeated factors, distinct-degree factorization
+//! groups the irreducible factors by degree, and equal-degree factorization splits each group with
+//! random trace maps. Intermediate products are kept below twice the degree of the input, so the
+//! cost grows with the square of the degree times m; this is meant for moderate degrees.
+//!
+//! `PolyVec::roots` finds the roots in the field, either by evaluating at every element with
+//! Chien search (`PolyVec::eval_all`) or, when the field is too large for that, by splitting off
+//! the linear factors.
+use crate::f2x::Degree;
+use crate::galoisfields::{BinaryField, FieldArithmetic};
+use crate::poly::{resize, PolyVec};
+use alloc::{vec, vec::Vec};
+use rand_core::CryptoRngCore;
+
+/// Factor the polynomial into monic irreducible factors, returned with their multiplicities in
+/// ascending order of degree. The leading coefficient of the input is dropped, so the product of
+/// the factors is the monic associate of the input. Every factor has the capacity of the input.
+/// Will panic if the input is zero
+pub fn factor<F: BinaryField>(
+    poly: &PolyVec<F>,
+    rng: &mut impl CryptoRngCore,
+) -> Vec<(PolyVec<F>, usize)> {
+    let degree = match poly.degree() {
+        Degree::NegativeInfinity => panic!("attempt to factor zero"),
+        Degree::NonNegative(0) => return vec![],
+        Degree::NonNegative(degree) => degree,
+    };
+    let capacity = 2 * degree;
+    let monic = PolyVec::gcd(
+        &resize(poly, capacity),
+        &PolyVec::zero_with_capacity(capacity),
+    );
+
+    let mut factors = vec![];
+    for (square_free, multiplicity) in square_free_factors(&monic) {
+        for (group, factor_degree) in distinct_degree_factors(&square_free) {
+            for irreducible in equal_degree_factors(&group, factor_degree, rng) {
+                factors.push((resize(&irreducible, poly.capacity()), multiplicity));
+            }
+        }
+    }
+    factors.sort_by_key(|(factor, _)| factor.degree());
+
+    factors
+}
+
+/// Return true if the polynomial has positive degree and is not the product of two polynomials of
+/// positive degree
+pub fn is_irreducible<F: BinaryField>(poly: &PolyVec<F>) -> bool {
+    let degree = match poly.degree() {
+        Degree::NonNegative(degree) if degree > 0 => degree,
+        _ => return false,
+    };
+    let monic = PolyVec::gcd(
+        &resize(poly, 2 * degree),
+        &PolyVec::zero_with_capacity(2 * degree),
+    );
+    if !is_one(&PolyVec::gcd(&monic, &monic.derivative())) {
+        return false;
+    }
+
+    // x^(q^i) - x is the product of all monic irreducible polynomials whose degree divides i, so a
+    // square-free polynomial is irreducible if it shares no factor with any of them for i <= n/2
+    let x = monomial(1, monic.capacity());
+    let (_, frobenius) = x.div_rem(&monic);
+    let frobenius = frobenius_mod(&frobenius, &monic);
+    let mut power = x.clone();
+    for _ in 1..=degree / 2 {
+        power = power.compose_mod(&frobenius, &monic);
+        if !is_one(&PolyVec::gcd(&monic, &power.sub(&x))) {
+            return false;
+        }
+    }
+    true
+}
+
+/// The largest exponent m for which `PolyVec::eval_all` is supported and `PolyVec::roots` uses
+/// Chien search
+pub const CHIEN_SEARCH_MAX_BITS: usize = 16;
+
+impl<F: BinaryField> PolyVec<F> {
+    /// The distinct roots of the polynomial in the field, found with `chien_search` for
+    /// m <= `CHIEN_SEARCH_MAX_BITS` and with `roots_by_factoring` otherwise. Will panic if the
+    /// polynomial is zero
+    pub fn roots(&self, rng: &mut impl CryptoRngCore) -> Vec<F> {
+        if F::BITS <= CHIEN_SEARCH_MAX_BITS {
+            chien_search(self)
+        } else {
+            roots_by_factoring(self, rng)
+        }
+    }
+
+    /// Evaluate the polynomial at every element of the field: the value at index 0 is f(0), and
+    /// the value at index i + 1 is f(g^i) for the primitive element g and 0 <= i < 2^m - 1, so the
+    /// output doubles as a lookup table indexed by discrete logarithm. Instead of evaluating from
+    /// scratch, the term c_j g^(ij) is kept for each j and multiplied by g^j to move on to the next
+    /// power, as in Chien search, so each point costs one multiplication per coefficient. Will
+    /// panic if m > `CHIEN_SEARCH_MAX_BITS`
+    pub fn eval_all(&self) -> Vec<F> {
+        if F::BITS > CHIEN_SEARCH_MAX_BITS {
+            panic!("field is too large for exhaustive evaluation");
+        }
+        let generator = F::primitive_element();
+        let steps = self
+            .coeffs
+            .iter()
+            .scan(F::one(), |power, _| {
+                let step = *power;
+                *power = power.modmul(&generator);
+                Some(step)
+            })
+            .collect::<Vec<F>>();
+        let mut terms = self.coeffs.clone();
+
+        let mut values = Vec::with_capacity(1 << F::BITS);
+        values.push(self.coeffs.first().copied().unwrap_or(F::zero()));
+        for _ in 0..(1usize << F::BITS) - 1 {
+            values.push(terms.iter().fold(F::zero(), |acc, term| acc.modadd(term)));
+            terms
+                .iter_mut()
+                .zip(steps.iter())
+                .for_each(|(term, step)| *term = term.modmul(step));
+        }
+
+        values
+    }
+}
+
+/// Find the distinct roots with `PolyVec::eval_all`, returned with zero first, then in the order
+/// of the powers of the primitive element. Will panic if the polynomial is zero or if
+/// m > `CHIEN_SEARCH_MAX_BITS`
+pub fn chien_search<F: BinaryField>(poly: &PolyVec<F>) -> Vec<F> {
+    if poly.is_zero() {
+        panic!("every element is a root of zero");
+    }
+    let generator = F::primitive_element();
+    let points = core::iter::once(F::zero())
+        .chain(core::iter::successors(Some(F::one()), |point| {
+            Some(point.modmul(&generator))
+        }));
+    poly.eval_all()
+        .into_iter()
+        .zip(points)
+        .filter(|(value, _)| value.is_zero())
+        .map(|(_, point)| point)
+        .collect()
+}
+
+/// Find the distinct roots by splitting off the product of the linear factors, gcd(f, x^q - x),
+/// and splitting that into linear factors x - r with random trace maps. Roots are returned in no
+/// particular order. Will panic if the polynomial is zero
+pub fn roots_by_factoring<F: BinaryField>(
+    poly: &PolyVec<F>,
+    rng: &mut impl CryptoRngCore,
+) -> Vec<F> {
+    let degree = match poly.degree() {
+        Degree::NegativeInfinity => panic!("every element is a root of zero"),
+        Degree::NonNegative(0) => return vec![],
+        Degree::NonNegative(degree) => degree,
+    };
+    let capacity = 2 * degree;
+    let monic = PolyVec::gcd(
+        &resize(poly, capacity),
+        &PolyVec::zero_with_capacity(capacity),
+    );
+    let x = monomial(1, capacity);
+    let (_, power) = x.div_rem(&monic);
+    let power = frobenius_mod(&power, &monic);
+    let linear = PolyVec::gcd(&monic, &power.sub(&x));
+    if is_one(&linear) {
+        return vec![];
+    }
+
+    // Each linear factor is monic, so its root is the negated constant term
+    equal_degree_factors(&linear, 1, rng)
+        .iter()
+        .map(|factor| F::zero().modsub(&factor.coeffs[0]))
+        .collect()
+}
+
+/// Split a monic polynomial into pairwise coprime square-free monic polynomials, each with the
+/// multiplicity of all of its irreducible factors
+fn square_free_factors<F: BinaryField>(poly: &PolyVec<F>) -> Vec<(PolyVec<F>, usize)> {
+    let mut factors = vec![];
+    let mut repeated = PolyVec::gcd(poly, &poly.derivative());
+    let (mut remaining, _) = poly.div_rem(&repeated);
+    let mut multiplicity = 1;
+
+    // remaining is the product of the irreducible factors whose multiplicity is at least
+    // `multiplicity` and not a multiple of 2
+    while !is_one(&remaining) {
+        let next = PolyVec::gcd(&remaining, &repeated);
+        let (square_free, _) = remaining.div_rem(&next);
+        if !is_one(&square_free) {
+            factors.push((square_free, multiplicity));
+        }
+        (repeated, _) = repeated.div_rem(&next);
+        remaining = next;
+        multiplicity += 1;
+    }
+
+    // What is left has a zero derivative and is therefore the square of some polynomial
+    if !is_one(&repeated) {
+        let root = sqrt(&repeated);
+        for (factor, multiplicity) in square_free_factors(&root) {
+            factors.push((factor, 2 * multiplicity));
+        }
+    }
+
+    factors
+}
+
+/// Split a square-free monic polynomial into (product, d) pairs, where product is the product of
+/// all of its irreducible factors of degree d
+fn distinct_degree_factors<F: BinaryField>(poly: &PolyVec<F>) -> Vec<(PolyVec<F>, usize)> {
+    let mut factors = vec![];
+    let mut remaining = poly.clone();
+    let x = monomial(1, poly.capacity());
+    let mut frobenius = x.clone();
+    let mut power = x.clone();
+    let mut factor_degree = 1;
+
+    while degree_of(&remaining) >= 2 * factor_degree {
+        // power is x^(q^i) reduced modulo the remaining polynomial. Since x^(q^(i + 1)) is
+        // x^(q^i) composed with x^q, the m squarings are only needed once
+        if factor_degree == 1 {
+            frobenius = frobenius_mod(&x, &remaining);
+            power = frobenius.clone();
+        } else {
+            power = power.compose_mod(&frobenius, &remaining);
+        }
+        let group = PolyVec::gcd(&remaining, &power.sub(&x));
+        if !is_one(&group) {
+            (remaining, _) = remaining.div_rem(&group);
+            (_, power) = power.div_rem(&remaining);
+            (_, frobenius) = frobenius.div_rem(&remaining);
+            factors.push((group, factor_degree));
+        }
+        factor_degree += 1;
+    }
+    if !is_one(&remaining) {
+        let factor_degree = degree_of(&remaining);
+        factors.push((remaining, factor_degree));
+    }
+
+    factors
+}
+
+/// Split a square-free monic polynomial whose irreducible factors all have the given degree. In
+/// characteristic 2, the trace map a + a^2 + ... + a^(2^(md - 1)) of a random a takes values in
+/// GF(2) modulo each irreducible factor, so its GCD with the polynomial picks out about half of them
+fn equal_degree_factors<F: BinaryField>(
+    poly: &PolyVec<F>,
+    factor_degree: usize,
+    rng: &mut impl CryptoRngCore,
+) -> Vec<PolyVec<F>> {
+    let degree = degree_of(poly);
+    let count = degree / factor_degree;
+    let mut factors = vec![poly.clone()];
+
+    while factors.len() < count {
+        let mut elem = PolyVec::zero_with_capacity(poly.capacity());
+        elem.coeffs[..degree]
+            .iter_mut()
+            .for_each(|coeff| *coeff = F::random(rng));
+        let mut trace = elem.clone();
+        for _ in 1..F::BITS * factor_degree {
+            elem = mul_mod(&elem, &elem, poly);
+            trace = trace.add(&elem);
+        }
+
+        let mut split = Vec::with_capacity(factors.len());
+        for factor in factors {
+            let common = PolyVec::gcd(&factor, &trace);
+            if degree_of(&factor) == factor_degree || is_one(&common) || common == factor {
+                split.push(factor);
+            } else {
+                let (cofactor, _) = factor.div_rem(&common);
+                split.push(common);
+                split.push(cofactor);
+            }
+        }
+        factors = split;
+    }
+
+    factors
+}
+
+/// The product of two polynomials reduced modulo the third. The product must fit in the capacity
+fn mul_mod<F: FieldArithmetic>(
+    lhs: &PolyVec<F>,
+    rhs: &PolyVec<F>,
+    modulus: &PolyVec<F>,
+) -> PolyVec<F> {
+    let (_, rem) = lhs.mul(rhs).div_rem(modulus);
+    rem
+}
+
+/// Raise the polynomial to the power q = 2^m modulo the modulus, by squaring m times
+fn frobenius_mod<F: BinaryField>(poly: &PolyVec<F>, modulus: &PolyVec<F>) -> PolyVec<F> {
+    (0..F::BITS).fold(poly.clone(), |acc, _| mul_mod(&acc, &acc, modulus))
+}
+
+/// The square root of a polynomial whose odd-power coefficients are all zero
+fn sqrt<F: BinaryField>(poly: &PolyVec<F>) -> PolyVec<F> {
+    let mut root = PolyVec::zero_with_capacity(poly.capacity());
+    for (dst, coeff) in root.coeffs.iter_mut().zip(poly.coeffs.iter().step_by(2)) {
+        *dst = coeff.sqrt();
+    }
+    root
+}
+
+/// x^power with the given capacity
+fn monomial<F: FieldArithmetic>(power: usize, capacity: usize) -> PolyVec<F> {
+    let mut poly = PolyVec::zero_with_capacity(capacity);
+    poly.coeffs[power] = F::one();
+    poly
+}
+
+/// The degree of a polynomial, where zero counts as degree 0
+fn degree_of<F: FieldArithmetic>(poly: &PolyVec<F>) -> usize {
+    match poly.degree() {
+        Degree::NegativeInfinity => 0,
+        Degree::NonNegative(degree) => degree,
+    }
+}
+
+/// Return true if the monic polynomial is the constant 1
+fn is_one<F: FieldArithmetic>(poly: &PolyVec<F>) -> bool {
+    poly.degree() == Degree::NonNegative(0)
+}
+
+#[cfg(all(test, feature = "gf8", feature = "gf16", feature = "gf128"))]
+mod tests {
+    use super::*;
+    use crate::galoisfields::{GF2p128, GF2p16, GF2p8};
+    use rand::{thread_rng, Rng};
+
+    /// A random monic irreducible polynomial of the given degree, found by rejection sampling
+    fn random_irreducible<F: BinaryField>(
+        degree: usize,
+        capacity: usize,
+        rng: &mut impl CryptoRngCore,
+    ) -> PolyVec<F> {
+        loop {
+            let mut poly = monomial(degree, capacity);
+            poly.coeffs[..degree]
+                .iter_mut()
+                .for_each(|coeff| *coeff = F::random(rng));
+            if is_irreducible(&poly) {
+                return poly;
+            }
+        }
+    }
+
+    /// Check that the factors are distinct, monic, and irreducible, and that they multiply back
+    /// into the monic associate of poly
+    fn check_factorization<F: BinaryField>(poly: &PolyVec<F>, factors: &[(PolyVec<F>, usize)]) {
+        let mut prod = monomial(0, poly.capacity());
+        for (i, (factor, multiplicity)) in factors.iter().enumerate() {
+            assert!(is_irreducible(factor));
+            assert!(factor.coeffs[degree_of(factor)].is_one());
+            assert!(factors[..i].iter().all(|(other, _)| other != factor));
+            for _ in 0..*multiplicity {
+                prod = prod.mul(factor);
+            }
+        }
+        let monic = PolyVec::gcd(poly, &PolyVec::zero_with_capacity(poly.capacity()));
+        assert!(prod == monic);
+    }
+
+    #[test]
+    fn quadratic_irreducibility_follows_trace() {
+        // x^2 + x + c is irreducible over GF(2^m) if and only if the trace of c is 1
+        let mut rng = thread_rng();
+        for _ in 0..32 {
+            let c = GF2p8::random(&mut rng);
+            let poly = PolyVec::from_coeffs(vec![c, GF2p8::ONE, GF2p8::ONE]);
+            assert_eq!(is_irreducible(&poly), c.trace());
+        }
+        assert!(!is_irreducible(&monomial::<GF2p8>(0, 4)));
+        assert!(!is_irreducible(&PolyVec::<GF2p8>::zero_with_capacity(4)));
+    }
+
+    #[test]
+    fn random_factor() {
+        let mut rng = thread_rng();
+        for exponents in [
+            &[(1, 1), (1, 1), (2, 1), (3, 1)][..],
+            &[(1, 2), (2, 3), (1, 4)],
+            &[(4, 1), (4, 1), (4, 2)],
+            &[(5, 1), (1, 3)],
+        ] {
+            let mut poly = monomial::<GF2p8>(0, 32);
+            for (degree, multiplicity) in exponents {
+                let factor = random_irreducible(*degree, 32, &mut rng);
+                for _ in 0..*multiplicity {
+                    poly = poly.mul(&factor);
+                }
+            }
+            // A non-monic input has the same factors as its monic associate
+            let lead = GF2p8::random(&mut rng);
+            if lead.is_zero() {
+                continue;
+            }
+            let poly = poly.mul_coeff(&lead);
+            let factors = factor(&poly, &mut rng);
+            check_factorization(&poly, &factors);
+        }
+    }
+
+    #[test]
+    fn random_factor_large_field() {
+        let mut rng = thread_rng();
+        let mut poly = monomial::<GF2p128>(0, 16);
+        let mut expected = vec![];
+        for degree in [1, 1, 2, 3] {
+            let factor = random_irreducible(degree, 16, &mut rng);
+            poly = poly.mul(&factor);
+            expected.push(factor);
+        }
+        let factors = factor(&poly, &mut rng);
+        check_factorization(&poly, &factors);
+        assert_eq!(factors.len(), expected.len());
+        for factor in expected {
+            assert!(factors.iter().any(|(found, _)| *found == factor));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn factor_zero() {
+        factor(&PolyVec::<GF2p8>::zero_with_capacity(4), &mut thread_rng());
+    }
+
+    /// The product of x - r over the given roots, with the given capacity
+    fn from_roots<F: BinaryField>(roots: &[F], capacity: usize) -> PolyVec<F> {
+        roots.iter().fold(monomial(0, capacity), |acc, root| {
+            let mut linear = monomial(1, capacity);
+            linear.coeffs[0] = F::zero().modsub(root);
+            acc.mul(&linear)
+        })
+    }
+
+    fn assert_same_roots<F: BinaryField + core::fmt::Debug>(found: &[F], expected: &[F]) {
+        assert_eq!(found.len(), expected.len());
+        assert!(expected.iter().all(|root| found.contains(root)));
+    }
+
+    #[test]
+    fn random_roots() {
+        let mut rng = thread_rng();
+        for count in [0, 1, 3, 6] {
+            let mut roots = vec![GF2p8::ZERO];
+            while roots.len() < count {
+                let root = GF2p8::random(&mut rng);
+                if !roots.contains(&root) {
+                    roots.push(root);
+                }
+            }
+            roots.truncate(count);
+            // A repeated root and an irreducible quadratic factor contribute nothing new
+            let mut poly = from_roots(&roots, 16);
+            if let Some(root) = roots.last() {
+                poly = poly.mul(&from_roots(&[*root], 16));
+            }
+            poly = poly.mul(&random_irreducible(2, 16, &mut rng));
+
+            assert_same_roots(&chien_search(&poly), &roots);
+            assert_same_roots(&roots_by_factoring(&poly, &mut rng), &roots);
+            assert_same_roots(&poly.roots(&mut rng), &roots);
+        }
+    }
+
+    #[test]
+    fn chien_search_medium_field() {
+        let mut rng = thread_rng();
+        let roots = [GF2p16::random(&mut rng), GF2p16::random(&mut rng)];
+        let poly = from_roots(&roots, 4);
+        let mut expected = roots.to_vec();
+        expected.dedup();
+        assert_same_roots(&chien_search(&poly), &expected);
+    }
+
+    #[test]
+    fn random_roots_large_field() {
+        let mut rng = thread_rng();
+        let roots = (0..4)
+            .map(|_| GF2p128::random(&mut rng))
+            .collect::<Vec<_>>();
+        let poly = from_roots(&roots, 16).mul(&random_irreducible(3, 16, &mut rng));
+        assert_same_roots(&poly.roots(&mut rng), &roots);
+        assert!(monomial::<GF2p128>(0, 4).roots(&mut rng).is_empty());
+    }
+
+    #[test]
+    fn random_eval_all() {
+        let mut rng = thread_rng();
+        let poly = random_irreducible(5, 8, &mut rng).mul_coeff(&GF2p8::random(&mut rng));
+        let values = poly.eval_all();
+        assert_eq!(values.len(), 256);
+        assert_eq!(values[0], poly.evaluate(&GF2p8::ZERO));
+        let mut point = GF2p8::ONE;
+        for value in values[1..].iter() {
+            assert_eq!(*value, poly.evaluate(&point));
+            point = point.modmul(&GF2p8::primitive_element());
+        }
+
+        // The values of x form the table of powers of the primitive element
+        let powers = monomial::<GF2p16>(1, 2).eval_all();
+        assert_eq!(powers[0], GF2p16::ZERO);
+        let i = rng.gen_range(0..65535);
+        assert_eq!(powers[i + 1], GF2p16::primitive_element().modexp(i as u64));
+    }
+
+    #[test]
+    #[should_panic]
+    fn eval_all_large_field() {
+        monomial::<GF2p128>(1, 2).eval_all();
+    }
+}
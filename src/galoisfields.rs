@@ -1,6 +1,485 @@
 //! Galois field (finite field) traits and implementations
-use crate::f2x::{F2x, WideF2x, Word};
+use crate::entropy::{EntropyRng, EntropySource};
+use crate::error::SssError;
+use crate::f2x::{Barrett, Degree, F2x, WideF2x};
+#[cfg(feature = "alloc")]
+use alloc::vec;
+use rand::distributions::{Distribution, Standard};
 use rand::Rng;
+use rand_core::CryptoRngCore;
+
+/// Implement the subtle crate's constant-time traits for a field defined by `galois_field!`. The
+/// feature check has to happen here rather than inside `galois_field!`, where it would be
+/// evaluated against the features of the invoking crate. Negation is the identity in
+/// characteristic 2, so `ConditionallyNegatable` is not implemented.
+#[cfg(feature = "subtle")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __subtle_binary_field {
+    ($name:ident) => {
+        impl $crate::__private::subtle::ConstantTimeEq for $name {
+            fn ct_eq(&self, other: &Self) -> $crate::__private::subtle::Choice {
+                $crate::__private::subtle::ConstantTimeEq::ct_eq(&self.poly, &other.poly)
+            }
+        }
+
+        impl $crate::__private::subtle::ConditionallySelectable for $name {
+            fn conditional_select(
+                a: &Self,
+                b: &Self,
+                choice: $crate::__private::subtle::Choice,
+            ) -> Self {
+                Self {
+                    poly: $crate::__private::subtle::ConditionallySelectable::conditional_select(
+                        &a.poly, &b.poly, choice,
+                    ),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "subtle"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __subtle_binary_field {
+    ($name:ident) => {};
+}
+
+/// Implement `Arbitrary` for a field defined by `galois_field!`. See `__subtle_binary_field` for
+/// why this is a separate macro. The unused high-order bits are cleared, so that every input
+/// produces a reduced element.
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __arbitrary_binary_field {
+    ($name:ident) => {
+        impl<'a> $crate::__private::arbitrary::Arbitrary<'a> for $name {
+            fn arbitrary(
+                u: &mut $crate::__private::arbitrary::Unstructured<'a>,
+            ) -> $crate::__private::arbitrary::Result<Self> {
+                let poly: $crate::f2x::F2x<{ Self::LIMBS }> = u.arbitrary()?;
+                let unused = $crate::f2x::F2x::<{ Self::LIMBS }>::BITS - Self::BITS;
+                Ok(Self {
+                    poly: poly.shl(unused).shr(unused),
+                })
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                type Poly = $crate::f2x::F2x<{ $name::LIMBS }>;
+                <Poly as $crate::__private::arbitrary::Arbitrary>::size_hint(depth)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "arbitrary"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __arbitrary_binary_field {
+    ($name:ident) => {};
+}
+
+/// Implement num-traits' `Zero`, `One`, and `Pow` for a field type with the operators of
+/// `__field_ops`. See `__subtle_binary_field` for why this is a separate macro. `Pow` is
+/// `FieldArithmetic::modexp`, whose running time depends on the exponent.
+#[cfg(feature = "num-traits")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __num_traits_field {
+    ($name:ident) => {
+        impl $crate::__private::num_traits::Zero for $name {
+            fn zero() -> Self {
+                <Self as $crate::galoisfields::FieldArithmetic>::zero()
+            }
+
+            fn is_zero(&self) -> bool {
+                <Self as $crate::galoisfields::FieldArithmetic>::is_zero(self)
+            }
+        }
+
+        impl $crate::__private::num_traits::One for $name {
+            fn one() -> Self {
+                <Self as $crate::galoisfields::FieldArithmetic>::one()
+            }
+
+            fn is_one(&self) -> bool {
+                <Self as $crate::galoisfields::FieldArithmetic>::is_one(self)
+            }
+        }
+
+        impl $crate::__private::num_traits::Pow<u64> for $name {
+            type Output = Self;
+
+            fn pow(self, exp: u64) -> Self {
+                $crate::galoisfields::FieldArithmetic::modexp(&self, exp)
+            }
+        }
+
+        impl $crate::__private::num_traits::Pow<u32> for $name {
+            type Output = Self;
+
+            fn pow(self, exp: u32) -> Self {
+                $crate::galoisfields::FieldArithmetic::modexp(&self, exp as u64)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "num-traits"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __num_traits_field {
+    ($name:ident) => {};
+}
+
+/// Implement ff's `Field` for a field defined by `galois_field!`; see `__subtle_binary_field` for
+/// why this is a separate macro. The `ff` feature enables `subtle`, whose traits `Field` requires.
+/// Doubling is zero in characteristic 2, and every element has the unique square root of
+/// `BinaryField::sqrt`, so `sqrt_ratio` only fails when dividing a nonzero element by zero.
+#[cfg(feature = "ff")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ff_field {
+    ($name:ident) => {
+        impl $crate::__private::ff::Field for $name {
+            const ZERO: Self = Self::ZERO;
+            const ONE: Self = Self::ONE;
+
+            fn random(mut rng: impl $crate::__private::rand_core::RngCore) -> Self {
+                $crate::__private::rand::Rng::gen(&mut rng)
+            }
+
+            fn square(&self) -> Self {
+                $crate::galoisfields::FieldArithmetic::square(self)
+            }
+
+            fn double(&self) -> Self {
+                Self::ZERO
+            }
+
+            fn invert(&self) -> $crate::__private::subtle::CtOption<Self> {
+                $crate::__private::subtle::CtOption::new(
+                    $crate::galoisfields::FieldArithmetic::inv_ct(self),
+                    !$crate::__private::ff::Field::is_zero(self),
+                )
+            }
+
+            fn sqrt_ratio(num: &Self, div: &Self) -> ($crate::__private::subtle::Choice, Self) {
+                let ratio = $crate::galoisfields::FieldArithmetic::modmul(
+                    num,
+                    &$crate::galoisfields::FieldArithmetic::inv_ct(div),
+                );
+                (
+                    $crate::__private::ff::Field::is_zero(num)
+                        | !$crate::__private::ff::Field::is_zero(div),
+                    $crate::galoisfields::BinaryField::sqrt(&ratio),
+                )
+            }
+
+            fn sqrt(&self) -> $crate::__private::subtle::CtOption<Self> {
+                $crate::__private::subtle::CtOption::new(
+                    $crate::galoisfields::BinaryField::sqrt(self),
+                    $crate::__private::subtle::Choice::from(1),
+                )
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "ff"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ff_field {
+    ($name:ident) => {};
+}
+
+/// The normal basis of a field defined by `galois_field!`, and the Frobenius map, trace, half
+/// trace, and quadratic solver computed through it. The basis is cached in a `OnceLock`, so these
+/// need std; see `__subtle_binary_field` for why this is a separate macro.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __normal_basis_field {
+    ($name:ident) => {
+        impl $name {
+            /// A normal basis of this field and the change-of-basis matrices, computed on first
+            /// use
+            pub fn normal_basis() -> &'static $crate::normalbasis::NormalBasis<{ Self::LIMBS }> {
+                static BASIS: $crate::__private::OnceLock<
+                    $crate::normalbasis::NormalBasis<{ $name::LIMBS }>,
+                > = $crate::__private::OnceLock::new();
+                BASIS.get_or_init(|| {
+                    $crate::normalbasis::NormalBasis::find(Self::BITS, |lhs, rhs| {
+                        let (lhs, rhs) = (Self::from_poly(*lhs), Self::from_poly(*rhs));
+                        $crate::galoisfields::FieldArithmetic::modmul(&lhs, &rhs).poly
+                    })
+                })
+            }
+
+            /// The coordinates of self under `normal_basis()`
+            pub fn to_normal(self) -> $crate::f2x::F2x<{ Self::LIMBS }> {
+                Self::normal_basis().to_normal(&self.poly)
+            }
+
+            /// Convert coordinates under `normal_basis()` back into a field element
+            pub fn from_normal(coords: &$crate::f2x::F2x<{ Self::LIMBS }>) -> Self {
+                Self::from_poly(Self::normal_basis().to_poly(coords))
+            }
+
+            /// The Frobenius map self -> self^(2^k), computed through the normal basis so that the
+            /// cost does not depend on k
+            pub fn frobenius(&self, k: usize) -> Self {
+                Self::from_poly(Self::normal_basis().frobenius(&self.poly, k))
+            }
+
+            /// The absolute trace of self, which is either 0 or 1. Return true if the trace is 1
+            pub fn trace(&self) -> bool {
+                Self::normal_basis().trace(&self.to_normal())
+            }
+
+            /// The half trace of self, which is only defined when the exponent m is odd. For such
+            /// fields, h = half_trace(c) satisfies h^2 + h = c + Tr(c).
+            pub fn half_trace(&self) -> Option<Self> {
+                let basis = Self::normal_basis();
+                basis
+                    .half_trace(&self.to_normal())
+                    .map(|coords| Self::from_normal(&coords))
+            }
+
+            /// Solve z^2 + az + b = 0, returning both roots (which are identical if a = 0), or None
+            /// if the equation has no root in this field
+            pub fn solve_quadratic(a: &Self, b: &Self) -> Option<(Self, Self)> {
+                use $crate::galoisfields::FieldArithmetic;
+
+                if a.is_zero() {
+                    // z^2 = b has the unique root b^(2^(m-1))
+                    let root = b.frobenius(Self::BITS - 1);
+                    return Some((root, root));
+                }
+                // Substituting z = aw gives w^2 + w = b / a^2
+                let a_inv = a.modinv()?;
+                let c = b.modmul(&a_inv).modmul(&a_inv);
+                let basis = Self::normal_basis();
+                let w = Self::from_normal(&basis.solve_artin_schreier(&c.to_normal())?);
+                let root = a.modmul(&w);
+                Some((root, root.modadd(a)))
+            }
+        }
+    };
+}
+
+/// Without std there is nowhere to cache the normal basis, so the Frobenius map, trace, and half
+/// trace are computed by repeated squaring instead, which costs O(m) multiplications
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __normal_basis_field {
+    ($name:ident) => {
+        impl $name {
+            /// The Frobenius map self -> self^(2^k)
+            pub fn frobenius(&self, k: usize) -> Self {
+                use $crate::galoisfields::FieldArithmetic;
+
+                (0..k % Self::BITS).fold(*self, |acc, _| acc.square())
+            }
+
+            /// The absolute trace of self, the sum of its m conjugates, which is either 0 or 1.
+            /// Return true if the trace is 1
+            pub fn trace(&self) -> bool {
+                use $crate::galoisfields::FieldArithmetic;
+
+                (1..Self::BITS)
+                    .fold((*self, *self), |(acc, power), _| {
+                        let power = power.square();
+                        (acc.modadd(&power), power)
+                    })
+                    .0
+                    .is_one()
+            }
+
+            /// The half trace of self, the sum of self^(4^i) for i = 0, ..., (m - 1) / 2, which is
+            /// only defined when the exponent m is odd. For such fields, h = half_trace(c)
+            /// satisfies h^2 + h = c + Tr(c).
+            pub fn half_trace(&self) -> Option<Self> {
+                use $crate::galoisfields::FieldArithmetic;
+
+                if Self::BITS % 2 == 0 {
+                    return None;
+                }
+                let half_trace = (0..Self::BITS / 2)
+                    .fold((*self, *self), |(acc, power), _| {
+                        let power = power.square().square();
+                        (acc.modadd(&power), power)
+                    })
+                    .0;
+                Some(half_trace)
+            }
+        }
+    };
+}
+
+/// Discrete logarithms in a small field defined by `galois_field!`. The baby steps are kept in a
+/// `BTreeMap`, so this needs alloc; see `__subtle_binary_field` for why this is a separate macro.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __discrete_log_field {
+    ($name:ident) => {
+        impl $name {
+            /// Find the smallest k such that base^k = self using baby-step giant-step, or None if
+            /// self is not a power of base. Only supported for m <= `SMALL_FIELD_MAX_BITS`
+            pub fn discrete_log(&self, base: &Self) -> Option<u64> {
+                use $crate::galoisfields::FieldArithmetic;
+
+                let order = base.multiplicative_order()?;
+                if self.is_zero() {
+                    return None;
+                }
+                let steps = (1..=order).find(|k| k * k >= order).unwrap_or(1);
+                let key = |elem: &Self| {
+                    (0..Self::LIMBS).fold(0u64, |acc, i| {
+                        acc.checked_shl($crate::f2x::Word::BITS).unwrap_or(0)
+                            | *elem.poly.get_limb(i).unwrap() as u64
+                    })
+                };
+                let mut baby_steps = $crate::__private::BTreeMap::new();
+                let mut power = Self::ONE;
+                for j in 0..steps {
+                    baby_steps.entry(key(&power)).or_insert(j);
+                    power = power.modmul(base);
+                }
+                // power is now base^steps
+                let giant_step = power.modinv()?;
+                let mut gamma = *self;
+                for i in 0..steps {
+                    if let Some(j) = baby_steps.get(&key(&gamma)) {
+                        return Some((i * steps + j) % order);
+                    }
+                    gamma = gamma.modmul(&giant_step);
+                }
+                None
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __discrete_log_field {
+    ($name:ident) => {};
+}
+
+/// Implement `Add`, `Sub`, `Mul`, and their assigning forms for owned and borrowed operands of a
+/// field type by delegating to `FieldArithmetic`, as well as `Neg`, and `Sum` and `Product` over
+/// owned and borrowed elements.
+///
+/// Sums accumulate in place with `modadd_assign`, which never reduces in a binary field. Products
+/// reduce after every factor with `modmul_assign`: the unreduced product of two elements already
+/// fills the double-width accumulator, so reduction cannot be deferred any further.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __field_ops {
+    ($name:ident) => {
+        $crate::__field_ops!(@binop $name, Add, add, AddAssign, add_assign, modadd);
+        $crate::__field_ops!(@binop $name, Sub, sub, SubAssign, sub_assign, modsub);
+        $crate::__field_ops!(@binop $name, Mul, mul, MulAssign, mul_assign, modmul);
+        $crate::__field_ops!(@fold $name, Sum, sum, zero, modadd_assign);
+        $crate::__field_ops!(@fold $name, Product, product, one, modmul_assign);
+
+        impl core::ops::Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                -&self
+            }
+        }
+
+        impl core::ops::Neg for &$name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                let zero = <$name as $crate::galoisfields::FieldArithmetic>::zero();
+                $crate::galoisfields::FieldArithmetic::modsub(&zero, self)
+            }
+        }
+    };
+    (@fold $name:ident, $trait:ident, $method:ident, $identity:ident, $accumulate:ident) => {
+        impl core::iter::$trait for $name {
+            fn $method<I: Iterator<Item = $name>>(iter: I) -> $name {
+                let mut acc = <$name as $crate::galoisfields::FieldArithmetic>::$identity();
+                iter.for_each(|elem| {
+                    $crate::galoisfields::FieldArithmetic::$accumulate(&mut acc, &elem)
+                });
+                acc
+            }
+        }
+
+        impl<'a> core::iter::$trait<&'a $name> for $name {
+            fn $method<I: Iterator<Item = &'a $name>>(iter: I) -> $name {
+                let mut acc = <$name as $crate::galoisfields::FieldArithmetic>::$identity();
+                iter.for_each(|elem| {
+                    $crate::galoisfields::FieldArithmetic::$accumulate(&mut acc, elem)
+                });
+                acc
+            }
+        }
+    };
+    (
+        @binop
+        $name:ident,
+        $op:ident,
+        $method:ident,
+        $op_assign:ident,
+        $method_assign:ident,
+        $field_method:ident
+    ) => {
+        impl core::ops::$op<&$name> for &$name {
+            type Output = $name;
+
+            fn $method(self, rhs: &$name) -> $name {
+                $crate::galoisfields::FieldArithmetic::$field_method(self, rhs)
+            }
+        }
+
+        impl core::ops::$op<&$name> for $name {
+            type Output = $name;
+
+            fn $method(self, rhs: &$name) -> $name {
+                $crate::galoisfields::FieldArithmetic::$field_method(&self, rhs)
+            }
+        }
+
+        impl core::ops::$op<$name> for &$name {
+            type Output = $name;
+
+            fn $method(self, rhs: $name) -> $name {
+                $crate::galoisfields::FieldArithmetic::$field_method(self, &rhs)
+            }
+        }
+
+        impl core::ops::$op<$name> for $name {
+            type Output = $name;
+
+            fn $method(self, rhs: $name) -> $name {
+                $crate::galoisfields::FieldArithmetic::$field_method(&self, &rhs)
+            }
+        }
+
+        impl core::ops::$op_assign<&$name> for $name {
+            fn $method_assign(&mut self, rhs: &$name) {
+                *self = $crate::galoisfields::FieldArithmetic::$field_method(self, rhs);
+            }
+        }
+
+        impl core::ops::$op_assign<$name> for $name {
+            fn $method_assign(&mut self, rhs: $name) {
+                *self = $crate::galoisfields::FieldArithmetic::$field_method(self, &rhs);
+            }
+        }
+    };
+}
 
 /// An algebraic field is defined by 0, 1, addition, and multiplication. Every non-zero element
 /// should have a multiplicative inverse.
@@ -9,31 +488,395 @@ pub trait FieldArithmetic: Sized + Copy + Clone + PartialEq + Eq {
     fn is_one(&self) -> bool;
     fn zero() -> Self;
     fn one() -> Self;
-    fn random() -> Self;
-    fn random_with_rng(rng: &mut impl Rng) -> Self;
+    /// Sample a random element with the input RNG
+    fn random(rng: &mut impl CryptoRngCore) -> Self;
+
+    /// Sample a random element from the operating system's entropy source
+    #[cfg(feature = "getrandom")]
+    fn random_from_os() -> Self {
+        Self::random(&mut rand_core::OsRng)
+    }
+
+    /// Sample a random element from a source that need not implement this crate's version of
+    /// rand_core
+    fn random_from_entropy(source: &mut impl EntropySource) -> Self {
+        Self::random(&mut EntropyRng(source))
+    }
     fn modadd(&self, rhs: &Self) -> Self;
     fn modsub(&self, rhs: &Self) -> Self;
     fn modmul(&self, rhs: &Self) -> Self;
     fn modinv(&self) -> Option<Self>;
 
+    /// Like `modinv`, but return `SssError::NotInvertible` for zero
+    fn try_inv(&self) -> Result<Self, SssError> {
+        self.modinv().ok_or(SssError::NotInvertible)
+    }
+
+    /// The inverse computed with a sequence of field operations that does not depend on self,
+    /// mapping zero to zero. The default falls back to `modinv`, which need not run in constant
+    /// time; every field in this crate overrides it.
+    fn inv_ct(&self) -> Self {
+        self.modinv().unwrap_or(Self::zero())
+    }
+
     // NOTE: serialization and deserialization is technically not part of FieldArithmetic; consider
     // moving them to a different trait later
     fn bytes() -> usize;
     fn write_be_bytes(&self, dst: &mut [u8]);
     fn from_be_bytes(src: &[u8]) -> Self;
-    // TODO: I don't need modular exponentiation yet, but it is common
-    // fn modexp(&self, exp: usize) -> Self;
+
+    /// The element whose big-endian encoding is the integer `val`, or None if `val` is not a
+    /// canonical encoding. The default works in a stack buffer of 8 bytes, so implementations
+    /// whose encoding is longer than that must override it.
+    fn try_from_u64(val: u64) -> Option<Self> {
+        let be_bytes = val.to_be_bytes();
+        let start = be_bytes
+            .len()
+            .checked_sub(Self::bytes())
+            .expect("encodings longer than 8 bytes need their own try_from_u64");
+        if be_bytes[..start].iter().any(|byte| *byte != 0) {
+            return None;
+        }
+        let elem = Self::from_be_bytes(&be_bytes[start..]);
+        let mut canonical = [0u8; 8];
+        elem.write_be_bytes(&mut canonical[start..]);
+        (canonical == be_bytes).then_some(elem)
+    }
+
+    /// The sum of pairwise products of lhs and rhs. Will panic if the lengths do not match.
+    /// Implementations can override this to reduce once at the end instead of after every product.
+    fn dot(lhs: &[Self], rhs: &[Self]) -> Self {
+        if lhs.len() != rhs.len() {
+            panic!("operand lengths do not match");
+        }
+        lhs.iter()
+            .zip(rhs.iter())
+            .fold(Self::zero(), |acc, (a, b)| acc.modadd(&a.modmul(b)))
+    }
+
+    /// Compute self * self. Implementations can override this with a cheaper routine than a full
+    /// multiplication
+    fn square(&self) -> Self {
+        self.modmul(self)
+    }
+
+    /// Add rhs to self in place. Implementations can override this to avoid the temporary
+    fn modadd_assign(&mut self, rhs: &Self) {
+        *self = self.modadd(rhs);
+    }
+
+    /// Subtract rhs from self in place
+    fn modsub_assign(&mut self, rhs: &Self) {
+        *self = self.modsub(rhs);
+    }
+
+    /// Multiply self by rhs in place
+    fn modmul_assign(&mut self, rhs: &Self) {
+        *self = self.modmul(rhs);
+    }
+
+    /// Square self in place
+    fn sqr_assign(&mut self) {
+        *self = self.square();
+    }
+
+    /// Square-and-multiply exponentiation
+    fn modexp(&self, exp: u64) -> Self {
+        let bits = u64::BITS - exp.leading_zeros();
+        (0..bits).rev().fold(Self::one(), |acc, i| {
+            let acc = acc.square();
+            if (exp >> i) & 1 == 1 {
+                acc.modmul(self)
+            } else {
+                acc
+            }
+        })
+    }
+
+    /// Square-and-multiply exponentiation that squares and multiplies for each of the 64 bits of
+    /// the exponent, so that the sequence of field operations does not depend on the exponent.
+    /// Whether the whole computation is constant time depends on the field arithmetic.
+    fn modexp_ct(&self, exp: u64) -> Self {
+        (0..u64::BITS).rev().fold(Self::one(), |acc, i| {
+            let acc = acc.square();
+            let prod = acc.modmul(self);
+            if (exp >> i) & 1 == 1 {
+                prod
+            } else {
+                acc
+            }
+        })
+    }
+}
+
+/// A binary extension field GF(2^m), which exposes the exponent m to generic code
+pub trait BinaryField: FieldArithmetic {
+    /// The exponent m
+    const BITS: usize;
+
+    /// A generator of the multiplicative group. Only supported for m <= `SMALL_FIELD_MAX_BITS`
+    fn primitive_element() -> Self;
+
+    /// The unique square root. Squaring is a bijection of GF(2^m) whose inverse is
+    /// a -> a^(2^(m - 1)), computed with m - 1 squarings
+    fn sqrt(&self) -> Self {
+        (1..Self::BITS).fold(*self, |acc, _| acc.square())
+    }
 }
 
+/// The algorithm with which a binary field defined by `galois_field!` computes inverses
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Inversion {
+    /// Extended Euclidean algorithm on the polynomial representation
+    Euclid,
+
+    /// Itoh-Tsujii exponentiation by 2^m - 2, which runs a fixed sequence of squarings and
+    /// multiplications
+    ItohTsujii,
+}
+
+/// The largest exponent m for which GF(2^m) supports primitive element search and discrete logs
+pub const SMALL_FIELD_MAX_BITS: usize = 20;
+
+/// The distinct prime factors of n in ascending order, found by trial division
+pub fn prime_factors(n: u64) -> PrimeFactors {
+    PrimeFactors { n, p: 2 }
+}
+
+/// Iterator over the distinct prime factors of an integer, returned by `prime_factors`
+pub struct PrimeFactors {
+    /// What is left of n after dividing out the factors found so far
+    n: u64,
+    /// The next trial divisor
+    p: u64,
+}
+
+impl Iterator for PrimeFactors {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        while self.p * self.p <= self.n {
+            let p = self.p;
+            self.p += 1;
+            if self.n.is_multiple_of(p) {
+                while self.n.is_multiple_of(p) {
+                    self.n /= p;
+                }
+                return Some(p);
+            }
+        }
+        if self.n > 1 {
+            return Some(core::mem::replace(&mut self.n, 1));
+        }
+        None
+    }
+}
+
+/// Invert every element of the slice in place using Montgomery's trick, which costs a single
+/// `inv_ct` plus about 3n multiplications. Zero elements have no inverse and are left unchanged.
+/// See `batch_inv_with_scratch` for a version that does not allocate.
+#[cfg(feature = "alloc")]
+pub fn batch_inv<F: FieldArithmetic>(elems: &mut [F]) {
+    let mut prefixes = vec![F::zero(); elems.len()];
+    batch_inv_with_scratch(elems, &mut prefixes);
+}
+
+/// Like `batch_inv`, but keep the running products in the caller's scratch slice, which must be
+/// at least as long as elems
+pub fn batch_inv_with_scratch<F: FieldArithmetic>(elems: &mut [F], scratch: &mut [F]) {
+    // prefixes[i] is the product of all non-zero elements before index i
+    let prefixes = &mut scratch[..elems.len()];
+    let mut acc = F::one();
+    for (elem, prefix) in elems.iter().zip(prefixes.iter_mut()) {
+        *prefix = acc;
+        if !elem.is_zero() {
+            acc = acc.modmul(elem);
+        }
+    }
+    // Walking backwards, acc is the inverse of the product of the non-zero elements up to index i
+    let mut acc = acc.inv_ct();
+    for (elem, prefix) in elems.iter_mut().zip(prefixes.iter()).rev() {
+        if elem.is_zero() {
+            continue;
+        }
+        let inv = acc.modmul(prefix);
+        acc = acc.modmul(elem);
+        *elem = inv;
+    }
+}
+
+/// Define a binary extension field GF(2^m) backed by `$limbs` limbs.
+///
+/// The most convenient form takes the exponent m and the powers of the non-leading terms of the
+/// modulus, e.g. `galois_field!(GF2p128, 128, [77, 35, 11, 0])` for x^128 + x^77 + x^35 + x^11 + 1;
+/// the number of limbs and the limb representation are computed at compile time. Fields defined
+/// this way reduce products by folding the sparse modulus; the other forms use Barrett reduction.
+/// Either way the reduction runs in constant time: the number of folds is fixed by the modulus, and
+/// Barrett reduction is a fixed sequence of multiplications.
+///
+/// The exponent-list form optionally takes an `Inversion` as the last argument to select how
+/// `modinv` is computed; all other forms use the extended Euclidean algorithm.
+///
+/// The modulus can also be supplied as an explicit `WideF2x`. The three-argument form is for
+/// fields whose exponent fills all limbs (m = Word::BITS * $limbs). The four-argument form takes the
+/// exponent explicitly, which is needed for fields whose exponent is not a multiple of the limb
+/// width.
+///
+/// The modulus must satisfy the following:
+/// - its degree is exactly m, which also rules out the zero polynomial
+/// - m is no more than Word::BITS * $limbs, so that every reduced element fits into a single
+///   `F2x<$limbs>`
+/// - it is irreducible over GF(2), otherwise the resulting ring has zero divisors and `modinv`
+///   will fail on some non-zero elements
+///
+/// The first two are checked at compile time. Irreducibility is too expensive to check in a const
+/// context, so debug builds check it on the first multiplication or inversion instead.
+///
+/// ```
+/// use shamirsecretsharing::f2x::{F2x, WideF2x};
+/// use shamirsecretsharing::galois_field;
+/// use shamirsecretsharing::galoisfields::FieldArithmetic;
+///
+/// // x^16 + x^5 + x^3 + x + 1
+/// galois_field!(GF2p16, 16, [5, 3, 1, 0]);
+/// galois_field!(
+///     GF2p16Limbs,
+///     1,
+///     16,
+///     WideF2x::<1>::from_exponents(&[16, 5, 3, 1, 0])
+/// );
+/// assert_eq!(GF2p16::MODULUS, GF2p16Limbs::MODULUS);
+///
+/// let elem = GF2p16::from_poly(F2x::<1>::from_u128(0x1234));
+/// let inv = elem.modinv().unwrap();
+/// assert!(elem.modmul(&inv).is_one());
+/// ```
+///
+/// A modulus whose degree is not the exponent fails to compile:
+///
+/// ```compile_fail
+/// use shamirsecretsharing::f2x::WideF2x;
+/// use shamirsecretsharing::galois_field;
+///
+/// galois_field!(GF2p16, 1, 16, WideF2x::<1>::from_exponents(&[15, 5, 3, 1, 0]));
+/// ```
+#[macro_export]
 macro_rules! galois_field {
+    ($name:ident, $degree:literal, [$($exp:literal),+ $(,)?]) => {
+        $crate::galois_field!(
+            $name,
+            $degree,
+            [$($exp),+],
+            $crate::galoisfields::Inversion::Euclid
+        );
+    };
+    ($name:ident, $degree:literal, [$($exp:literal),+ $(,)?], $inversion:expr) => {
+        $crate::galois_field!(
+            @define
+            $name,
+            ($degree as usize).div_ceil($crate::f2x::Word::BITS as usize),
+            $degree,
+            $crate::f2x::WideF2x::from_exponents(&[$degree, $($exp),+]),
+            Some(&[$($exp),+]),
+            $inversion
+        );
+    };
     ($name:ident, $limbs:literal, $irreducible:expr) => {
-        /// An element of the binary extension field with the specified exponent
-        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        $crate::galois_field!(
+            $name,
+            $limbs,
+            $limbs * ($crate::f2x::Word::BITS as usize),
+            $irreducible
+        );
+    };
+    ($name:ident, $limbs:expr, $degree:expr, $irreducible:expr) => {
+        $crate::galois_field!(
+            @define
+            $name,
+            $limbs,
+            $degree,
+            $irreducible,
+            None,
+            $crate::galoisfields::Inversion::Euclid
+        );
+    };
+    (
+        @define
+        $name:ident,
+        $limbs:expr,
+        $degree:expr,
+        $irreducible:expr,
+        $sparse:expr,
+        $inversion:expr
+    ) => {
+        /// An element of the binary extension field with the specified exponent. `Ord` and `Hash`
+        /// follow the polynomial representation; see `F2x`.
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub struct $name {
-            pub poly: F2x<{ Self::LIMBS }>,
+            pub poly: $crate::f2x::F2x<{ Self::LIMBS }>,
         }
 
-        impl FieldArithmetic for $name {
+        $crate::__subtle_binary_field!($name);
+        $crate::__arbitrary_binary_field!($name);
+        $crate::__field_ops!($name);
+        $crate::__num_traits_field!($name);
+        $crate::__ff_field!($name);
+        $crate::__normal_basis_field!($name);
+        $crate::__discrete_log_field!($name);
+
+        // Field elements are `Copy`, so they cannot be zeroized on drop
+        impl $crate::__private::zeroize::Zeroize for $name {
+            fn zeroize(&mut self) {
+                $crate::__private::zeroize::Zeroize::zeroize(&mut self.poly);
+            }
+        }
+
+        // Reject a mistyped modulus at compile time
+        const _: () = {
+            assert!(
+                !$name::MODULUS.is_zero(),
+                concat!("the modulus of ", stringify!($name), " is zero")
+            );
+            assert!(
+                $name::BITS <= $crate::f2x::F2x::<{ $name::LIMBS }>::BITS,
+                concat!("the elements of ", stringify!($name), " do not fit into its limbs")
+            );
+            assert!(
+                matches!(
+                    $name::MODULUS.degree(),
+                    $crate::f2x::Degree::NonNegative(degree) if degree == $name::BITS
+                ),
+                concat!("the degree of the modulus of ", stringify!($name), " is not its exponent")
+            );
+        };
+
+        impl $crate::__private::rand::distributions::Distribution<$name>
+            for $crate::__private::rand::distributions::Standard
+        {
+            fn sample<R: $crate::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                // Draw only the bytes that hold the element, big-endian, so that the same stream
+                // samples the same element whatever the width of the limbs
+                let mut buf = [0u8; $crate::f2x::F2x::<{ $name::LIMBS }>::BYTES];
+                let start = buf.len() - $name::BYTES;
+                rng.fill_bytes(&mut buf[start..]);
+                // Clear the unused high-order bits so that the element is already reduced
+                let unused = $crate::f2x::F2x::<{ $name::LIMBS }>::BITS - $name::BITS;
+                let poly = $crate::f2x::F2x::<{ $name::LIMBS }>::read_from_be_bytes(&buf)
+                    .shl(unused)
+                    .shr(unused);
+                $name { poly }
+            }
+        }
+
+        impl $crate::galoisfields::BinaryField for $name {
+            const BITS: usize = $name::BITS;
+
+            fn primitive_element() -> Self {
+                $name::primitive_element()
+            }
+        }
+
+        impl $crate::galoisfields::FieldArithmetic for $name {
             fn is_zero(&self) -> bool {
                 *self == Self::ZERO
             }
@@ -46,19 +889,10 @@ macro_rules! galois_field {
             fn one() -> Self {
                 Self::ONE
             }
-            fn random() -> Self {
-                let mut limbs = [0; Self::LIMBS];
-                let mut rng = rand::thread_rng();
-                rng.fill(&mut limbs);
-                let poly = F2x::from_limbs(limbs);
-                Self { poly }
-            }
-
-            fn random_with_rng(rng: &mut impl Rng) -> Self {
-                let mut limbs = [0; Self::LIMBS];
-                rng.fill(&mut limbs);
-                let poly = F2x::from_limbs(limbs);
-                Self { poly }
+            fn random(
+                rng: &mut impl $crate::__private::rand_core::CryptoRngCore,
+            ) -> Self {
+                $crate::__private::rand::Rng::gen(rng)
             }
             fn modadd(&self, rhs: &Self) -> Self {
                 Self::from_poly(self.poly.add(&rhs.poly))
@@ -68,92 +902,576 @@ macro_rules! galois_field {
                 self.modadd(rhs)
             }
 
+            fn modadd_assign(&mut self, rhs: &Self) {
+                self.poly.add_assign(&rhs.poly);
+            }
+
+            fn modsub_assign(&mut self, rhs: &Self) {
+                self.poly.sub_assign(&rhs.poly);
+            }
+
             fn modmul(&self, rhs: &Self) -> Self {
-                let prod = self.poly.widening_mul(&rhs.poly);
-                let (_, rem) = prod.div_rem(&Self::MODULUS);
-                Self::from_poly(rem.truncate())
+                Self::reduce(&self.poly.widening_mul(&rhs.poly))
+            }
+
+            fn square(&self) -> Self {
+                Self::reduce(&self.poly.widening_square())
             }
 
-            /// Compute the multiplicative inverse under the modulus. Will return None if self is
-            /// not invertible
+            /// Compute the multiplicative inverse using the algorithm selected by `INVERSION`. Will
+            /// return None if self is not invertible
             fn modinv(&self) -> Option<Self> {
-                let inverse = self.poly.modinv(&Self::MODULUS);
-                inverse.map_or(None, |poly| Some(Self::from_poly(poly)))
+                Self::debug_assert_irreducible();
+                match Self::INVERSION {
+                    $crate::galoisfields::Inversion::Euclid => {
+                        let inverse = self.poly.modinv(&Self::MODULUS);
+                        inverse.map_or(None, |poly| Some(Self::from_poly(poly)))
+                    }
+                    $crate::galoisfields::Inversion::ItohTsujii => self.itoh_tsujii_inv(),
+                }
+            }
+
+            fn inv_ct(&self) -> Self {
+                $name::inv_ct(self)
+            }
+
+            /// Select between the square and the product with `F2x::ct_select` instead of a
+            /// branch on the exponent bit
+            fn modexp_ct(&self, exp: u64) -> Self {
+                (0..u64::BITS).rev().fold(Self::ONE, |acc, i| {
+                    let acc = acc.square();
+                    let prod = acc.modmul(self);
+                    Self::from_poly($crate::f2x::F2x::ct_select(
+                        &acc.poly,
+                        &prod.poly,
+                        (exp >> i) & 1 == 1,
+                    ))
+                })
+            }
+
+            /// Accumulate the unreduced products and reduce only once at the end
+            fn dot(lhs: &[Self], rhs: &[Self]) -> Self {
+                if lhs.len() != rhs.len() {
+                    panic!("operand lengths do not match");
+                }
+                let sum = lhs
+                    .iter()
+                    .zip(rhs.iter())
+                    .fold($crate::f2x::WideF2x::ZERO, |mut acc, (a, b)| {
+                        acc.add_assign(&a.poly.widening_mul(&b.poly));
+                        acc
+                    });
+                Self::reduce(&sum)
             }
 
             fn bytes() -> usize {
                 Self::BYTES
             }
 
+            /// Write the lowest `BYTES` bytes of the polynomial, which hold all of its coefficients.
+            /// Will panic if the buffer is not exactly `BYTES` long
             fn write_be_bytes(&self, dst: &mut [u8]) {
-                self.poly.write_to_be_bytes(dst);
+                if dst.len() != Self::BYTES {
+                    panic!("input buffer size is incorrect");
+                }
+                let mut buf = [0u8; $crate::f2x::F2x::<{ $name::LIMBS }>::BYTES];
+                self.poly.write_to_be_bytes(&mut buf);
+                dst.copy_from_slice(&buf[(buf.len() - Self::BYTES)..]);
             }
 
+            /// Deserialize and reduce under the modulus, so that the output is always a canonical
+            /// field element even if the input encodes a polynomial of too high a degree. Will
+            /// panic if the input is not exactly `BYTES` long
             fn from_be_bytes(src: &[u8]) -> Self {
-                let poly = F2x::<{ Self::LIMBS }>::read_from_be_bytes(src);
-                Self::from_poly(poly)
+                if src.len() != Self::BYTES {
+                    panic!("input buffer size is incorrect");
+                }
+                let mut buf = [0u8; $crate::f2x::F2x::<{ $name::LIMBS }>::BYTES];
+                let start = buf.len() - Self::BYTES;
+                buf[start..].copy_from_slice(src);
+                let poly = $crate::f2x::F2x::<{ Self::LIMBS }>::read_from_be_bytes(&buf);
+                Self::reduce(&poly.widen())
+            }
+
+            fn try_from_u64(val: u64) -> Option<Self> {
+                if Self::BITS < 64 && val >> Self::BITS != 0 {
+                    return None;
+                }
+                Some(Self::from_u64(val))
             }
         }
 
-        impl $name {
-            pub const LIMBS: usize = $limbs;
-            pub const BITS: usize = (Word::BITS as usize) * Self::LIMBS;
-            pub const BYTES: usize = Self::BITS / 8;
-            pub const MODULUS: WideF2x<{ Self::LIMBS }> = $irreducible;
-            pub const ONE: Self = Self::from_poly(F2x::<{ Self::LIMBS }>::ONE);
-            pub const ZERO: Self = Self::from_poly(F2x::<{ Self::LIMBS }>::ZERO);
-
-            /// Sample a random element
-            pub const fn from_poly(poly: F2x<{ Self::LIMBS }>) -> Self {
-                Self { poly }
-            }
+        impl Default for $name {
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl $name {
+            pub const LIMBS: usize = $limbs;
+            /// The exponent m of GF(2^m), which is also the degree of the modulus
+            pub const BITS: usize = $degree;
+            /// The size of the serialized element: the exponent rounded up to whole bytes, which
+            /// does not depend on the width of the limbs
+            pub const BYTES: usize = Self::BITS.div_ceil(8);
+            pub const MODULUS: $crate::f2x::WideF2x<{ Self::LIMBS }> = $irreducible;
+            pub const ONE: Self = Self::from_poly($crate::f2x::F2x::<{ Self::LIMBS }>::ONE);
+            pub const ZERO: Self = Self::from_poly($crate::f2x::F2x::<{ Self::LIMBS }>::ZERO);
+
+            /// The powers of the non-leading terms of the modulus, if the field was defined with an
+            /// exponent list
+            const SPARSE_EXPONENTS: Option<&'static [usize]> = $sparse;
+            /// The algorithm used by `modinv`
+            pub const INVERSION: $crate::galoisfields::Inversion = $inversion;
+
+            pub const fn from_poly(poly: $crate::f2x::F2x<{ Self::LIMBS }>) -> Self {
+                Self { poly }
+            }
+
+            /// The element whose polynomial has the bits of `val` as coefficients, so that
+            /// `from_u64(2)` is x. Will panic if the polynomial does not fit into the field.
+            pub const fn from_u64(val: u64) -> Self {
+                if Self::BITS < 64 && val >> Self::BITS != 0 {
+                    panic!("value is too large");
+                }
+                Self::from_poly($crate::f2x::F2x::from_u128(val as u128))
+            }
+
+            /// See `from_u64`
+            pub const fn from_u16(val: u16) -> Self {
+                Self::from_u64(val as u64)
+            }
+
+            /// Reduce a product under the modulus, folding the high-order terms directly if the
+            /// modulus is sparse and using Barrett reduction otherwise
+            fn reduce(wide: &$crate::f2x::WideF2x<{ Self::LIMBS }>) -> Self {
+                Self::debug_assert_irreducible();
+                match Self::SPARSE_EXPONENTS {
+                    Some(exps) => Self::from_poly(wide.reduce_sparse(Self::BITS, exps).truncate()),
+                    None => Self::from_poly(Self::BARRETT.reduce(wide)),
+                }
+            }
+
+            /// Panic in debug builds if the modulus is reducible. The test runs once per field, on
+            /// first use
+            fn debug_assert_irreducible() {
+                use core::sync::atomic::{AtomicBool, Ordering};
+
+                if cfg!(debug_assertions) {
+                    static IRREDUCIBLE: AtomicBool = AtomicBool::new(false);
+                    if !IRREDUCIBLE.load(Ordering::Relaxed) {
+                        assert!(
+                            $name::MODULUS.is_irreducible(),
+                            concat!("the modulus of ", stringify!($name), " is not irreducible")
+                        );
+                        IRREDUCIBLE.store(true, Ordering::Relaxed);
+                    }
+                }
+            }
+
+            /// The Barrett reciprocal of the modulus, computed at compile time
+            const BARRETT: $crate::f2x::Barrett<{ Self::LIMBS }> =
+                $crate::f2x::Barrett::new(&Self::MODULUS);
+
+            /// Field addition as a const fn, so that constants can be computed at compile time
+            pub const fn modadd_const(&self, other: &Self) -> Self {
+                Self::from_poly(self.poly.add(&other.poly))
+            }
+
+            /// Field multiplication as a const fn, so that constants can be computed at compile
+            /// time. The product is computed bit by bit and reduced without the Barrett reciprocal,
+            /// so this is much slower than `modmul` at runtime.
+            pub const fn modmul_const(&self, other: &Self) -> Self {
+                let wide = self.poly.widening_mul_const(&other.poly);
+                let rem = match Self::SPARSE_EXPONENTS {
+                    Some(exps) => wide.reduce_sparse(Self::BITS, exps),
+                    None => wide.div_rem_ct(&Self::MODULUS).1,
+                };
+                Self::from_poly(rem.truncate())
+            }
+
+            /// Raise self to the specified power with `modmul_const`, e.g. to tabulate the powers
+            /// of a generator at compile time
+            pub const fn modexp_const(&self, exp: u64) -> Self {
+                let mut power = Self::ONE;
+                let mut i = u64::BITS;
+                while i > 0 {
+                    i -= 1;
+                    power = power.modmul_const(&power);
+                    if (exp >> i) & 1 == 1 {
+                        power = power.modmul_const(self);
+                    }
+                }
+                power
+            }
+
+            /// Constant-time equality. See `F2x::ct_eq`
+            pub fn ct_eq(&self, other: &Self) -> bool {
+                self.poly.ct_eq(&other.poly)
+            }
+
+            /// Constant-time version of `is_zero`
+            pub fn ct_is_zero(&self) -> bool {
+                self.poly.ct_is_zero()
+            }
+
+            /// Constant-time version of `is_one`
+            pub fn ct_is_one(&self) -> bool {
+                self.poly.ct_is_one()
+            }
+
+            /// Compute the inverse as self^(2^m - 2) by Fermat's little theorem. The exponent is m - 1
+            /// ones followed by a zero in binary, so the schedule of m squarings and m - 1
+            /// multiplications is fixed by the field and does not depend on self. Zero is mapped to
+            /// zero.
+            pub fn inv_ct(&self) -> Self {
+                use $crate::galoisfields::FieldArithmetic;
+
+                (1..Self::BITS)
+                    .fold(Self::ONE, |acc, _| acc.square().modmul(self))
+                    .square()
+            }
+
+            /// Compute the inverse as (self^(2^(m-1) - 1))^2 = self^(2^m - 2) with the Itoh-Tsujii
+            /// addition chain: self^(2^(i+j) - 1) = (self^(2^i - 1))^(2^j) * self^(2^j - 1). This
+            /// takes m - 1 squarings and O(log m) multiplications, and the sequence of operations
+            /// only depends on m. Return None if self is zero.
+            pub fn itoh_tsujii_inv(&self) -> Option<Self> {
+                use $crate::galoisfields::FieldArithmetic;
+
+                let n = Self::BITS - 1;
+                // beta is self^(2^k - 1), starting from the leading bit of n where k = 1
+                let (mut beta, mut k) = (*self, 1);
+                let bits = (usize::BITS - n.leading_zeros()).saturating_sub(1);
+                for bit in (0..bits).rev() {
+                    let mut power = beta;
+                    for _ in 0..k {
+                        power.sqr_assign();
+                    }
+                    beta = power.modmul(&beta);
+                    k *= 2;
+                    if (n >> bit) & 1 == 1 {
+                        beta = beta.square().modmul(self);
+                        k += 1;
+                    }
+                }
+                let inverse = if n == 0 { beta } else { beta.square() };
+
+                if self.ct_is_zero() {
+                    None
+                } else {
+                    Some(inverse)
+                }
+            }
+
+            /// The order 2^m - 1 of the multiplicative group. Panics if the field is too large for
+            /// exhaustive search
+            fn small_group_order() -> u64 {
+                if Self::BITS > $crate::galoisfields::SMALL_FIELD_MAX_BITS {
+                    panic!("field is too large for exhaustive search");
+                }
+                (1 << Self::BITS) - 1
+            }
+
+            /// The multiplicative order of self, or None if self is zero. Only supported for
+            /// m <= `SMALL_FIELD_MAX_BITS`
+            pub fn multiplicative_order(&self) -> Option<u64> {
+                use $crate::galoisfields::FieldArithmetic;
+
+                let group_order = Self::small_group_order();
+                if self.is_zero() {
+                    return None;
+                }
+                let mut order = group_order;
+                for p in $crate::galoisfields::prime_factors(group_order) {
+                    while order.is_multiple_of(p) && self.modexp(order / p).is_one() {
+                        order /= p;
+                    }
+                }
+                Some(order)
+            }
+
+            /// Return true if self generates the multiplicative group. Only supported for
+            /// m <= `SMALL_FIELD_MAX_BITS`
+            pub fn is_primitive(&self) -> bool {
+                self.multiplicative_order() == Some(Self::small_group_order())
+            }
+
+            /// The primitive element whose polynomial representation has the smallest value. Only
+            /// supported for m <= `SMALL_FIELD_MAX_BITS`
+            pub fn primitive_element() -> Self {
+                use $crate::galoisfields::FieldArithmetic;
+
+                (1..=Self::small_group_order())
+                    .map(|value| {
+                        let buf = value.to_be_bytes();
+                        Self::from_be_bytes(&buf[(buf.len() - Self::BYTES)..])
+                    })
+                    .find(|elem| elem.is_primitive())
+                    .expect("multiplicative group has no generator")
+            }
+        }
+    };
+}
+
+// x^8 + x^4 + x^3 + x + 1 (the AES field), too small for secret sharing on its own but used for
+// splitting secrets byte by byte
+#[cfg(feature = "gf8")]
+galois_field!(GF2p8, 8, [4, 3, 1, 0]);
+
+// x^16 + x^5 + x^3 + x + 1, too small for secret sharing but used as the base of tower fields
+#[cfg(feature = "gf16")]
+galois_field!(GF2p16, 16, [5, 3, 1, 0]);
+
+// x^128 + x^77 + x^35 + x^11 + 1
+#[cfg(feature = "gf128")]
+galois_field!(GF2p128, 128, [77, 35, 11, 0]);
+
+// x^192 + x^142 + x^103 + x^17 + 1
+#[cfg(feature = "gf192")]
+galois_field!(GF2p192, 192, [142, 103, 17, 0]);
+
+// x^256 + x^241 + x^178 + x^121 + 1
+#[cfg(feature = "gf256")]
+galois_field!(GF2p256, 256, [241, 178, 121, 0]);
+
+// x^233 + x^74 + 1 (NIST B-233 and K-233)
+#[cfg(feature = "binary-curves")]
+galois_field!(GF2p233, 233, [74, 0]);
+
+// x^283 + x^12 + x^7 + x^5 + 1 (NIST B-283 and K-283)
+#[cfg(feature = "binary-curves")]
+galois_field!(GF2p283, 283, [12, 7, 5, 0]);
+
+// x^409 + x^87 + 1 (NIST B-409 and K-409)
+#[cfg(feature = "binary-curves")]
+galois_field!(GF2p409, 409, [87, 0]);
+
+// x^571 + x^10 + x^5 + x^2 + 1 (NIST B-571 and K-571)
+#[cfg(feature = "binary-curves")]
+galois_field!(GF2p571, 571, [10, 5, 2, 0]);
+
+/// An element of GF(2^m) whose modulus is a runtime value instead of a compile-time constant,
+/// backed by `L` limbs. This is useful when field parameters are negotiated or loaded at runtime.
+///
+/// Each element carries its own modulus. Because `FieldArithmetic::zero`, `one`, `random`, and
+/// `from_be_bytes` have no way of knowing the modulus, elements they produce are "unbound": they
+/// adopt (and are reduced under) the modulus of the first bound element they interact with.
+/// Combining two elements bound to different moduli will panic.
+#[derive(Debug, Copy, Clone)]
+pub struct DynGf2<const L: usize> {
+    poly: F2x<L>,
+
+    /// The modulus and its precomputed Barrett reciprocal, or None if this element is not bound to
+    /// any field yet
+    field: Option<Barrett<L>>,
+}
+
+impl<const L: usize> DynGf2<L> {
+    /// Create an element under the given modulus, reducing the input polynomial if necessary.
+    /// Will panic if the modulus has degree 0 or does not fit into the limbs. The modulus is
+    /// assumed to be irreducible.
+    pub fn new(poly: F2x<L>, modulus: WideF2x<L>) -> Self {
+        Self::unbound(poly).bind(&Some(Barrett::new(&modulus)))
+    }
+
+    /// Create an element that is not yet bound to any modulus
+    pub const fn unbound(poly: F2x<L>) -> Self {
+        Self { poly, field: None }
+    }
+
+    /// Sample a random element under the given modulus
+    pub fn random_with_modulus(modulus: WideF2x<L>, rng: &mut impl CryptoRngCore) -> Self {
+        Self::new(Self::random(rng).poly, modulus)
+    }
+
+    /// The polynomial representation of this element
+    pub fn poly(&self) -> &F2x<L> {
+        &self.poly
+    }
+
+    /// The modulus this element is bound to, or None if the element is unbound
+    pub fn modulus(&self) -> Option<&WideF2x<L>> {
+        self.field.as_ref().map(|field| field.modulus())
+    }
+
+    /// Bind self to the input field. Will panic if self is already bound to a different modulus
+    fn bind(&self, field: &Option<Barrett<L>>) -> Self {
+        match (&self.field, field) {
+            (_, None) => *self,
+            (Some(own), Some(other)) if own.modulus() != other.modulus() => {
+                panic!("attempt to mix elements of different fields")
+            }
+            (Some(_), Some(_)) => *self,
+            (None, Some(other)) => Self {
+                poly: other.reduce(&self.poly.widen()),
+                field: Some(*other),
+            },
+        }
+    }
+
+    /// Bind both operands to a common modulus
+    fn bind_pair(&self, rhs: &Self) -> (Self, Self) {
+        let field = self.field.or(rhs.field);
+        (self.bind(&field), rhs.bind(&field))
+    }
+}
+
+impl<const L: usize> PartialEq for DynGf2<L> {
+    fn eq(&self, other: &Self) -> bool {
+        let (lhs, rhs) = self.bind_pair(other);
+        lhs.poly == rhs.poly
+    }
+}
+
+impl<const L: usize> Eq for DynGf2<L> {}
+
+impl<const L: usize> FieldArithmetic for DynGf2<L> {
+    fn is_zero(&self) -> bool {
+        self.poly.is_zero()
+    }
+    fn is_one(&self) -> bool {
+        self.poly == F2x::<L>::ONE
+    }
+    fn zero() -> Self {
+        Self::unbound(F2x::<L>::ZERO)
+    }
+    fn one() -> Self {
+        Self::unbound(F2x::<L>::ONE)
+    }
+    /// Sample an unbound element; it will be reduced once it is bound to a modulus
+    fn random(rng: &mut impl CryptoRngCore) -> Self {
+        let mut limbs = [0; L];
+        rng.fill(&mut limbs[..]);
+        Self::unbound(F2x::from_limbs(limbs))
+    }
+
+    fn modadd(&self, rhs: &Self) -> Self {
+        let (lhs, rhs) = self.bind_pair(rhs);
+        Self {
+            poly: lhs.poly.add(&rhs.poly),
+            field: lhs.field,
+        }
+    }
+
+    fn modsub(&self, rhs: &Self) -> Self {
+        self.modadd(rhs)
+    }
+
+    /// Will panic if both operands are unbound, unless the product fits without reduction
+    fn modmul(&self, rhs: &Self) -> Self {
+        let (lhs, rhs) = self.bind_pair(rhs);
+        let prod = lhs.poly.widening_mul(&rhs.poly);
+        match lhs.field {
+            Some(field) => Self {
+                poly: field.reduce(&prod),
+                field: lhs.field,
+            },
+            None => Self::unbound(prod.truncate()),
+        }
+    }
+
+    fn square(&self) -> Self {
+        let square = self.poly.widening_square();
+        match self.field {
+            Some(field) => Self {
+                poly: field.reduce(&square),
+                field: self.field,
+            },
+            None => Self::unbound(square.truncate()),
+        }
+    }
+
+    /// Compute the multiplicative inverse under the modulus. Will panic if self is unbound and is
+    /// neither 0 nor 1, since the inverse depends on the modulus.
+    fn modinv(&self) -> Option<Self> {
+        match self.modulus() {
+            Some(modulus) => self.poly.modinv(modulus).map(|poly| Self {
+                poly,
+                field: self.field,
+            }),
+            None if self.is_zero() || self.is_one() => self.is_one().then_some(*self),
+            None => panic!("attempt to invert an element that is not bound to a modulus"),
+        }
+    }
+
+    /// Fermat inversion self^(2^m - 2) like the fields of `galois_field!`, where m is the degree
+    /// of the modulus. Will panic like `modinv` if self is unbound and is neither 0 nor 1
+    fn inv_ct(&self) -> Self {
+        let bits = match self.modulus().map(|modulus| modulus.degree()) {
+            Some(Degree::NonNegative(bits)) => bits,
+            _ => return self.modinv().unwrap_or(*self),
+        };
+        (1..bits)
+            .fold(Self::one(), |acc, _| acc.square().modmul(self))
+            .square()
+    }
+
+    /// Accumulate the unreduced products and reduce only once at the end. Will panic if the
+    /// operands are bound to different moduli.
+    fn dot(lhs: &[Self], rhs: &[Self]) -> Self {
+        if lhs.len() != rhs.len() {
+            panic!("operand lengths do not match");
+        }
+        let Some(field) = lhs.iter().chain(rhs.iter()).find_map(|elem| elem.field) else {
+            // Without a modulus the products cannot be reduced
+            return lhs
+                .iter()
+                .zip(rhs.iter())
+                .fold(Self::zero(), |acc, (a, b)| acc.modadd(&a.modmul(b)));
+        };
+        let sum = lhs
+            .iter()
+            .zip(rhs.iter())
+            .fold(WideF2x::<L>::ZERO, |acc, (a, b)| {
+                let (a, b) = (a.bind(&Some(field)), b.bind(&Some(field)));
+                acc.add(&a.poly.widening_mul(&b.poly))
+            });
+        Self {
+            poly: field.reduce(&sum),
+            field: Some(field),
+        }
+    }
+
+    fn bytes() -> usize {
+        F2x::<L>::BYTES
+    }
+
+    fn write_be_bytes(&self, dst: &mut [u8]) {
+        self.poly.write_to_be_bytes(dst);
+    }
+
+    /// The output is unbound; it will be reduced once it is bound to a modulus
+    fn from_be_bytes(src: &[u8]) -> Self {
+        Self::unbound(F2x::<L>::read_from_be_bytes(src))
+    }
+
+    /// The output is unbound, like `from_be_bytes`
+    fn try_from_u64(val: u64) -> Option<Self> {
+        if F2x::<L>::BITS < 64 && val >> F2x::<L>::BITS != 0 {
+            return None;
         }
-    };
+        Some(Self::unbound(F2x::<L>::from_u128(val as u128)))
+    }
 }
 
-galois_field!(
-    GF2p128,
-    8,
-    // x^128 + x^77 + x^35 + x^11 + 1
-    WideF2x::from_f2x(
-        F2x::<8>::ONE,
-        F2x::<8>::from_limbs([0x0000, 0x0000, 0x0000, 0x2000, 0x0000, 0x0008, 0x0000, 0x0801,]),
-    )
-);
-
-galois_field!(
-    GF2p192,
-    12,
-    // x^192 + x^142 + x^103 + x^17 + 1
-    WideF2x::from_f2x(
-        F2x::<12>::ONE,
-        F2x::<12>::from_limbs([
-            0x0000, 0x0000, 0x0000, 0x4000, 0x0000, 0x0080, 0x0000, 0x0000, 0x0000, 0x0000, 0x0002,
-            0x0001,
-        ]),
-    )
-);
-
-galois_field!(
-    GF2p256,
-    16,
-    // x^256 + x^241 + x^178 + x^121 + 1
-    WideF2x::from_f2x(
-        F2x::<16>::ONE,
-        F2x::<16>::from_limbs([
-            0x0002, 0x0000, 0x0000, 0x0000, 0x0004, 0x0000, 0x0000, 0x0000, 0x0200, 0x0000, 0x0000,
-            0x0000, 0x0000, 0x0000, 0x0000, 0x0001,
-        ]),
-    )
-);
-
 /// Toy implementation of the prime field F_3329, mostly for testing purposes since proper secret
 /// sharing requires cryptographically large prime numbers
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "debug-secrets", derive(Debug))]
+#[derive(PartialEq, Eq, Copy, Clone, Default)]
 pub struct F3329 {
     val: u64,
 }
 
+#[cfg(not(feature = "debug-secrets"))]
+impl core::fmt::Debug for F3329 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("F3329")
+            .field("val", &format_args!("REDACTED"))
+            .finish()
+    }
+}
+
 impl F3329 {
     const MODULUS: u64 = 3329;
 }
@@ -166,6 +1484,12 @@ impl From<u64> for F3329 {
     }
 }
 
+impl Distribution<F3329> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F3329 {
+        F3329::from(rng.gen::<u64>())
+    }
+}
+
 impl FieldArithmetic for F3329 {
     fn zero() -> Self {
         Self::from(0)
@@ -199,18 +1523,16 @@ impl FieldArithmetic for F3329 {
                 return Some(Self::from(inv));
             }
         }
-        return None;
+        None
     }
 
-    fn random() -> Self {
-        let mut rng = rand::thread_rng();
-        let val: u64 = rng.gen();
-        Self::from(val)
+    /// Fermat's little theorem: self^(3329 - 2)
+    fn inv_ct(&self) -> Self {
+        self.modexp_ct(Self::MODULUS - 2)
     }
 
-    fn random_with_rng(rng: &mut impl Rng) -> Self {
-        let val: u64 = rng.gen();
-        Self::from(val)
+    fn random(rng: &mut impl CryptoRngCore) -> Self {
+        rng.gen()
     }
 
     fn bytes() -> usize {
@@ -226,76 +1548,61 @@ impl FieldArithmetic for F3329 {
     fn from_be_bytes(src: &[u8]) -> Self {
         todo!();
     }
+
+    fn try_from_u64(val: u64) -> Option<Self> {
+        (val < Self::MODULUS).then(|| Self::from(val))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::thread_rng;
 
     const NTESTS: usize = 10;
 
+    /// Build a polynomial from 16-bit chunks in big-endian order regardless of the limb width
+    #[cfg(feature = "binary-curves")]
+    fn from_u16_limbs<const L: usize>(chunks: &[u16]) -> F2x<L> {
+        chunks.iter().fold(F2x::ZERO, |acc, chunk| {
+            acc.shl(16).xor(&F2x::from_u128(*chunk as u128))
+        })
+    }
+
     #[test]
+    #[cfg(feature = "gf128")]
     fn test_gf2_128_mul() {
-        let lhs = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0x1254, 0x4198, 0x8DA7, 0x29BD, 0xECF1, 0x64DE, 0xFBA7, 0xB692,
-        ]));
-        let rhs = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0x7D89, 0xD76A, 0x644E, 0x3A1C, 0x047C, 0xB60A, 0x1B98, 0x30F0,
-        ]));
-        let rem = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0x38B8, 0xB93F, 0xE30A, 0xE55E, 0xFC24, 0x2F4D, 0x5A16, 0xBD14,
-        ]));
+        let lhs = GF2p128::from_poly(F2x::from_u128(0x1254_4198_8DA7_29BD_ECF1_64DE_FBA7_B692));
+        let rhs = GF2p128::from_poly(F2x::from_u128(0x7D89_D76A_644E_3A1C_047C_B60A_1B98_30F0));
+        let rem = GF2p128::from_poly(F2x::from_u128(0x38B8_B93F_E30A_E55E_FC24_2F4D_5A16_BD14));
         assert_eq!(lhs.modmul(&rhs), rem);
 
-        let lhs = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0x5E17, 0xA183, 0x0FB7, 0xC7A9, 0xD3D3, 0xD1A4, 0x8962, 0xC3F5,
-        ]));
-        let rhs = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0x6ECC, 0x895B, 0x76CC, 0x855E, 0x9C14, 0xEF6F, 0x587A, 0x4A04,
-        ]));
-        let rem = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0xC42C, 0xBD01, 0xE0B0, 0x693D, 0x5E49, 0x6D28, 0xA69F, 0x701A,
-        ]));
+        let lhs = GF2p128::from_poly(F2x::from_u128(0x5E17_A183_0FB7_C7A9_D3D3_D1A4_8962_C3F5));
+        let rhs = GF2p128::from_poly(F2x::from_u128(0x6ECC_895B_76CC_855E_9C14_EF6F_587A_4A04));
+        let rem = GF2p128::from_poly(F2x::from_u128(0xC42C_BD01_E0B0_693D_5E49_6D28_A69F_701A));
         assert_eq!(lhs.modmul(&rhs), rem);
 
-        let lhs = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0x6C70, 0x49B5, 0x97A4, 0x65D1, 0x2370, 0x8DBE, 0x127F, 0x5EFB,
-        ]));
-        let rhs = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0x3387, 0xF3D0, 0xBD53, 0xADF3, 0x2994, 0x3B7A, 0xB2A8, 0x974A,
-        ]));
-        let rem = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0x4402, 0xF342, 0x347A, 0xA630, 0x0B5D, 0x31BB, 0xBED8, 0x76A2,
-        ]));
+        let lhs = GF2p128::from_poly(F2x::from_u128(0x6C70_49B5_97A4_65D1_2370_8DBE_127F_5EFB));
+        let rhs = GF2p128::from_poly(F2x::from_u128(0x3387_F3D0_BD53_ADF3_2994_3B7A_B2A8_974A));
+        let rem = GF2p128::from_poly(F2x::from_u128(0x4402_F342_347A_A630_0B5D_31BB_BED8_76A2));
         assert_eq!(lhs.modmul(&rhs), rem);
 
-        let lhs = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0x98AA, 0xD35C, 0x02F5, 0x612C, 0x67A1, 0x9B5C, 0xF1FE, 0x98C7,
-        ]));
-        let rhs = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0x4F13, 0x9428, 0x6D75, 0x61C6, 0x2CE7, 0xA102, 0xB546, 0xC183,
-        ]));
-        let rem = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0x09D9, 0x9D26, 0x5665, 0x2DAE, 0x2A22, 0x9928, 0xC29C, 0xD153,
-        ]));
+        let lhs = GF2p128::from_poly(F2x::from_u128(0x98AA_D35C_02F5_612C_67A1_9B5C_F1FE_98C7));
+        let rhs = GF2p128::from_poly(F2x::from_u128(0x4F13_9428_6D75_61C6_2CE7_A102_B546_C183));
+        let rem = GF2p128::from_poly(F2x::from_u128(0x09D9_9D26_5665_2DAE_2A22_9928_C29C_D153));
         assert_eq!(lhs.modmul(&rhs), rem);
 
-        let lhs = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0x1EA8, 0xE4CA, 0x33E9, 0x7C78, 0xD1E4, 0x903F, 0x6F70, 0x20F8,
-        ]));
-        let rhs = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0xEA22, 0x6D4C, 0xC5D4, 0x0C82, 0xF584, 0x3185, 0x36F4, 0x12B2,
-        ]));
-        let rem = GF2p128::from_poly(F2x::<8>::from_limbs([
-            0x8AF2, 0x5D11, 0xB56A, 0x68C0, 0xC3DD, 0xD9A1, 0xAC6E, 0x930B,
-        ]));
+        let lhs = GF2p128::from_poly(F2x::from_u128(0x1EA8_E4CA_33E9_7C78_D1E4_903F_6F70_20F8));
+        let rhs = GF2p128::from_poly(F2x::from_u128(0xEA22_6D4C_C5D4_0C82_F584_3185_36F4_12B2));
+        let rem = GF2p128::from_poly(F2x::from_u128(0x8AF2_5D11_B56A_68C0_C3DD_D9A1_AC6E_930B));
         assert_eq!(lhs.modmul(&rhs), rem);
     }
 
     #[test]
+    #[cfg(feature = "gf128")]
     fn random_gf2_128_inv() {
         for _ in 0..NTESTS {
-            let elem = GF2p128::random();
+            let elem = GF2p128::random(&mut thread_rng());
             if elem.poly.is_zero() {
                 assert!(elem.modinv().is_none());
             } else {
@@ -306,9 +1613,10 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "gf192")]
     fn random_gf2p192_inv() {
         for _ in 0..NTESTS {
-            let elem = GF2p192::random();
+            let elem = GF2p192::random(&mut thread_rng());
             if elem.poly.is_zero() {
                 assert!(elem.modinv().is_none());
             } else {
@@ -319,9 +1627,10 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "gf256")]
     fn random_gf2p256_inv() {
         for _ in 0..NTESTS {
-            let elem = GF2p256::random();
+            let elem = GF2p256::random(&mut thread_rng());
             if elem.poly.is_zero() {
                 assert!(elem.modinv().is_none());
             } else {
@@ -332,13 +1641,970 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "gf256")]
     fn random_gf2p256_serde() {
         for _ in 0..NTESTS {
-            let lhs = GF2p256::random();
+            let lhs = GF2p256::random(&mut thread_rng());
             let mut buf = [0u8; GF2p256::BYTES];
             lhs.write_be_bytes(&mut buf);
             let rhs = GF2p256::from_be_bytes(&buf);
             assert_eq!(lhs, rhs);
         }
     }
+    #[test]
+    #[cfg(feature = "binary-curves")]
+    fn test_gf2p233_mul() {
+        let lhs = GF2p233::from_poly(from_u16_limbs(&[
+            0x01B3, 0xCCD2, 0x2945, 0x3B16, 0x2438, 0x8671, 0xD624, 0x2CA2, 0x0EF1, 0xA0D9, 0x97E9,
+            0xCD6E, 0x5C0B, 0xAEB6, 0xCE87,
+        ]));
+        let rhs = GF2p233::from_poly(from_u16_limbs(&[
+            0x0077, 0x44C7, 0x63A6, 0x1A31, 0x46CB, 0xD542, 0x255B, 0x1550, 0x0923, 0x3450, 0xFB4D,
+            0x8D00, 0x0285, 0x7BEE, 0xDEBF,
+        ]));
+        let rem = GF2p233::from_poly(from_u16_limbs(&[
+            0x01EF, 0x3EB6, 0xEDA8, 0x0A9F, 0x6D45, 0x5532, 0x870A, 0x0656, 0x597B, 0xBB6D, 0x4720,
+            0xED7D, 0xC726, 0x4CE8, 0x43EC,
+        ]));
+        assert_eq!(lhs.modmul(&rhs), rem);
+    }
+
+    #[test]
+    #[cfg(feature = "binary-curves")]
+    fn random_nist_binary_fields_inv() {
+        macro_rules! check_inv {
+            ($field:ty) => {
+                for _ in 0..NTESTS {
+                    let elem = <$field>::random(&mut thread_rng());
+                    assert!(elem.poly.degree() < Degree::NonNegative(<$field>::BITS));
+                    if elem.is_zero() {
+                        assert!(elem.modinv().is_none());
+                    } else {
+                        let inv = elem.modinv().expect("Unexpected inversion failure");
+                        assert_eq!(elem.modmul(&inv), <$field>::ONE);
+                    }
+                }
+            };
+        }
+        check_inv!(GF2p233);
+        check_inv!(GF2p283);
+        check_inv!(GF2p409);
+        check_inv!(GF2p571);
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf8", feature = "gf16", feature = "binary-curves"))]
+    fn serialized_width_ignores_limbs() {
+        assert_eq!(GF2p8::BYTES, 1);
+        assert_eq!(GF2p16::BYTES, 2);
+        assert_eq!(GF2p233::BYTES, 30);
+        assert_eq!(GF2p571::BYTES, 72);
+        let mut buf = [0u8; GF2p233::BYTES];
+        GF2p233::from_u64(0x0102).write_be_bytes(&mut buf);
+        assert!(buf[..28].iter().all(|byte| *byte == 0));
+        assert_eq!(buf[28..], [0x01, 0x02]);
+        assert_eq!(GF2p233::from_be_bytes(&buf), GF2p233::from_u64(0x0102));
+    }
+
+    #[test]
+    #[cfg(feature = "binary-curves")]
+    fn gf2p233_from_be_bytes_reduces() {
+        // All ones is x^239 + ... + 1, which is not a canonical element of GF(2^233)
+        let buf = [0xFFu8; GF2p233::BYTES];
+        let elem = GF2p233::from_be_bytes(&buf);
+        assert!(elem.poly.degree() < Degree::NonNegative(GF2p233::BITS));
+        let mut roundtrip = [0u8; GF2p233::BYTES];
+        elem.write_be_bytes(&mut roundtrip);
+        assert_eq!(GF2p233::from_be_bytes(&roundtrip), elem);
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "gf8",
+        feature = "gf16",
+        feature = "gf128",
+        feature = "gf192",
+        feature = "gf256",
+        feature = "binary-curves"
+    ))]
+    fn field_moduli_are_irreducible() {
+        assert!(GF2p8::MODULUS.is_irreducible());
+        assert!(GF2p16::MODULUS.is_irreducible());
+        assert!(GF2p128::MODULUS.is_irreducible());
+        assert!(GF2p192::MODULUS.is_irreducible());
+        assert!(GF2p256::MODULUS.is_irreducible());
+        assert!(GF2p233::MODULUS.is_irreducible());
+        assert!(GF2p283::MODULUS.is_irreducible());
+        assert!(GF2p409::MODULUS.is_irreducible());
+        assert!(GF2p571::MODULUS.is_irreducible());
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf128", feature = "gf192", feature = "binary-curves"))]
+    fn exponent_list_moduli() {
+        use crate::f2x::Word;
+
+        assert_eq!(GF2p128::LIMBS * Word::BITS as usize, 128);
+        assert_eq!(GF2p233::LIMBS, 233_usize.div_ceil(Word::BITS as usize));
+        assert_eq!(GF2p571::LIMBS, 571_usize.div_ceil(Word::BITS as usize));
+        assert_eq!(
+            GF2p192::MODULUS,
+            WideF2x::from_f2x(
+                F2x::ONE,
+                from_u16_limbs(&[
+                    0x0000, 0x0000, 0x0000, 0x4000, 0x0000, 0x0080, 0x0000, 0x0000, 0x0000, 0x0000,
+                    0x0002, 0x0001,
+                ]),
+            )
+        );
+        assert_eq!(
+            GF2p283::MODULUS,
+            WideF2x::from_f2x(
+                F2x::ZERO,
+                from_u16_limbs(&[
+                    0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+                    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x10A1,
+                ]),
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf128", feature = "binary-curves"))]
+    fn random_dyn_gf2_matches_gf2p128() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..NTESTS {
+            let (lhs, rhs) = (
+                GF2p128::random(&mut thread_rng()),
+                GF2p128::random(&mut thread_rng()),
+            );
+            let dyn_lhs = DynGf2::new(lhs.poly, GF2p128::MODULUS);
+            let dyn_rhs = DynGf2::new(rhs.poly, GF2p128::MODULUS);
+            assert_eq!(*dyn_lhs.modmul(&dyn_rhs).poly(), lhs.modmul(&rhs).poly);
+            assert_eq!(*dyn_lhs.modadd(&dyn_rhs).poly(), lhs.modadd(&rhs).poly);
+            let inv = dyn_lhs.modinv().expect("Unexpected inversion failure");
+            assert!(inv.modmul(&dyn_lhs).is_one());
+
+            let elem = DynGf2::random_with_modulus(GF2p233::MODULUS, &mut rng);
+            assert!(elem.poly().degree() < Degree::NonNegative(233));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    fn dyn_gf2_unbound_elements() {
+        let elem = DynGf2::new(GF2p128::random(&mut thread_rng()).poly, GF2p128::MODULUS);
+        assert_eq!(DynGf2::one().modmul(&elem), elem);
+        assert_eq!(DynGf2::zero().modadd(&elem), elem);
+        assert_eq!(elem.modsub(&elem), DynGf2::zero());
+        assert_eq!(
+            DynGf2::<{ GF2p128::LIMBS }>::one().modinv(),
+            Some(DynGf2::one())
+        );
+        assert!(DynGf2::<{ GF2p128::LIMBS }>::zero().modinv().is_none());
+        assert_eq!(
+            DynGf2::one().modmul(&elem).modulus(),
+            Some(&GF2p128::MODULUS)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    #[should_panic]
+    fn dyn_gf2_mixed_moduli() {
+        let lhs = DynGf2::new(F2x::ONE, GF2p128::MODULUS);
+        let rhs = DynGf2::new(F2x::ONE, WideF2x::from_exponents(&[127, 1, 0]));
+        lhs.modmul(&rhs);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "gf128", feature = "binary-curves"))]
+    fn random_normal_basis_squaring() {
+        let basis = GF2p233::normal_basis();
+        for _ in 0..NTESTS {
+            let elem = GF2p233::random(&mut thread_rng());
+            let coords = elem.to_normal();
+            assert_eq!(GF2p233::from_normal(&coords), elem);
+
+            let square = elem.modmul(&elem);
+            assert_eq!(square.to_normal(), basis.rotate(&coords, 1));
+            let fourth = square.modmul(&square);
+            assert_eq!(GF2p233::from_normal(&basis.rotate(&coords, 2)), fourth);
+            assert_eq!(basis.rotate(&coords, GF2p233::BITS), coords);
+        }
+
+        let elem = GF2p128::random(&mut thread_rng());
+        assert_eq!(GF2p128::from_normal(&elem.to_normal()), elem);
+        assert_eq!(
+            GF2p128::normal_basis().rotate(&elem.to_normal(), 1),
+            elem.modmul(&elem).to_normal()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "gf256")]
+    fn random_frobenius() {
+        for _ in 0..NTESTS {
+            let elem = GF2p256::random(&mut thread_rng());
+            let mut power = elem;
+            for k in 0..5 {
+                assert_eq!(elem.frobenius(k), power);
+                power = power.modmul(&power);
+            }
+            assert_eq!(elem.frobenius(GF2p256::BITS), elem);
+            assert_eq!(elem.frobenius(3).frobenius(GF2p256::BITS - 3), elem);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf128", feature = "binary-curves"))]
+    fn random_trace_and_half_trace() {
+        for _ in 0..NTESTS {
+            let elem = GF2p233::random(&mut thread_rng());
+            let mut acc = GF2p233::ZERO;
+            let mut power = elem;
+            for _ in 0..GF2p233::BITS {
+                acc = acc.modadd(&power);
+                power = power.modmul(&power);
+            }
+            assert_eq!(acc.is_one(), elem.trace());
+            assert!(acc.is_zero() || acc.is_one());
+
+            let half_trace = elem.half_trace().unwrap();
+            let mut expected = elem;
+            if elem.trace() {
+                expected = expected.modadd(&GF2p233::ONE);
+            }
+            assert_eq!(half_trace.modmul(&half_trace).modadd(&half_trace), expected);
+        }
+        assert!(GF2p128::random(&mut thread_rng()).half_trace().is_none());
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "gf128", feature = "binary-curves"))]
+    fn random_solve_quadratic() {
+        fn check<F: FieldArithmetic>(a: &F, b: &F, roots: Option<(F, F)>) -> bool {
+            match roots {
+                Some((r0, r1)) => [r0, r1]
+                    .iter()
+                    .all(|r| r.modmul(r).modadd(&a.modmul(r)).modadd(b).is_zero()),
+                None => false,
+            }
+        }
+        let mut solved = 0;
+        for _ in 0..NTESTS {
+            let (a, b) = (
+                GF2p128::random(&mut thread_rng()),
+                GF2p128::random(&mut thread_rng()),
+            );
+            if let Some(roots) = GF2p128::solve_quadratic(&a, &b) {
+                assert!(check(&a, &b, Some(roots)));
+                assert_ne!(roots.0, roots.1);
+                solved += 1;
+            }
+            let (a, b) = (
+                GF2p233::random(&mut thread_rng()),
+                GF2p233::random(&mut thread_rng()),
+            );
+            if let Some(roots) = GF2p233::solve_quadratic(&a, &b) {
+                assert!(check(&a, &b, Some(roots)));
+                solved += 1;
+            }
+            // (z - r0)(z - r1) always has roots
+            let (r0, r1) = (
+                GF2p233::random(&mut thread_rng()),
+                GF2p233::random(&mut thread_rng()),
+            );
+            let roots = GF2p233::solve_quadratic(&r0.modadd(&r1), &r0.modmul(&r1));
+            assert!(check(&r0.modadd(&r1), &r0.modmul(&r1), roots));
+            let roots = roots.unwrap();
+            assert!(roots == (r0, r1) || roots == (r1, r0));
+        }
+        // Roughly half of random equations are solvable
+        assert!(solved > 0);
+
+        let b = GF2p128::random(&mut thread_rng());
+        let (root, _) = GF2p128::solve_quadratic(&GF2p128::ZERO, &b).unwrap();
+        assert_eq!(root.modmul(&root), b);
+    }
+
+    // x^20 + x^3 + 1 is a primitive trinomial
+    mod small_field {
+        galois_field!(GF2p20, 20, [3, 0]);
+    }
+    #[cfg(feature = "gf16")]
+    use small_field::GF2p20;
+
+    #[test]
+    #[cfg(all(feature = "gf16", feature = "gf128"))]
+    fn random_modexp() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..NTESTS {
+            let base = GF2p128::random(&mut thread_rng());
+            let exp = rng.gen::<u64>() >> rng.gen_range(0..64);
+            let expected = base
+                .modexp(exp / 2)
+                .modmul(&base.modexp(exp / 2))
+                .modmul(&base.modexp(exp % 2));
+            assert_eq!(base.modexp(exp), expected);
+            assert_eq!(base.modexp_ct(exp), expected);
+        }
+        let base = GF2p128::random(&mut thread_rng());
+        assert!(base.modexp(0).is_one());
+        assert_eq!(base.modexp(1), base);
+        assert_eq!(base.modexp(2), base.modmul(&base));
+        assert_eq!(base.modexp(4), base.frobenius(2));
+        // Fermat's little theorem in GF(2^16)
+        let base = GF2p16::random(&mut thread_rng());
+        if !base.is_zero() {
+            assert!(base.modexp((1 << 16) - 1).is_one());
+            assert_eq!(base.modexp((1 << 16) - 2), base.modinv().unwrap());
+        }
+    }
+
+    mod itoh_tsujii_field {
+        galois_field!(
+            GF2p233ItohTsujii,
+            233,
+            [74, 0],
+            crate::galoisfields::Inversion::ItohTsujii
+        );
+    }
+    #[cfg(all(feature = "gf16", feature = "gf128", feature = "binary-curves"))]
+    use itoh_tsujii_field::GF2p233ItohTsujii;
+
+    #[test]
+    #[cfg(all(feature = "gf16", feature = "gf256", feature = "binary-curves"))]
+    fn random_inv_ct() {
+        for _ in 0..NTESTS {
+            let elem = GF2p256::random(&mut thread_rng());
+            assert_eq!(Some(elem.inv_ct()), elem.modinv());
+            let elem = GF2p233::random(&mut thread_rng());
+            assert_eq!(Some(elem.inv_ct()), elem.modinv());
+        }
+        assert_eq!(GF2p16::ONE.inv_ct(), GF2p16::ONE);
+        assert_eq!(GF2p16::ZERO.inv_ct(), GF2p16::ZERO);
+    }
+
+    /// `FieldArithmetic::inv_ct` agrees with `modinv` in every field that overrides it
+    #[test]
+    fn random_generic_inv_ct() {
+        fn check<F: FieldArithmetic>(elem: F) {
+            assert!(elem.inv_ct() == elem.modinv().unwrap_or(F::zero()));
+        }
+        let mut rng = thread_rng();
+        for _ in 0..NTESTS {
+            check(F3329::random(&mut rng));
+            check(crate::primefields::Fp25519::random(&mut rng));
+        }
+        check(F3329::zero());
+        #[cfg(feature = "gf128")]
+        {
+            check(crate::tower::GF2p16p8::random(&mut rng));
+            check(DynGf2::random_with_modulus(GF2p128::MODULUS, &mut rng));
+            check(DynGf2::<{ GF2p128::LIMBS }>::one());
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf16", feature = "gf128", feature = "binary-curves"))]
+    fn random_itoh_tsujii_inv() {
+        for _ in 0..NTESTS {
+            let elem = GF2p128::random(&mut thread_rng());
+            assert_eq!(elem.itoh_tsujii_inv(), elem.modinv());
+            let elem = GF2p283::random(&mut thread_rng());
+            assert_eq!(elem.itoh_tsujii_inv(), elem.modinv());
+            let elem = GF2p233ItohTsujii::random(&mut thread_rng());
+            let inv = elem.modinv().unwrap();
+            assert!(elem.modmul(&inv).is_one());
+        }
+        assert_eq!(GF2p16::ONE.itoh_tsujii_inv(), Some(GF2p16::ONE));
+        assert!(GF2p16::ZERO.itoh_tsujii_inv().is_none());
+        assert!(GF2p233ItohTsujii::ZERO.modinv().is_none());
+        assert_eq!(GF2p233ItohTsujii::INVERSION, Inversion::ItohTsujii);
+        assert_eq!(GF2p233::INVERSION, Inversion::Euclid);
+    }
+
+    // The GF2p128 modulus given as limbs, which is reduced with Barrett reduction
+    #[cfg(feature = "gf128")]
+    mod dense_field {
+        galois_field!(
+            GF2p128Dense,
+            super::GF2p128::LIMBS,
+            128,
+            super::GF2p128::MODULUS
+        );
+    }
+    #[cfg(feature = "gf128")]
+    use dense_field::GF2p128Dense;
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    fn sparse_reduction_matches_barrett_reduction() {
+        for _ in 0..NTESTS {
+            let (lhs, rhs) = (
+                GF2p128::random(&mut thread_rng()),
+                GF2p128::random(&mut thread_rng()),
+            );
+            let dense =
+                GF2p128Dense::from_poly(lhs.poly).modmul(&GF2p128Dense::from_poly(rhs.poly));
+            assert_eq!(lhs.modmul(&rhs).poly, dense.poly);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf128", feature = "binary-curves"))]
+    fn random_assign_ops() {
+        macro_rules! check_assign {
+            ($field:ty) => {
+                for _ in 0..NTESTS {
+                    let (lhs, rhs) = (
+                        <$field>::random(&mut thread_rng()),
+                        <$field>::random(&mut thread_rng()),
+                    );
+                    let mut elem = lhs;
+                    elem.modadd_assign(&rhs);
+                    assert_eq!(elem, lhs.modadd(&rhs));
+                    elem.modsub_assign(&rhs);
+                    assert_eq!(elem, lhs);
+                    elem.modmul_assign(&rhs);
+                    assert_eq!(elem, lhs.modmul(&rhs));
+                    elem.sqr_assign();
+                    assert_eq!(elem, lhs.modmul(&rhs).square());
+                }
+            };
+        }
+        check_assign!(GF2p128);
+        check_assign!(GF2p233);
+        check_assign!(F3329);
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf16", feature = "gf128", feature = "binary-curves"))]
+    fn const_field_arithmetic() {
+        const X: GF2p128 = GF2p128::from_poly(F2x::from_exponents(&[1]));
+        const X_128: GF2p128 = X.modexp_const(128);
+        assert_eq!(
+            X_128,
+            GF2p128::from_poly(F2x::from_exponents(&[77, 35, 11, 0]))
+        );
+        const GENERATOR_POWERS: [GF2p16; 4] = {
+            let generator = GF2p16::from_poly(F2x::from_exponents(&[1, 0]));
+            let mut powers = [GF2p16::ONE; 4];
+            let mut i = 1;
+            while i < 4 {
+                powers[i] = powers[i - 1].modmul_const(&generator);
+                i += 1;
+            }
+            powers
+        };
+        let generator = GF2p16::from_poly(F2x::from_exponents(&[1, 0]));
+        for (i, power) in GENERATOR_POWERS.iter().enumerate() {
+            assert_eq!(*power, generator.modexp(i as u64));
+        }
+
+        for _ in 0..NTESTS {
+            let (lhs, rhs) = (
+                GF2p233::random(&mut thread_rng()),
+                GF2p233::random(&mut thread_rng()),
+            );
+            assert_eq!(lhs.modmul_const(&rhs), lhs.modmul(&rhs));
+            assert_eq!(lhs.modadd_const(&rhs), lhs.modadd(&rhs));
+            assert_eq!(lhs.modexp_const(5), lhs.modexp(5));
+            let (lhs, rhs) = (
+                GF2p128Dense::random(&mut thread_rng()),
+                GF2p128Dense::random(&mut thread_rng()),
+            );
+            assert_eq!(lhs.modmul_const(&rhs), lhs.modmul(&rhs));
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf256", feature = "binary-curves"))]
+    fn random_ct_eq() {
+        for _ in 0..NTESTS {
+            let (lhs, rhs) = (
+                GF2p256::random(&mut thread_rng()),
+                GF2p256::random(&mut thread_rng()),
+            );
+            assert_eq!(lhs.ct_eq(&rhs), lhs == rhs);
+            assert!(lhs.ct_eq(&lhs));
+            assert_eq!(lhs.ct_is_zero(), lhs.is_zero());
+        }
+        assert!(GF2p233::ZERO.ct_is_zero());
+        assert!(GF2p233::ONE.ct_is_one());
+        assert!(!GF2p233::ONE.ct_is_zero());
+        assert!(!GF2p233::ZERO.ct_is_one());
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf128", feature = "binary-curves"))]
+    #[allow(clippy::op_ref)]
+    fn operators_match_field_arithmetic() {
+        use crate::tower::GF2p16p8;
+        use core::ops::{Add, Mul};
+
+        /// Generic code that only knows about the operators
+        fn mul_add<T: Add<Output = T> + Mul<Output = T>>(a: T, b: T, c: T) -> T {
+            a * b + c
+        }
+
+        let (a, b, c) = (
+            GF2p233::random(&mut thread_rng()),
+            GF2p233::random(&mut thread_rng()),
+            GF2p233::random(&mut thread_rng()),
+        );
+        assert_eq!(mul_add(a, b, c), a.modmul(&b).modadd(&c));
+        assert_eq!(&a * &b - &c, a.modmul(&b).modsub(&c));
+        let mut acc = a;
+        acc *= b;
+        acc += &c;
+        acc -= c;
+        assert_eq!(acc, a * &b);
+        // Negation is the identity in characteristic 2
+        assert_eq!(-a, a);
+        assert_eq!(a + -&a, GF2p233::ZERO);
+
+        let (a, b) = (
+            GF2p16p8::random(&mut thread_rng()),
+            GF2p16p8::random(&mut thread_rng()),
+        );
+        assert_eq!(mul_add(a, b, a), a.modmul(&b).modadd(&a));
+    }
+
+    // x^8 + 1 = (x + 1)^8 passes the compile-time checks but is not irreducible
+    mod reducible_field {
+        galois_field!(GF2p8Reducible, 8, [0]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "not irreducible")]
+    fn reducible_modulus_is_rejected() {
+        let x = reducible_field::GF2p8Reducible::from_u64(2);
+        x.modmul(&x);
+    }
+
+    /// `F2x::modmul` with a caller-supplied modulus agrees with the fields' own reduction
+    #[test]
+    #[cfg(all(
+        feature = "gf8",
+        feature = "gf16",
+        feature = "gf128",
+        feature = "gf192",
+        feature = "gf256",
+        feature = "binary-curves"
+    ))]
+    fn f2x_modmul_matches_fields() {
+        macro_rules! check_modmul {
+            ($field:ty) => {
+                for _ in 0..NTESTS {
+                    let lhs = <$field>::random(&mut thread_rng());
+                    let rhs = <$field>::random(&mut thread_rng());
+                    assert_eq!(
+                        lhs.poly.modmul(&rhs.poly, &<$field>::MODULUS),
+                        lhs.modmul(&rhs).poly
+                    );
+                }
+            };
+        }
+        check_modmul!(GF2p8);
+        check_modmul!(GF2p16);
+        check_modmul!(GF2p128);
+        check_modmul!(GF2p192);
+        check_modmul!(GF2p256);
+        check_modmul!(GF2p233);
+        check_modmul!(GF2p283);
+        check_modmul!(GF2p409);
+        check_modmul!(GF2p571);
+        check_modmul!(crate::gcm::GF2p128Gcm);
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf8", feature = "gf128", feature = "gf256"))]
+    fn integer_constructors() {
+        use crate::primefields::Fp25519;
+
+        const X: GF2p256 = GF2p256::from_u16(2);
+        assert_eq!(X, GF2p256::from_poly(F2x::from_u128(2)));
+        assert_eq!(GF2p256::from_u64(1), GF2p256::ONE);
+        assert_eq!(GF2p8::from_u64(0xFF), GF2p8::try_from_u64(0xFF).unwrap());
+        assert_eq!(GF2p8::try_from_u64(0x100), None);
+        assert_eq!(GF2p256::default(), GF2p256::ZERO);
+
+        assert_eq!(
+            Fp25519::from_u16(2).modadd(&Fp25519::ONE),
+            Fp25519::from_u64(3)
+        );
+        assert_eq!(
+            Fp25519::from_u64(u64::MAX),
+            Fp25519::try_from_u64(u64::MAX).unwrap()
+        );
+        assert!(Fp25519::default().is_zero());
+        assert!(F3329::default().is_zero());
+        assert_eq!(F3329::try_from_u64(3329), None);
+        // DynGf2 encodings are longer than 8 bytes, so it cannot use the default
+        assert_eq!(
+            DynGf2::<4>::try_from_u64(u64::MAX),
+            Some(DynGf2::unbound(F2x::from_u128(u64::MAX as u128)))
+        );
+        assert!(crate::tower::GF2p16p8::default().is_zero());
+    }
+
+    #[test]
+    #[cfg(feature = "gf256")]
+    fn elements_as_map_keys() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let elems = (1..=NTESTS as u64)
+            .map(GF2p256::from_u64)
+            .collect::<Vec<GF2p256>>();
+        let sorted = elems
+            .iter()
+            .rev()
+            .map(|elem| (*elem, ()))
+            .collect::<BTreeMap<GF2p256, ()>>();
+        assert!(sorted.keys().eq(elems.iter()));
+        let mut counts = HashMap::new();
+        elems
+            .iter()
+            .chain(elems.iter())
+            .for_each(|elem| *counts.entry(*elem).or_insert(0) += 1);
+        assert_eq!(counts.len(), NTESTS);
+        assert!(counts.values().all(|count| *count == 2));
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf128", feature = "gf256"))]
+    fn sum_and_product() {
+        use crate::tower::GF2p16p8;
+
+        let elems = (0..NTESTS)
+            .map(|_| GF2p256::random(&mut thread_rng()))
+            .collect::<Vec<GF2p256>>();
+        let (sum, prod) = elems
+            .iter()
+            .fold((GF2p256::ZERO, GF2p256::ONE), |(sum, prod), elem| {
+                (sum.modadd(elem), prod.modmul(elem))
+            });
+        assert_eq!(elems.iter().sum::<GF2p256>(), sum);
+        assert_eq!(elems.iter().product::<GF2p256>(), prod);
+        assert_eq!(
+            elems.into_iter().map(|elem| elem * elem).sum::<GF2p256>(),
+            sum * sum
+        );
+
+        assert_eq!(
+            core::iter::empty::<GF2p16p8>().sum::<GF2p16p8>(),
+            GF2p16p8::zero()
+        );
+        assert_eq!(
+            core::iter::empty::<GF2p16p8>().product::<GF2p16p8>(),
+            GF2p16p8::one()
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf128", feature = "num-traits"))]
+    fn num_traits() {
+        use crate::tower::GF2p16p8;
+        use num_traits::{One, Pow, Zero};
+
+        /// Generic code bounded on num-traits only
+        fn horner<T: Zero + One + Copy>(coeffs: &[T], at: T) -> T {
+            coeffs
+                .iter()
+                .rev()
+                .fold(T::zero(), |acc, coeff| acc * at + *coeff)
+        }
+
+        let at = GF2p128::random(&mut thread_rng());
+        assert_eq!(
+            horner(&[GF2p128::ONE; 3], at),
+            at.pow(2u32) + at + GF2p128::ONE
+        );
+        assert_eq!(at.pow(5u64), at.modexp(5));
+        assert!(Zero::is_zero(&<GF2p16p8 as Zero>::zero()));
+        assert!(One::is_one(&<GF2p16p8 as One>::one()));
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf128", feature = "binary-curves"))]
+    fn standard_distribution() {
+        use crate::primefields::Fp25519;
+        use crate::tower::GF2p16p8;
+        use rand::{distributions::Standard, rngs::StdRng, Rng, SeedableRng};
+
+        // Sampling through rand agrees with FieldArithmetic::random
+        let elem: GF2p128 = StdRng::seed_from_u64(0).gen();
+        assert_eq!(elem, GF2p128::random(&mut StdRng::seed_from_u64(0)));
+        let elem: Fp25519 = StdRng::seed_from_u64(0).gen();
+        assert_eq!(elem, Fp25519::random(&mut StdRng::seed_from_u64(0)));
+        let elem: GF2p16p8 = StdRng::seed_from_u64(0).gen();
+        assert_eq!(elem, GF2p16p8::random(&mut StdRng::seed_from_u64(0)));
+
+        let elems = thread_rng()
+            .sample_iter(Standard)
+            .take(NTESTS)
+            .collect::<Vec<GF2p233>>();
+        assert!(elems
+            .iter()
+            .all(|elem| elem.poly.shr(GF2p233::BITS).is_zero()));
+        assert!(elems.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf256", feature = "binary-curves", feature = "arbitrary"))]
+    fn arbitrary_elements() {
+        use crate::f2x::Word;
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes = [0xFF; 64];
+        let elem = GF2p233::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert!(elem.poly.shr(GF2p233::BITS).is_zero());
+        assert_eq!(elem.poly.degree(), Degree::NonNegative(GF2p233::BITS - 1));
+        let elem = GF2p256::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(elem.poly, F2x::from_limbs([Word::MAX; GF2p256::LIMBS]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf256", feature = "getrandom"))]
+    fn random_from_os() {
+        let elems = (0..NTESTS)
+            .map(|_| GF2p256::random_from_os())
+            .collect::<Vec<_>>();
+        assert!(elems.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    #[cfg(feature = "gf256")]
+    fn zeroize_elements() {
+        use zeroize::Zeroize;
+
+        let mut elem = GF2p256::random(&mut thread_rng());
+        elem.zeroize();
+        assert!(elem.is_zero());
+        let mut wide = GF2p256::random(&mut thread_rng())
+            .poly
+            .widening_mul(&GF2p256::random(&mut thread_rng()).poly);
+        wide.zeroize();
+        assert!(wide.is_zero());
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf256", feature = "subtle"))]
+    fn subtle_traits() {
+        use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+        for _ in 0..NTESTS {
+            let (lhs, rhs) = (
+                GF2p256::random(&mut thread_rng()),
+                GF2p256::random(&mut thread_rng()),
+            );
+            assert_eq!(bool::from(ConstantTimeEq::ct_eq(&lhs, &rhs)), lhs == rhs);
+            assert!(bool::from(ConstantTimeEq::ct_eq(&lhs, &lhs)));
+            assert_eq!(
+                GF2p256::conditional_select(&lhs, &rhs, Choice::from(0)),
+                lhs
+            );
+            assert_eq!(
+                GF2p256::conditional_select(&lhs, &rhs, Choice::from(1)),
+                rhs
+            );
+
+            let (mut lhs_swapped, mut rhs_swapped) = (lhs, rhs);
+            GF2p256::conditional_swap(&mut lhs_swapped, &mut rhs_swapped, Choice::from(1));
+            assert_eq!((lhs_swapped, rhs_swapped), (rhs, lhs));
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf128", feature = "binary-curves"))]
+    fn random_square() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..NTESTS {
+            let elem = GF2p233::random(&mut thread_rng());
+            assert_eq!(elem.square(), elem.modmul(&elem));
+            let mut square = elem;
+            square.sqr_assign();
+            assert_eq!(square, elem.frobenius(1));
+
+            let elem = DynGf2::random_with_modulus(GF2p128::MODULUS, &mut rng);
+            assert_eq!(elem.square(), elem.modmul(&elem));
+        }
+        assert_eq!(F3329::from(3328).square(), F3329::from(1));
+        assert_eq!(DynGf2::<8>::one().square(), DynGf2::one());
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "gf128", feature = "binary-curves"))]
+    fn random_batch_inv() {
+        let mut elems = (0..NTESTS)
+            .map(|_| GF2p128::random(&mut thread_rng()))
+            .collect::<Vec<_>>();
+        elems[NTESTS / 2] = GF2p128::ZERO;
+        let mut inverses = elems.clone();
+        batch_inv(&mut inverses);
+        for (elem, inv) in elems.iter().zip(inverses.iter()) {
+            assert_eq!(elem.modinv().unwrap_or(GF2p128::ZERO), *inv);
+        }
+
+        let mut empty: [GF2p128; 0] = [];
+        batch_inv(&mut empty);
+        let mut zeros = [GF2p233::ZERO; 3];
+        batch_inv(&mut zeros);
+        assert_eq!(zeros, [GF2p233::ZERO; 3]);
+        let mut elems = [F3329::from(2), F3329::from(3329 - 1)];
+        let mut scratch = [F3329::zero(); 3];
+        batch_inv_with_scratch(&mut elems, &mut scratch);
+        assert_eq!(elems, [F3329::from(1665), F3329::from(3329 - 1)]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf128", feature = "binary-curves"))]
+    fn random_dot() {
+        fn naive<F: FieldArithmetic>(lhs: &[F], rhs: &[F]) -> F {
+            lhs.iter()
+                .zip(rhs.iter())
+                .fold(F::zero(), |acc, (a, b)| acc.modadd(&a.modmul(b)))
+        }
+        let mut rng = rand::thread_rng();
+        let lhs = (0..NTESTS)
+            .map(|_| GF2p128::random(&mut thread_rng()))
+            .collect::<Vec<_>>();
+        let rhs = (0..NTESTS)
+            .map(|_| GF2p128::random(&mut thread_rng()))
+            .collect::<Vec<_>>();
+        assert_eq!(GF2p128::dot(&lhs, &rhs), naive(&lhs, &rhs));
+        let lhs = (0..NTESTS)
+            .map(|_| GF2p571::random(&mut thread_rng()))
+            .collect::<Vec<_>>();
+        let rhs = (0..NTESTS)
+            .map(|_| GF2p571::random(&mut thread_rng()))
+            .collect::<Vec<_>>();
+        assert_eq!(GF2p571::dot(&lhs, &rhs), naive(&lhs, &rhs));
+        assert!(GF2p128::dot(&[], &[]).is_zero());
+
+        let lhs = (0..NTESTS)
+            .map(|_| DynGf2::random_with_modulus(GF2p233::MODULUS, &mut rng))
+            .collect::<Vec<_>>();
+        let mut rhs = (0..NTESTS)
+            .map(|_| DynGf2::random_with_modulus(GF2p233::MODULUS, &mut rng))
+            .collect::<Vec<_>>();
+        // Unbound elements are bound to the common modulus
+        rhs[0] = DynGf2::random(&mut thread_rng());
+        assert_eq!(DynGf2::dot(&lhs, &rhs), naive(&lhs, &rhs));
+        assert_eq!(
+            DynGf2::dot(&[DynGf2::<8>::one()], &[DynGf2::one()]),
+            DynGf2::one()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    #[should_panic]
+    fn dot_length_mismatch() {
+        GF2p128::dot(&[GF2p128::ONE], &[]);
+    }
+
+    #[test]
+    fn small_field_prime_factors() {
+        assert_eq!(
+            prime_factors(65535).collect::<Vec<_>>(),
+            vec![3, 5, 17, 257]
+        );
+        assert_eq!(
+            prime_factors((1 << 20) - 1).collect::<Vec<_>>(),
+            vec![3, 5, 11, 31, 41]
+        );
+        assert_eq!(prime_factors(1).next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "gf16")]
+    fn small_field_primitive_element() {
+        let generator = GF2p16::primitive_element();
+        assert!(generator.is_primitive());
+        assert_eq!(generator.multiplicative_order(), Some(65535));
+        assert_eq!(GF2p16::ONE.multiplicative_order(), Some(1));
+        assert_eq!(GF2p16::ZERO.multiplicative_order(), None);
+        // GF(2^2)^* is a subgroup of order 3
+        let cube_root = generator.modexp(65535 / 3);
+        assert_eq!(cube_root.multiplicative_order(), Some(3));
+
+        let x = GF2p20::from_poly(F2x::from_exponents(&[1]));
+        assert_eq!(GF2p20::primitive_element(), x);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "gf16"))]
+    fn random_discrete_log() {
+        let mut rng = thread_rng();
+        let generator = GF2p20::primitive_element();
+        for _ in 0..NTESTS {
+            let exp = rng.gen_range(0..((1 << 20) - 1));
+            let elem = generator.modexp(exp);
+            assert_eq!(elem.discrete_log(&generator), Some(exp));
+        }
+        // Elements outside of the subgroup generated by the base have no logarithm
+        let generator = GF2p16::primitive_element();
+        let cube_root = generator.modexp(65535 / 3);
+        assert_eq!(generator.discrete_log(&cube_root), None);
+        assert_eq!(
+            cube_root.modmul(&cube_root).discrete_log(&cube_root),
+            Some(2)
+        );
+        assert_eq!(GF2p16::ZERO.discrete_log(&generator), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "gf128"))]
+    #[should_panic]
+    fn discrete_log_large_field() {
+        GF2p128::ONE.discrete_log(&GF2p128::ONE);
+    }
+
+    /// Exercise a binary field only through ff's `Field`, as generic zkcrypto code would
+    #[cfg(all(
+        feature = "ff",
+        any(feature = "gf8", feature = "gf128", feature = "binary-curves")
+    ))]
+    fn check_ff_field<F: ff::Field>() {
+        let mut rng = thread_rng();
+        for _ in 0..NTESTS {
+            let elem = F::random(&mut rng);
+            let other = F::random(&mut rng);
+            assert_eq!(elem.double(), F::ZERO);
+            assert_eq!(-elem, elem);
+            assert_eq!(elem.square(), elem * elem);
+            assert_eq!([elem, other].iter().sum::<F>(), elem + other);
+            assert_eq!([elem, other].iter().product::<F>(), elem * other);
+            if !bool::from(elem.is_zero()) {
+                assert_eq!(elem * elem.invert().unwrap(), F::ONE);
+            }
+            assert_eq!(elem.sqrt().unwrap().square(), elem);
+            if !bool::from(other.is_zero()) {
+                let (is_square, root) = F::sqrt_ratio(&elem, &other);
+                assert!(bool::from(is_square));
+                assert_eq!(root.square() * other, elem);
+            }
+        }
+        assert!(bool::from(F::ZERO.invert().is_none()));
+        assert_eq!(F::sqrt_ratio(&F::ONE, &F::ZERO).0.unwrap_u8(), 0);
+        assert_eq!(F::sqrt_ratio(&F::ZERO, &F::ZERO).0.unwrap_u8(), 1);
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "ff",
+        any(feature = "gf8", feature = "gf128", feature = "binary-curves")
+    ))]
+    fn ff_field() {
+        #[cfg(feature = "gf8")]
+        check_ff_field::<GF2p8>();
+        #[cfg(feature = "gf128")]
+        check_ff_field::<GF2p128>();
+        #[cfg(feature = "binary-curves")]
+        check_ff_field::<GF2p233>();
+    }
 }
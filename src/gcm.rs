@@ -0,0 +1,133 @@
+//! The field GF(2^128) under the GCM modulus x^128 + x^7 + x^2 + x + 1
+//!
+//! `GF2p128Gcm` and `GF2p128` are two representations of the same field. The isomorphism maps the
+//! indeterminate of the GCM field to GCM_ROOT, a root of the GCM modulus in `GF2p128`, and is
+//! computed with a change-of-basis matrix whose columns are the powers of GCM_ROOT. The inverse
+//! matrix is built the same way from GF2P128_ROOT, a root of the `GF2p128` modulus in the GCM
+//! field.
+use crate::f2x::F2x;
+use crate::galoisfields::{FieldArithmetic, GF2p128};
+
+crate::galois_field!(GF2p128Gcm, 128, [7, 2, 1, 0]);
+
+/// The image of the GCM field's indeterminate in `GF2p128`
+const GCM_ROOT: GF2p128 =
+    GF2p128::from_poly(F2x::from_u128(0x496B_6A8C_5F57_8886_7E48_DCE6_BFE6_EBB1));
+
+/// The image of the indeterminate of `GF2p128` in the GCM field
+const GF2P128_ROOT: GF2p128Gcm =
+    GF2p128Gcm::from_poly(F2x::from_u128(0x0594_06D2_8CC7_BEF8_9AFD_C75A_DE67_E1CE));
+
+impl GF2p128Gcm {
+    /// Map into the crate's default representation of GF(2^128)
+    pub fn to_gf2p128(&self) -> GF2p128 {
+        let matrix = cached!([u128; 128], power_images(&GCM_ROOT));
+        from_bits(change_basis(matrix, to_bits(self)))
+    }
+
+    /// Map from the crate's default representation of GF(2^128). This is the inverse of
+    /// `to_gf2p128`
+    pub fn from_gf2p128(elem: &GF2p128) -> Self {
+        let matrix = cached!([u128; 128], power_images(&GF2P128_ROOT));
+        from_bits(change_basis(matrix, to_bits(elem)))
+    }
+
+    /// Parse a block in the byte order of the GCM specification, where the most significant bit
+    /// of the first byte is the constant term
+    pub fn from_gcm_bytes(block: &[u8; 16]) -> Self {
+        from_bits(u128::from_be_bytes(*block).reverse_bits())
+    }
+
+    /// Serialize into the byte order of the GCM specification. This is the inverse of
+    /// `from_gcm_bytes`
+    pub fn to_gcm_bytes(&self) -> [u8; 16] {
+        to_bits(self).reverse_bits().to_be_bytes()
+    }
+}
+
+/// Bit i of the output is the coefficient of x^i
+fn to_bits<F: FieldArithmetic>(elem: &F) -> u128 {
+    let mut buf = [0u8; 16];
+    elem.write_be_bytes(&mut buf);
+    u128::from_be_bytes(buf)
+}
+
+fn from_bits<F: FieldArithmetic>(bits: u128) -> F {
+    F::from_be_bytes(&bits.to_be_bytes())
+}
+
+/// Column i of the change-of-basis matrix is root^i, which is where x^i is sent
+fn power_images<F: FieldArithmetic>(root: &F) -> [u128; 128] {
+    let mut images = [0u128; 128];
+    let mut power = F::one();
+    for image in images.iter_mut() {
+        *image = to_bits(&power);
+        power = power.modmul(root);
+    }
+    images
+}
+
+fn change_basis(columns: &[u128; 128], bits: u128) -> u128 {
+    columns
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| (bits >> i) & 1 == 1)
+        .fold(0, |acc, (_, column)| acc ^ column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::thread_rng;
+
+    const NTESTS: usize = 10;
+
+    #[test]
+    fn gcm_to_gf2p128() {
+        let elem = GF2p128Gcm::from_poly(F2x::from_u128(0xDB5B_5FAB_8F4D_3E27_DDA1_494C_73CF_256D));
+        let image = GF2p128::from_poly(F2x::from_u128(0x0264_4A61_A7A6_0830_819B_EE80_F0EB_E52A));
+        assert_eq!(elem.to_gf2p128(), image);
+        assert_eq!(GF2p128Gcm::from_gf2p128(&image), elem);
+    }
+
+    #[test]
+    fn random_gcm_isomorphism() {
+        for _ in 0..NTESTS {
+            let (lhs, rhs) = (
+                GF2p128Gcm::random(&mut thread_rng()),
+                GF2p128Gcm::random(&mut thread_rng()),
+            );
+            assert_eq!(GF2p128Gcm::from_gf2p128(&lhs.to_gf2p128()), lhs);
+            assert_eq!(
+                lhs.modmul(&rhs).to_gf2p128(),
+                lhs.to_gf2p128().modmul(&rhs.to_gf2p128())
+            );
+            assert_eq!(
+                lhs.modadd(&rhs).to_gf2p128(),
+                lhs.to_gf2p128().modadd(&rhs.to_gf2p128())
+            );
+        }
+    }
+
+    /// The first GHASH multiplication in test case 2 of the GCM specification
+    #[test]
+    fn gcm_spec_multiplication() {
+        let hash_key =
+            GF2p128Gcm::from_gcm_bytes(&0x66e94bd4ef8a2c3b884cfa59ca342b2e_u128.to_be_bytes());
+        let block =
+            GF2p128Gcm::from_gcm_bytes(&0x0388dace60b6a392f328c2b971b2fe78_u128.to_be_bytes());
+        let prod = block.modmul(&hash_key);
+        assert_eq!(
+            prod.to_gcm_bytes(),
+            0x5e2ec746917062882c85b0685353deb7_u128.to_be_bytes()
+        );
+        assert_eq!(GF2p128Gcm::from_gcm_bytes(&prod.to_gcm_bytes()), prod);
+
+        // The same product computed under the crate's default modulus
+        let prod = GF2p128Gcm::from_gf2p128(&block.to_gf2p128().modmul(&hash_key.to_gf2p128()));
+        assert_eq!(
+            prod.to_gcm_bytes(),
+            0x5e2ec746917062882c85b0685353deb7_u128.to_be_bytes()
+        );
+    }
+}
@@ -0,0 +1,241 @@
+//! The GHASH and POLYVAL universal hashes, implementing the RustCrypto `universal-hash` traits
+//!
+//! GHASH is the authenticator of AES-GCM (NIST SP 800-38D): the message blocks are the
+//! coefficients of a polynomial that is evaluated at the hash key H in `GF2p128Gcm`, using
+//! Horner's rule. POLYVAL, the authenticator of AES-GCM-SIV (RFC 8452), is the same construction
+//! with little-endian blocks under the modulus x^128 + x^127 + x^126 + x^121 + 1, where every
+//! product is additionally multiplied by x^(-128).
+use crate::f2x::F2x;
+use crate::galoisfields::FieldArithmetic;
+use crate::gcm::GF2p128Gcm;
+use universal_hash::{
+    consts::{U1, U16},
+    crypto_common::{BlockSizeUser, KeySizeUser, ParBlocksSizeUser},
+    Block, Key, KeyInit, Reset, UhfBackend, UhfClosure, UniversalHash,
+};
+
+crate::galois_field!(GF2p128Polyval, 128, [127, 126, 121, 0]);
+
+/// x^128 reduced under the POLYVAL modulus
+const X128: GF2p128Polyval =
+    GF2p128Polyval::from_poly(F2x::from_u128((1 << 127) | (1 << 126) | (1 << 121) | 1));
+
+impl GF2p128Polyval {
+    /// Parse a block in the byte order of RFC 8452, where bit i of the little-endian integer is
+    /// the coefficient of x^i
+    pub fn from_polyval_bytes(block: &[u8; 16]) -> Self {
+        Self::from_poly(F2x::from_u128(u128::from_le_bytes(*block)))
+    }
+
+    /// Serialize into the byte order of RFC 8452. This is the inverse of `from_polyval_bytes`
+    pub fn to_polyval_bytes(&self) -> [u8; 16] {
+        let mut buf = [0u8; 16];
+        self.write_be_bytes(&mut buf);
+        u128::from_be_bytes(buf).to_le_bytes()
+    }
+}
+
+/// GHASH keyed by H. `Debug` does not print the key or the accumulator.
+#[derive(Clone)]
+pub struct Ghash {
+    key: GF2p128Gcm,
+    acc: GF2p128Gcm,
+}
+
+/// POLYVAL keyed by H. The key is stored as H * x^(-128), so that each block costs one ordinary
+/// field multiplication. `Debug` does not print the key or the accumulator.
+#[derive(Clone)]
+pub struct Polyval {
+    key: GF2p128Polyval,
+    acc: GF2p128Polyval,
+}
+
+impl core::fmt::Debug for Ghash {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Ghash { .. }")
+    }
+}
+
+impl core::fmt::Debug for Polyval {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Polyval { .. }")
+    }
+}
+
+impl KeySizeUser for Ghash {
+    type KeySize = U16;
+}
+
+impl KeySizeUser for Polyval {
+    type KeySize = U16;
+}
+
+impl KeyInit for Ghash {
+    fn new(key: &Key<Self>) -> Self {
+        Self {
+            key: GF2p128Gcm::from_gcm_bytes(&(*key).into()),
+            acc: GF2p128Gcm::ZERO,
+        }
+    }
+}
+
+impl KeyInit for Polyval {
+    fn new(key: &Key<Self>) -> Self {
+        let x128_inv = X128.modinv().expect("x^128 is not zero");
+        Self {
+            key: GF2p128Polyval::from_polyval_bytes(&(*key).into()).modmul(&x128_inv),
+            acc: GF2p128Polyval::ZERO,
+        }
+    }
+}
+
+impl BlockSizeUser for Ghash {
+    type BlockSize = U16;
+}
+
+impl BlockSizeUser for Polyval {
+    type BlockSize = U16;
+}
+
+impl ParBlocksSizeUser for Ghash {
+    type ParBlocksSize = U1;
+}
+
+impl ParBlocksSizeUser for Polyval {
+    type ParBlocksSize = U1;
+}
+
+impl UhfBackend for Ghash {
+    fn proc_block(&mut self, block: &Block<Self>) {
+        let block = GF2p128Gcm::from_gcm_bytes(&(*block).into());
+        self.acc = self.acc.modadd(&block).modmul(&self.key);
+    }
+}
+
+impl UhfBackend for Polyval {
+    fn proc_block(&mut self, block: &Block<Self>) {
+        let block = GF2p128Polyval::from_polyval_bytes(&(*block).into());
+        self.acc = self.acc.modadd(&block).modmul(&self.key);
+    }
+}
+
+impl UniversalHash for Ghash {
+    fn update_with_backend(&mut self, f: impl UhfClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+
+    fn finalize(self) -> Block<Self> {
+        self.acc.to_gcm_bytes().into()
+    }
+}
+
+impl UniversalHash for Polyval {
+    fn update_with_backend(&mut self, f: impl UhfClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+
+    fn finalize(self) -> Block<Self> {
+        self.acc.to_polyval_bytes().into()
+    }
+}
+
+impl Reset for Ghash {
+    fn reset(&mut self) {
+        self.acc = GF2p128Gcm::ZERO;
+    }
+}
+
+impl Reset for Polyval {
+    fn reset(&mut self) {
+        self.acc = GF2p128Polyval::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aes_gcm::aead::{Aead, Payload};
+    use aes_gcm::aes::cipher::BlockEncrypt;
+    use aes_gcm::aes::Aes256;
+    use aes_gcm::{Aes256Gcm, Nonce};
+    use rand::{thread_rng, Rng};
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn polyval_rfc8452_vector() {
+        // RFC 8452, appendix A
+        let mut polyval =
+            Polyval::new_from_slice(&hex("25629347589242761d31f826ba4b757b")).unwrap();
+        polyval.update_padded(&hex(
+            "4f4f95668c83dfb6401762bb2d01a262d1a24ddd2721d006bbe45f20d3c9f362",
+        ));
+        assert_eq!(
+            polyval.finalize().to_vec(),
+            hex("f7a3b47b846119fae5b7866cf5e5b77e")
+        );
+    }
+
+    #[test]
+    fn ghash_gcm_test_case_2() {
+        // McGrew and Viega, "The Galois/Counter Mode of Operation", test case 2
+        let mut ghash = Ghash::new_from_slice(&hex("66e94bd4ef8a2c3b884cfa59ca342b2e")).unwrap();
+        ghash.update_padded(&hex("0388dace60b6a392f328c2b971b2fe78"));
+        ghash.update_padded(&hex("00000000000000000000000000000080"));
+        assert_eq!(
+            ghash.finalize().to_vec(),
+            hex("f38cbb1ad69223dcc3457ae5b6b0f885")
+        );
+    }
+
+    /// Recompute the tag of AES-256-GCM from GHASH and the block cipher
+    #[test]
+    fn random_ghash_matches_aes_gcm() {
+        let mut rng = thread_rng();
+        for (aad_len, msg_len) in [(0, 0), (13, 0), (0, 32), (20, 45)] {
+            let key: [u8; 32] = rng.gen();
+            let nonce: [u8; 12] = rng.gen();
+            let aad = (0..aad_len).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let msg = (0..msg_len).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let sealed = Aes256Gcm::new(&key.into())
+                .encrypt(
+                    Nonce::from_slice(&nonce),
+                    Payload {
+                        msg: &msg,
+                        aad: &aad,
+                    },
+                )
+                .unwrap();
+            let (ciphertext, tag) = sealed.split_at(msg_len);
+
+            let aes = Aes256::new(&key.into());
+            let mut hash_key = [0u8; 16].into();
+            aes.encrypt_block(&mut hash_key);
+            let mut ghash = Ghash::new(&hash_key);
+            ghash.update_padded(&aad);
+            ghash.update_padded(ciphertext);
+            let mut lengths = [0u8; 16];
+            lengths[..8].copy_from_slice(&(8 * aad_len as u64).to_be_bytes());
+            lengths[8..].copy_from_slice(&(8 * msg_len as u64).to_be_bytes());
+            ghash.update_padded(&lengths);
+
+            let mut mask = [0u8; 16];
+            mask[..12].copy_from_slice(&nonce);
+            mask[15] = 1;
+            let mut mask = mask.into();
+            aes.encrypt_block(&mut mask);
+            let expected = ghash
+                .finalize()
+                .iter()
+                .zip(mask.iter())
+                .map(|(hash, mask)| hash ^ mask)
+                .collect::<Vec<u8>>();
+            assert_eq!(tag, expected);
+        }
+    }
+}
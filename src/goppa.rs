@@ -0,0 +1,265 @@
+//! The polynomial ring GF(2^m)[x] modulo a Goppa polynomial, and Patterson decoding
+//!
+//! A binary Goppa code is defined by an irreducible polynomial g of degree t over GF(2^m) and a
+//! support of n distinct field elements a_0, ..., a_(n-1) that are not roots of g. A binary word
+//! c is a codeword if its syndrome, the sum of 1 / (x - a_i) modulo g over the positions where
+//! c_i = 1, is zero; this is the code that Classic McEliece is built on. Patterson's algorithm
+//! corrects up to t errors using arithmetic in GF(2^m)[x] / (g): inverses, square roots, and the
+//! Extended Euclidean Algorithm stopped half-way.
+use crate::f2x::Degree;
+use crate::factor::is_irreducible;
+use crate::galoisfields::BinaryField;
+use crate::poly::{resize, PolyVec};
+use alloc::{vec, vec::Vec};
+
+/// Arithmetic in GF(2^m)[x] / (g) for a monic irreducible g of degree t, which is the field
+/// GF(2^(mt)). Elements are represented by their remainders of degree below t, with capacity 2t so
+/// that the product of two of them fits before it is reduced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoppaRing<F: BinaryField> {
+    modulus: PolyVec<F>,
+    /// The square root of x modulo g
+    sqrt_x: PolyVec<F>,
+}
+
+impl<F: BinaryField> GoppaRing<F> {
+    /// The ring modulo the monic associate of the input. Return None if the input is not
+    /// irreducible
+    pub fn new(modulus: &PolyVec<F>) -> Option<Self> {
+        if !is_irreducible(modulus) {
+            return None;
+        }
+        let degree = match modulus.degree() {
+            Degree::NonNegative(degree) => degree,
+            Degree::NegativeInfinity => unreachable!("zero is not irreducible"),
+        };
+        let modulus = resize(modulus, 2 * degree);
+        let modulus = PolyVec::gcd(&modulus, &PolyVec::zero_with_capacity(2 * degree));
+
+        // Write g = g0^2 + x g1^2. Since g is zero in the ring, (g0 / g1)^2 = x, and g1 is not zero
+        // because an irreducible g is not a square
+        let (g0, g1) = split_square(&modulus);
+        let mut ring = Self {
+            sqrt_x: PolyVec::zero_with_capacity(2 * degree),
+            modulus,
+        };
+        let g1_inv = ring.inv(&g1).expect("g1 is coprime to an irreducible g");
+        ring.sqrt_x = ring.mul(&g0, &g1_inv);
+
+        Some(ring)
+    }
+
+    /// The monic modulus g, with the capacity of the ring elements
+    pub fn modulus(&self) -> &PolyVec<F> {
+        &self.modulus
+    }
+
+    /// The degree t of the modulus
+    pub fn degree(&self) -> usize {
+        self.modulus.capacity() / 2
+    }
+
+    /// The remainder of a polynomial of any capacity modulo g, with the capacity of the ring
+    /// elements
+    pub fn reduce(&self, poly: &PolyVec<F>) -> PolyVec<F> {
+        let capacity = poly.capacity().max(self.modulus.capacity());
+        let (_, rem) = resize(poly, capacity).div_rem(&resize(&self.modulus, capacity));
+        resize(&rem, self.modulus.capacity())
+    }
+
+    /// The product of two reduced elements
+    pub fn mul(&self, lhs: &PolyVec<F>, rhs: &PolyVec<F>) -> PolyVec<F> {
+        let (_, rem) = lhs.mul(rhs).div_rem(&self.modulus);
+        rem
+    }
+
+    /// The inverse of a reduced element, or None if it is zero
+    pub fn inv(&self, elem: &PolyVec<F>) -> Option<PolyVec<F>> {
+        let (_, inv, gcd) = PolyVec::xgcd(&self.modulus, elem);
+        // The GCD with an irreducible modulus is a non-zero constant unless elem is zero
+        let gcd_inv = match gcd.degree() {
+            Degree::NonNegative(0) => gcd.coeffs[0].modinv()?,
+            _ => return None,
+        };
+        Some(self.reduce(&inv.mul_coeff(&gcd_inv)))
+    }
+
+    /// The unique square root of a reduced element: with a = a0^2 + x a1^2, the square root is
+    /// a0 + sqrt(x) a1
+    pub fn sqrt(&self, elem: &PolyVec<F>) -> PolyVec<F> {
+        let (even, odd) = split_square(elem);
+        even.add(&self.mul(&self.sqrt_x, &odd))
+    }
+}
+
+/// Split a into (a0, a1) such that a = a0^2 + x a1^2, with the capacity of a
+fn split_square<F: BinaryField>(poly: &PolyVec<F>) -> (PolyVec<F>, PolyVec<F>) {
+    let mut even = PolyVec::zero_with_capacity(poly.capacity());
+    let mut odd = PolyVec::zero_with_capacity(poly.capacity());
+    for (i, coeff) in poly.coeffs.iter().enumerate() {
+        let half = if i % 2 == 0 { &mut even } else { &mut odd };
+        half.coeffs[i / 2] = coeff.sqrt();
+    }
+    (even, odd)
+}
+
+/// A binary Goppa code: the ring modulo the Goppa polynomial g, and the support
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoppaCode<F: BinaryField> {
+    ring: GoppaRing<F>,
+    support: Vec<F>,
+}
+
+impl<F: BinaryField> GoppaCode<F> {
+    /// Return None if the Goppa polynomial is not irreducible, or if the support has repeated
+    /// elements or roots of g
+    pub fn new(goppa_poly: &PolyVec<F>, support: &[F]) -> Option<Self> {
+        let ring = GoppaRing::new(goppa_poly)?;
+        for (i, elem) in support.iter().enumerate() {
+            if support[..i].contains(elem) || ring.modulus.evaluate(elem).is_zero() {
+                return None;
+            }
+        }
+        Some(Self {
+            ring,
+            support: support.to_vec(),
+        })
+    }
+
+    pub fn ring(&self) -> &GoppaRing<F> {
+        &self.ring
+    }
+
+    pub fn support(&self) -> &[F] {
+        &self.support
+    }
+
+    /// The sum of 1 / (x - a_i) in the ring over the positions i where the word is set, which is
+    /// zero if and only if the word is a codeword. Will panic if the word is not as long as the
+    /// support
+    pub fn syndrome(&self, word: &[bool]) -> PolyVec<F> {
+        if word.len() != self.support.len() {
+            panic!("operand lengths do not match");
+        }
+        let mut syndrome = PolyVec::zero_with_capacity(self.ring.modulus.capacity());
+        for (elem, _) in self.support.iter().zip(word).filter(|(_, set)| **set) {
+            let mut linear = PolyVec::zero_with_capacity(self.ring.modulus.capacity());
+            linear.coeffs[0] = F::zero().modsub(elem);
+            linear.coeffs[1] = F::one();
+            let inv = self
+                .ring
+                .inv(&self.ring.reduce(&linear))
+                .expect("the support has no roots of g");
+            syndrome = syndrome.add(&inv);
+        }
+        syndrome
+    }
+
+    /// Find the positions where the received word differs from the nearest codeword with
+    /// Patterson's algorithm, which corrects up to t errors. With syndrome S, T = 1/S, and
+    /// R = sqrt(T + x), the partial Extended Euclidean Algorithm finds a = bR with deg a <= t/2
+    /// and deg b <= (t - 1)/2, and the error locator a^2 + x b^2 vanishes exactly at the support
+    /// elements of the error positions. Return the positions in ascending order, or None if the
+    /// locator does not split into distinct roots in the support, i.e. there are too many errors
+    pub fn decode(&self, received: &[bool]) -> Option<Vec<usize>> {
+        let syndrome = self.syndrome(received);
+        if syndrome.is_zero() {
+            return Some(vec![]);
+        }
+        let mut x = PolyVec::zero_with_capacity(self.ring.modulus.capacity());
+        x.coeffs[1] = F::one();
+        let inv = self.ring.inv(&syndrome).expect("syndrome is not zero");
+        let root = self.ring.sqrt(&inv.add(&x));
+
+        let t = self.ring.degree();
+        let (_, b, a) = PolyVec::partial_xgcd(&self.ring.modulus, &root, t / 2 + 1);
+        let locator = a.mul(&a).add(&x.mul(&b.mul(&b)));
+
+        let errors = locator
+            .evaluate_many(&self.support)
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| value.is_zero())
+            .map(|(i, _)| i)
+            .collect::<Vec<usize>>();
+        if locator.degree() != Degree::NonNegative(errors.len()) {
+            return None;
+        }
+        Some(errors)
+    }
+}
+
+#[cfg(all(test, feature = "gf8"))]
+mod tests {
+    use super::*;
+    use crate::galoisfields::{FieldArithmetic, GF2p8};
+    use rand::{seq::SliceRandom, thread_rng, Rng};
+
+    /// A random monic irreducible polynomial of degree t over GF(2^8)
+    fn random_goppa_poly(t: usize) -> PolyVec<GF2p8> {
+        let mut rng = thread_rng();
+        loop {
+            let mut poly = PolyVec::zero_with_capacity(t + 1);
+            poly.coeffs[..t]
+                .iter_mut()
+                .for_each(|coeff| *coeff = GF2p8::random(&mut rng));
+            poly.coeffs[t] = GF2p8::ONE;
+            if is_irreducible(&poly) {
+                return poly;
+            }
+        }
+    }
+
+    #[test]
+    fn random_ring_arithmetic() {
+        let mut rng = thread_rng();
+        let ring = GoppaRing::new(&random_goppa_poly(5)).unwrap();
+        assert_eq!(ring.degree(), 5);
+        let mut elem = PolyVec::zero_with_capacity(10);
+        elem.coeffs[..5]
+            .iter_mut()
+            .for_each(|coeff| *coeff = GF2p8::random(&mut rng));
+
+        let root = ring.sqrt(&elem);
+        assert_eq!(ring.mul(&root, &root), elem);
+        if !elem.is_zero() {
+            let inv = ring.inv(&elem).unwrap();
+            assert_eq!(
+                ring.mul(&inv, &elem),
+                ring.reduce(&PolyVec::from_coeffs(vec![GF2p8::ONE]))
+            );
+        }
+        assert!(ring.inv(&PolyVec::zero_with_capacity(10)).is_none());
+
+        // x^2 + x + 1 is reducible over GF(2^8), since GF(4) is a subfield
+        let reducible = PolyVec::from_coeffs(vec![GF2p8::ONE; 3]);
+        assert!(GoppaRing::new(&reducible).is_none());
+    }
+
+    #[test]
+    fn random_patterson_decoding() {
+        let mut rng = thread_rng();
+        let t = 6;
+        let mut support = (0..=255).map(GF2p8::from_u64).collect::<Vec<GF2p8>>();
+        support.shuffle(&mut rng);
+        support.truncate(64);
+        let code = GoppaCode::new(&random_goppa_poly(t), &support).unwrap();
+
+        // The zero word is a codeword, so an error pattern on its own decodes to its positions
+        for weight in 0..=t {
+            let mut errors = (0..support.len()).collect::<Vec<usize>>();
+            errors.shuffle(&mut rng);
+            errors.truncate(weight);
+            errors.sort();
+            let mut received = vec![false; support.len()];
+            errors.iter().for_each(|i| received[*i] = true);
+            assert_eq!(code.decode(&received), Some(errors));
+        }
+        assert!(code.syndrome(&vec![false; support.len()]).is_zero());
+
+        // The support must not repeat elements
+        let i = rng.gen_range(1..support.len());
+        support[i] = support[0];
+        assert!(GoppaCode::new(code.ring().modulus(), &support).is_none());
+    }
+}
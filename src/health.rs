@@ -0,0 +1,216 @@
+//! Continuous health tests on the output of a random number generator
+//!
+//! A dealer whose generator gets stuck hands out shares of a predictable polynomial. The two
+//! continuous tests of [NIST SP 800-90B](https://csrc.nist.gov/pubs/sp/800/90/b/final), section
+//! 4.4, catch the most common failures cheaply: the repetition count test fails when one byte
+//! repeats too many times in a row, and the adaptive proportion test fails when one byte makes up
+//! too large a share of a window of samples.
+//!
+//! Each output byte is one sample. The cutoffs assume at least 4 bits of min-entropy per byte and
+//! a false positive rate of 2^-20, which a working generator never gets near.
+use rand::{CryptoRng, RngCore};
+
+/// The repetition count test fails when the same byte is seen this many times in a row:
+/// 1 + ceil(20 / H) for H = 4
+pub const REPETITION_CUTOFF: usize = 6;
+
+/// The number of samples in each window of the adaptive proportion test
+pub const PROPORTION_WINDOW: usize = 512;
+
+/// The adaptive proportion test fails when the first byte of a window is seen this many times in
+/// the window (SP 800-90B, table 2, for H = 4)
+pub const PROPORTION_CUTOFF: usize = 62;
+
+/// The state of both continuous tests
+#[derive(Debug, Default, Clone)]
+pub struct HealthTests {
+    /// The last sample and how many times in a row it has been seen
+    last: Option<u8>,
+    repetitions: usize,
+    /// The first sample of the current window, how many times it has been seen, and how many
+    /// samples of the window have been consumed
+    window_sample: Option<u8>,
+    window_count: usize,
+    window_len: usize,
+    failed: bool,
+}
+
+impl HealthTests {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run both tests on the next sample. Once a test fails, the failure is sticky.
+    pub fn push(&mut self, sample: u8) {
+        if self.last == Some(sample) {
+            self.repetitions += 1;
+        } else {
+            self.last = Some(sample);
+            self.repetitions = 1;
+        }
+        if self.repetitions >= REPETITION_CUTOFF {
+            self.failed = true;
+        }
+
+        match self.window_sample {
+            Some(first) if self.window_len < PROPORTION_WINDOW => {
+                self.window_len += 1;
+                if first == sample {
+                    self.window_count += 1;
+                }
+                if self.window_count >= PROPORTION_CUTOFF {
+                    self.failed = true;
+                }
+            }
+            _ => {
+                self.window_sample = Some(sample);
+                self.window_count = 1;
+                self.window_len = 1;
+            }
+        }
+    }
+
+    /// False if either test has failed on any sample so far
+    pub fn is_healthy(&self) -> bool {
+        !self.failed
+    }
+}
+
+/// The error returned by `HealthCheckedRng::try_fill_bytes` once a health test has failed
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HealthTestFailure;
+
+impl core::fmt::Display for HealthTestFailure {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "the random number generator failed a continuous health test"
+        )
+    }
+}
+
+impl core::error::Error for HealthTestFailure {}
+
+/// The code of the error that `HealthCheckedRng::try_fill_bytes` returns without std, where
+/// `rand::Error` cannot box a `HealthTestFailure`
+#[cfg(not(feature = "std"))]
+pub const HEALTH_TEST_FAILURE_CODE: core::num::NonZeroU32 =
+    match core::num::NonZeroU32::new(rand::Error::CUSTOM_START) {
+        Some(code) => code,
+        None => panic!("custom error codes are non-zero"),
+    };
+
+/// Runs the continuous health tests on every byte drawn from the inner generator. `fill_bytes`
+/// cannot fail, so callers that sample through it must check `is_healthy` before using the
+/// output; `try_fill_bytes` returns an error instead.
+pub struct HealthCheckedRng<R> {
+    rng: R,
+    tests: HealthTests,
+}
+
+impl<R: RngCore> HealthCheckedRng<R> {
+    pub fn new(rng: R) -> Self {
+        Self {
+            rng,
+            tests: HealthTests::new(),
+        }
+    }
+
+    /// False if any byte drawn so far failed a health test
+    pub fn is_healthy(&self) -> bool {
+        self.tests.is_healthy()
+    }
+}
+
+impl<R: RngCore> RngCore for HealthCheckedRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0u8; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0u8; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+        dest.iter().for_each(|sample| self.tests.push(*sample));
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)?;
+        dest.iter().for_each(|sample| self.tests.push(*sample));
+        if !self.is_healthy() {
+            #[cfg(feature = "std")]
+            return Err(rand::Error::new(HealthTestFailure));
+            #[cfg(not(feature = "std"))]
+            return Err(rand::Error::from(HEALTH_TEST_FAILURE_CODE));
+        }
+        Ok(())
+    }
+}
+
+impl<R: CryptoRng> CryptoRng for HealthCheckedRng<R> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::thread_rng;
+
+    /// A generator that produces the input pattern over and over
+    struct Pattern(Vec<u8>, usize);
+
+    impl RngCore for Pattern {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for byte in dest.iter_mut() {
+                *byte = self.0[self.1 % self.0.len()];
+                self.1 += 1;
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn healthy_generator_passes() {
+        let mut rng = HealthCheckedRng::new(thread_rng());
+        let mut buf = [0u8; 4096];
+        for _ in 0..64 {
+            assert!(rng.try_fill_bytes(&mut buf).is_ok());
+        }
+        assert!(rng.is_healthy());
+    }
+
+    #[test]
+    fn repetition_count_detects_stuck_output() {
+        let mut rng = HealthCheckedRng::new(Pattern(vec![0x42], 0));
+        let mut buf = [0u8; REPETITION_CUTOFF - 1];
+        assert!(rng.try_fill_bytes(&mut buf).is_ok());
+        assert!(rng.try_fill_bytes(&mut [0u8]).is_err());
+        assert!(!rng.is_healthy());
+    }
+
+    #[test]
+    fn adaptive_proportion_detects_bias() {
+        // 0xAA is a quarter of the output but never repeats in a row
+        let mut rng = HealthCheckedRng::new(Pattern(vec![0xAA, 1, 2, 3, 0xAA, 4, 5, 6], 0));
+        let mut buf = [0u8; PROPORTION_WINDOW];
+        rng.fill_bytes(&mut buf);
+        assert!(!rng.is_healthy());
+        assert!(rng.try_fill_bytes(&mut buf).is_err());
+    }
+}
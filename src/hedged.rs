@@ -0,0 +1,122 @@
+//! Hedged dealing in the spirit of [RFC 6979](https://www.rfc-editor.org/rfc/rfc6979)
+//!
+//! A dealer who samples the coefficients of the secret polynomial from a broken random number
+//! generator leaks the secret to anyone who can predict the generator. Hedged dealing instead draws
+//! the coefficients from an HMAC-DRBG seeded with the secret, the participant set, a nonce, and
+//! fresh randomness. If the randomness is good, the coefficients are as random as ever; if it is
+//! not, they are still unpredictable to anyone who does not already know the secret.
+use hmac::{Hmac, Mac};
+use rand::{CryptoRng, RngCore};
+use sha3::Sha3_256;
+use zeroize::Zeroize;
+
+type HmacSha3 = Hmac<Sha3_256>;
+
+/// The output size of HMAC-SHA3-256
+const OUTPUT_BYTES: usize = 32;
+
+/// The HMAC_DRBG of NIST SP 800-90A, instantiated with HMAC-SHA3-256. Every input to `new` is
+/// prefixed with its length, so that different splits of the same bytes into inputs produce
+/// different outputs. The state is zeroized on drop.
+pub struct HmacDrbg {
+    key: [u8; OUTPUT_BYTES],
+    value: [u8; OUTPUT_BYTES],
+}
+
+impl HmacDrbg {
+    /// Instantiate the generator from the concatenation of the length-prefixed inputs
+    pub fn new(inputs: &[&[u8]]) -> Self {
+        let mut drbg = Self {
+            key: [0x00; OUTPUT_BYTES],
+            value: [0x01; OUTPUT_BYTES],
+        };
+        drbg.update(inputs);
+
+        drbg
+    }
+
+    /// HMAC under the current key of the concatenated parts followed by the length-prefixed
+    /// inputs
+    fn hmac(&self, parts: &[&[u8]], inputs: &[&[u8]]) -> [u8; OUTPUT_BYTES] {
+        let mut mac =
+            <HmacSha3 as Mac>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        for part in parts {
+            mac.update(part);
+        }
+        for input in inputs {
+            mac.update(&(input.len() as u64).to_be_bytes());
+            mac.update(input);
+        }
+        mac.finalize().into_bytes().into()
+    }
+
+    /// The HMAC_DRBG update function, whose provided data is the concatenation of the
+    /// length-prefixed inputs. The inputs are streamed into the HMAC instead of being copied into
+    /// one buffer, so that the secret among them is never duplicated on the heap.
+    fn update(&mut self, inputs: &[&[u8]]) {
+        self.key = self.hmac(&[&self.value, &[0x00]], inputs);
+        self.value = self.hmac(&[&self.value], &[]);
+        if !inputs.is_empty() {
+            self.key = self.hmac(&[&self.value, &[0x01]], inputs);
+            self.value = self.hmac(&[&self.value], &[]);
+        }
+    }
+}
+
+impl RngCore for HmacDrbg {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0u8; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0u8; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(OUTPUT_BYTES) {
+            self.value = self.hmac(&[&self.value], &[]);
+            chunk.copy_from_slice(&self.value[..chunk.len()]);
+        }
+        self.update(&[]);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for HmacDrbg {}
+
+impl Drop for HmacDrbg {
+    fn drop(&mut self) {
+        self.key.zeroize();
+        self.value.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drbg_is_deterministic() {
+        let mut lhs = HmacDrbg::new(&[b"secret", b"nonce"]);
+        let mut rhs = HmacDrbg::new(&[b"secret", b"nonce"]);
+        let (mut lhs_buf, mut rhs_buf) = ([0u8; 100], [0u8; 100]);
+        lhs.fill_bytes(&mut lhs_buf);
+        rhs.fill_bytes(&mut rhs_buf);
+        assert_eq!(lhs_buf, rhs_buf);
+        assert_eq!(lhs.next_u64(), rhs.next_u64());
+
+        // Moving bytes between inputs changes the seed
+        let mut shifted = HmacDrbg::new(&[b"secretn", b"once"]);
+        let mut shifted_buf = [0u8; 100];
+        shifted.fill_bytes(&mut shifted_buf);
+        assert_ne!(lhs_buf, shifted_buf);
+    }
+}
@@ -12,7 +12,91 @@
 //! as many possible polynomials as there are unique values the polynomial could have evaluate to,
 //! so as long as we choose a cryptographically large field to build the polynomial on, the scheme
 //! will be secure (it is in fact information theoretically secure).
-mod f2x;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+// Casts between limbs and u64 are no-ops when the limbs are 64 bits wide
+#![cfg_attr(feature = "word-u64", allow(clippy::unnecessary_cast))]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Evaluate an expression of the given type on first use and keep it in a static `OnceLock`,
+/// returning a reference to it. Without std there is no `OnceLock`, so the expression is evaluated
+/// again every time.
+#[cfg(feature = "std")]
+#[cfg_attr(not(feature = "gf128"), allow(unused_macros))]
+macro_rules! cached {
+    ($ty:ty, $init:expr) => {{
+        static CACHE: std::sync::OnceLock<$ty> = std::sync::OnceLock::new();
+        CACHE.get_or_init(|| $init)
+    }};
+}
+
+#[cfg(not(feature = "std"))]
+#[cfg_attr(not(feature = "gf128"), allow(unused_macros))]
+macro_rules! cached {
+    ($ty:ty, $init:expr) => {
+        &$init
+    };
+}
+
+#[cfg(feature = "alloc")]
+pub mod additivefft;
+pub mod bitslice;
+pub mod deterministic;
+#[cfg(feature = "dudect")]
+pub mod dudect;
+pub mod entropy;
+pub mod error;
+pub mod f2x;
+#[cfg(feature = "alloc")]
+pub mod factor;
 pub mod galoisfields;
+#[cfg(feature = "gf128")]
+pub mod gcm;
+#[cfg(feature = "universal-hash")]
+pub mod ghash;
+#[cfg(feature = "alloc")]
+pub mod goppa;
+pub mod health;
+pub mod hedged;
+#[cfg(feature = "alloc")]
+pub mod linearized;
+#[cfg(feature = "alloc")]
+pub mod matrix;
+#[cfg(feature = "alloc")]
+pub mod normalbasis;
 pub mod poly;
+pub mod primefields;
+pub mod secret;
+#[cfg(all(feature = "std", feature = "gf256"))]
 pub mod secretsharing;
+pub mod selftest;
+pub mod shamir;
+#[cfg(feature = "alloc")]
+pub mod sparsepoly;
+#[cfg(feature = "gf128")]
+pub mod tower;
+pub mod transcript;
+
+/// The one GF(2)[x] implementation, on which every binary field is built
+pub use f2x::{F2x, WideF2x};
+
+/// Re-exports used by the exported macros so that downstream crates do not need to depend on the
+/// same versions of these crates themselves
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::collections::BTreeMap;
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
+    #[cfg(feature = "ff")]
+    pub use ff;
+    #[cfg(feature = "num-traits")]
+    pub use num_traits;
+    pub use rand;
+    pub use rand_core;
+    #[cfg(feature = "std")]
+    pub use std::sync::OnceLock;
+    #[cfg(feature = "subtle")]
+    pub use subtle;
+    pub use zeroize;
+}
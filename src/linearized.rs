@@ -0,0 +1,173 @@
+//! Linearized polynomials over binary fields GF(2^m)
+//!
+//! A linearized polynomial L(x) = a_0 x + a_1 x^2 + a_2 x^4 + ... only has terms whose powers are
+//! powers of 2. Since squaring is additive in characteristic 2, L(x + y) = L(x) + L(y), so L is a
+//! GF(2)-linear map of the field. The subspace polynomial of a GF(2)-linear subspace, whose roots
+//! are exactly the subspace, is linearized, which is what additive FFTs and normal-basis
+//! conversions build on.
+use crate::galoisfields::BinaryField;
+use crate::poly::PolyVec;
+use alloc::{vec, vec::Vec};
+
+/// A linearized polynomial with coeffs[i] the coefficient of x^(2^i)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearizedPoly<F: BinaryField> {
+    pub coeffs: Vec<F>,
+}
+
+impl<F: BinaryField> LinearizedPoly<F> {
+    pub fn from_coeffs(coeffs: Vec<F>) -> Self {
+        Self { coeffs }
+    }
+
+    /// The identity map x
+    pub fn identity() -> Self {
+        Self::from_coeffs(vec![F::one()])
+    }
+
+    /// The subspace polynomial of the GF(2)-span of the basis: the monic linearized polynomial of
+    /// q-degree k whose roots are the 2^k elements of the span, each with multiplicity one. With
+    /// W_0 = x, each basis element b_i contributes W_(i + 1) = W_i^2 + W_i(b_i) W_i, which vanishes
+    /// on b_i and on the span so far. Will panic if the basis is not linearly independent over
+    /// GF(2)
+    pub fn subspace(basis: &[F]) -> Self {
+        let mut poly = Self::identity();
+        for elem in basis {
+            let value = poly.evaluate(elem);
+            if value.is_zero() {
+                panic!("basis is not linearly independent over GF(2)");
+            }
+            poly = Self::from_coeffs(vec![value, F::one()]).compose(&poly);
+        }
+        poly
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.iter().all(|coeff| coeff.is_zero())
+    }
+
+    /// The largest i such that the coefficient of x^(2^i) is not zero, or None for zero
+    pub fn q_degree(&self) -> Option<usize> {
+        self.coeffs.iter().rposition(|coeff| !coeff.is_zero())
+    }
+
+    pub fn add(&self, rhs: &Self) -> Self {
+        let len = self.coeffs.len().max(rhs.coeffs.len());
+        let coeffs = (0..len)
+            .map(|i| {
+                let lhs = self.coeffs.get(i).copied().unwrap_or(F::zero());
+                let rhs = rhs.coeffs.get(i).copied().unwrap_or(F::zero());
+                lhs.modadd(&rhs)
+            })
+            .collect();
+        Self::from_coeffs(coeffs)
+    }
+
+    /// Evaluate at the given point with one squaring and one multiplication per term
+    pub fn evaluate(&self, at: &F) -> F {
+        let mut sum = F::zero();
+        let mut power = *at;
+        for coeff in self.coeffs.iter() {
+            sum.modadd_assign(&coeff.modmul(&power));
+            power = power.square();
+        }
+        sum
+    }
+
+    /// The symbolic composition self(inner(x)), which is again linearized: the coefficient of
+    /// x^(2^k) is the sum of a_i b_j^(2^i) over i + j = k. Composition is associative but not
+    /// commutative
+    pub fn compose(&self, inner: &Self) -> Self {
+        if self.coeffs.is_empty() || inner.coeffs.is_empty() {
+            return Self::from_coeffs(vec![]);
+        }
+        let mut coeffs = vec![F::zero(); self.coeffs.len() + inner.coeffs.len() - 1];
+        // frobenius[j] holds b_j^(2^i) for the current i
+        let mut frobenius = inner.coeffs.clone();
+        for (i, outer) in self.coeffs.iter().enumerate() {
+            for (j, inner) in frobenius.iter().enumerate() {
+                coeffs[i + j].modadd_assign(&outer.modmul(inner));
+            }
+            frobenius.iter_mut().for_each(|elem| *elem = elem.square());
+        }
+        Self::from_coeffs(coeffs)
+    }
+
+    /// The same polynomial with all of its coefficients, with the given capacity. Will panic if
+    /// 2^(q-degree) does not fit
+    pub fn to_poly(&self, capacity: usize) -> PolyVec<F> {
+        let mut poly = PolyVec::zero_with_capacity(capacity);
+        for (i, coeff) in self.coeffs.iter().enumerate() {
+            if coeff.is_zero() {
+                continue;
+            }
+            match 1usize.checked_shl(i as u32) {
+                Some(power) if power < capacity => poly.coeffs[power] = *coeff,
+                _ => panic!("polynomial does not fit in the capacity"),
+            }
+        }
+        poly
+    }
+}
+
+#[cfg(all(test, feature = "gf8", feature = "gf128"))]
+mod tests {
+    use super::*;
+    use crate::additivefft::span_point;
+    use crate::galoisfields::{FieldArithmetic, GF2p128, GF2p8};
+    use rand::thread_rng;
+
+    fn random_linearized(terms: usize) -> LinearizedPoly<GF2p128> {
+        let mut rng = thread_rng();
+        LinearizedPoly::from_coeffs((0..terms).map(|_| GF2p128::random(&mut rng)).collect())
+    }
+
+    #[test]
+    fn random_linearized_evaluation() {
+        let mut rng = thread_rng();
+        let poly = random_linearized(5);
+        let (a, b) = (GF2p128::random(&mut rng), GF2p128::random(&mut rng));
+        assert_eq!(
+            poly.evaluate(&a.modadd(&b)),
+            poly.evaluate(&a).modadd(&poly.evaluate(&b))
+        );
+        assert_eq!(poly.evaluate(&GF2p128::ZERO), GF2p128::ZERO);
+        assert_eq!(poly.to_poly(17).evaluate(&a), poly.evaluate(&a));
+
+        let other = random_linearized(3);
+        assert_eq!(
+            poly.add(&other).evaluate(&a),
+            poly.evaluate(&a).modadd(&other.evaluate(&a))
+        );
+        assert_eq!(
+            poly.compose(&other).evaluate(&a),
+            poly.evaluate(&other.evaluate(&a))
+        );
+        assert_eq!(
+            other.compose(&poly).evaluate(&a),
+            other.evaluate(&poly.evaluate(&a))
+        );
+        assert_eq!(poly.compose(&LinearizedPoly::identity()), poly);
+    }
+
+    #[test]
+    fn subspace_polynomial() {
+        let mut rng = thread_rng();
+        let basis = [0x03, 0x05, 0x80].map(GF2p8::from_u64);
+        let poly = LinearizedPoly::subspace(&basis);
+        assert_eq!(poly.q_degree(), Some(3));
+        assert!(poly.coeffs[3].is_one());
+        for i in 0..8 {
+            assert!(poly.evaluate(&span_point(&basis, i)).is_zero());
+        }
+        // No element outside the span is a root
+        let roots = poly.to_poly(9).roots(&mut rng);
+        assert_eq!(roots.len(), 8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dependent_subspace_basis() {
+        LinearizedPoly::subspace(&[0x03, 0x05, 0x06].map(GF2p8::from_u64));
+    }
+}
@@ -0,0 +1,316 @@
+//! Dense matrices over any field
+//!
+//! `Matrix` is meant for the small linear systems of verifiable secret sharing, Berlekamp-Welch
+//! decoding, and similar experiments. Everything is built on Gauss-Jordan elimination, which costs
+//! O(n^3) field operations and is exact, so there is no pivoting strategy beyond finding a non-zero
+//! entry.
+use crate::galoisfields::FieldArithmetic;
+use alloc::{vec, vec::Vec};
+
+/// A matrix stored in row-major order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<F: FieldArithmetic> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<F>,
+}
+
+impl<F: FieldArithmetic> Matrix<F> {
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            entries: vec![F::zero(); rows * cols],
+        }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut identity = Self::zero(n, n);
+        for i in 0..n {
+            identity[(i, i)] = F::one();
+        }
+        identity
+    }
+
+    /// Build a matrix from its entries in row-major order. Will panic if there are not
+    /// rows * cols entries
+    pub fn from_entries(rows: usize, cols: usize, entries: Vec<F>) -> Self {
+        if entries.len() != rows * cols {
+            panic!("Matrix dimensions do not match the number of entries");
+        }
+        Self {
+            rows,
+            cols,
+            entries,
+        }
+    }
+
+    /// Build a matrix from its rows. Will panic if the rows have different lengths
+    pub fn from_rows(rows: &[&[F]]) -> Self {
+        let cols = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != cols) {
+            panic!("Matrix rows have different lengths");
+        }
+        Self::from_entries(rows.len(), cols, rows.concat())
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, i: usize) -> &[F] {
+        &self.entries[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut transpose = Self::zero(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                transpose[(j, i)] = self[(i, j)];
+            }
+        }
+        transpose
+    }
+
+    /// Matrix product. Will panic if the number of columns of self is not the number of rows of
+    /// rhs
+    pub fn mul(&self, rhs: &Self) -> Self {
+        if self.cols != rhs.rows {
+            panic!("Matrix dimensions do not match");
+        }
+        let mut prod = Self::zero(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for (k, lhs) in self.row(i).iter().enumerate() {
+                for j in 0..rhs.cols {
+                    let term = lhs.modmul(&rhs[(k, j)]);
+                    prod[(i, j)].modadd_assign(&term);
+                }
+            }
+        }
+        prod
+    }
+
+    /// The product of self with a column vector. Will panic if the vector does not have one entry
+    /// per column
+    pub fn mul_vec(&self, vec: &[F]) -> Vec<F> {
+        if self.cols != vec.len() {
+            panic!("Matrix dimensions do not match");
+        }
+        (0..self.rows)
+            .map(|i| {
+                self.row(i)
+                    .iter()
+                    .zip(vec)
+                    .fold(F::zero(), |acc, (a, b)| acc.modadd(&a.modmul(b)))
+            })
+            .collect()
+    }
+
+    /// Bring self into reduced row echelon form with Gauss-Jordan elimination and return the
+    /// pivot column of each non-zero row, in order. The number of pivots is the rank
+    pub fn row_reduce(&mut self) -> Vec<usize> {
+        let mut pivots = vec![];
+        for col in 0..self.cols {
+            let row = pivots.len();
+            let pivot = match (row..self.rows).find(|i| !self[(*i, col)].is_zero()) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            self.swap_rows(row, pivot);
+            let inv = self[(row, col)]
+                .modinv()
+                .expect("non-zero elements are invertible");
+            self.scale_row(row, &inv);
+            for other in 0..self.rows {
+                let factor = self[(other, col)];
+                if other != row && !factor.is_zero() {
+                    self.sub_row_multiple(other, row, &factor);
+                }
+            }
+            pivots.push(col);
+            if pivots.len() == self.rows {
+                break;
+            }
+        }
+        pivots
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().row_reduce().len()
+    }
+
+    /// The inverse of a square matrix, or None if it is singular. Will panic if self is not square
+    pub fn inverse(&self) -> Option<Self> {
+        if self.rows != self.cols {
+            panic!("Only square matrices can be inverted");
+        }
+        let n = self.rows;
+        // Row reduce [self | I]; if self is invertible, the right half becomes its inverse
+        let mut augmented = Self::zero(n, 2 * n);
+        for i in 0..n {
+            for j in 0..n {
+                augmented[(i, j)] = self[(i, j)];
+            }
+            augmented[(i, n + i)] = F::one();
+        }
+        let pivots = augmented.row_reduce();
+        if pivots.len() < n || pivots[n - 1] >= n {
+            return None;
+        }
+        let mut inverse = Self::zero(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                inverse[(i, j)] = augmented[(i, n + j)];
+            }
+        }
+        Some(inverse)
+    }
+
+    /// A basis of the kernel, the vectors v with self * v = 0: one vector per non-pivot column,
+    /// which has a one at that column and zeros at the other non-pivot columns
+    pub fn kernel(&self) -> Vec<Vec<F>> {
+        let mut reduced = self.clone();
+        let pivots = reduced.row_reduce();
+        (0..self.cols)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                let mut vec = vec![F::zero(); self.cols];
+                vec[free] = F::one();
+                for (row, pivot) in pivots.iter().enumerate() {
+                    vec[*pivot] = F::zero().modsub(&reduced[(row, free)]);
+                }
+                vec
+            })
+            .collect()
+    }
+
+    /// Some solution v of self * v = rhs, with zeros at the non-pivot columns, or None if the
+    /// system is inconsistent. Will panic if rhs does not have one entry per row
+    pub fn solve(&self, rhs: &[F]) -> Option<Vec<F>> {
+        if self.rows != rhs.len() {
+            panic!("Matrix dimensions do not match");
+        }
+        let mut augmented = Self::zero(self.rows, self.cols + 1);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                augmented[(i, j)] = self[(i, j)];
+            }
+            augmented[(i, self.cols)] = rhs[i];
+        }
+        let pivots = augmented.row_reduce();
+        if pivots.last() == Some(&self.cols) {
+            return None;
+        }
+        let mut solution = vec![F::zero(); self.cols];
+        for (row, pivot) in pivots.iter().enumerate() {
+            solution[*pivot] = augmented[(row, self.cols)];
+        }
+        Some(solution)
+    }
+
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        for col in 0..self.cols {
+            self.entries.swap(i * self.cols + col, j * self.cols + col);
+        }
+    }
+
+    fn scale_row(&mut self, i: usize, factor: &F) {
+        let cols = self.cols;
+        self.entries[i * cols..(i + 1) * cols]
+            .iter_mut()
+            .for_each(|entry| entry.modmul_assign(factor));
+    }
+
+    /// Subtract factor times row src from row dst
+    fn sub_row_multiple(&mut self, dst: usize, src: usize, factor: &F) {
+        for col in 0..self.cols {
+            let term = self[(src, col)].modmul(factor);
+            self[(dst, col)].modsub_assign(&term);
+        }
+    }
+}
+
+impl<F: FieldArithmetic> core::ops::Index<(usize, usize)> for Matrix<F> {
+    type Output = F;
+
+    fn index(&self, (i, j): (usize, usize)) -> &F {
+        if i >= self.rows || j >= self.cols {
+            panic!("Matrix index out of bounds");
+        }
+        &self.entries[i * self.cols + j]
+    }
+}
+
+impl<F: FieldArithmetic> core::ops::IndexMut<(usize, usize)> for Matrix<F> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut F {
+        if i >= self.rows || j >= self.cols {
+            panic!("Matrix index out of bounds");
+        }
+        &mut self.entries[i * self.cols + j]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "gf128")]
+    use crate::galoisfields::GF2p128;
+    use crate::primefields::Fp25519;
+    use rand::thread_rng;
+
+    fn random_matrix<F: FieldArithmetic>(rows: usize, cols: usize) -> Matrix<F> {
+        let mut rng = thread_rng();
+        let entries = (0..rows * cols).map(|_| F::random(&mut rng)).collect();
+        Matrix::from_entries(rows, cols, entries)
+    }
+
+    #[test]
+    fn random_matrix_algebra() {
+        // A random square matrix is singular with probability about 1/q
+        let square = loop {
+            let square = random_matrix::<Fp25519>(5, 5);
+            if square.rank() == 5 {
+                break square;
+            }
+        };
+        let inverse = square.inverse().unwrap();
+        assert_eq!(square.mul(&inverse), Matrix::identity(5));
+        assert_eq!(inverse.mul(&square), Matrix::identity(5));
+
+        // A 6x6 product through a 6x3 matrix has rank at most 3
+        let singular = random_matrix::<Fp25519>(6, 3).mul(&random_matrix(3, 6));
+        let rank = singular.rank();
+        assert!(rank <= 3);
+        assert!(singular.inverse().is_none());
+        let kernel = singular.kernel();
+        assert_eq!(kernel.len(), 6 - rank);
+        for vec in kernel.iter() {
+            assert!(singular.mul_vec(vec).iter().all(|entry| entry.is_zero()));
+        }
+        assert_eq!(singular.transpose().rank(), rank);
+
+        // A consistent right-hand side is solved exactly; a generic one is not in the image
+        let rhs = singular.mul_vec(&random_matrix::<Fp25519>(6, 1).entries);
+        let solution = singular.solve(&rhs).unwrap();
+        assert_eq!(singular.mul_vec(&solution), rhs);
+        let generic = random_matrix::<Fp25519>(6, 1).entries;
+        assert!(singular.solve(&generic).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    fn from_rows_and_transpose() {
+        let (one, zero) = (GF2p128::ONE, GF2p128::ZERO);
+        let matrix = Matrix::from_rows(&[&[one, zero, one], &[zero, one, one]]);
+        assert_eq!((matrix.rows(), matrix.cols()), (2, 3));
+        assert_eq!(matrix.row(1), &[zero, one, one]);
+        assert_eq!(matrix.transpose()[(2, 0)], one);
+        assert_eq!(matrix.rank(), 2);
+        assert_eq!(matrix.kernel(), vec![vec![one, one, one]]);
+        assert!(Matrix::<GF2p128>::zero(3, 3).inverse().is_none());
+    }
+}
@@ -0,0 +1,197 @@
+//! Normal basis representation of binary extension fields
+//!
+//! A normal element b of GF(2^m) is one whose conjugates {b, b^2, b^4, ..., b^(2^(m-1))} form a
+//! basis of GF(2^m) over GF(2). Under such a basis, squaring an element is a cyclic rotation of
+//! its coordinates, so repeated squaring (inversion by exponentiation, trace, Frobenius maps)
+//! becomes almost free. Multiplication is still best done in the polynomial basis, so this module
+//! only provides the change of basis and the rotation.
+use crate::f2x::{F2x, Word};
+use alloc::{vec, vec::Vec};
+
+/// Flip the coefficient of x^i
+fn flip_bit<const L: usize>(poly: &mut F2x<L>, i: usize) {
+    let limb = poly
+        .le_limb_mut(i / (Word::BITS as usize))
+        .expect("unexpected out-of-bound");
+    *limb ^= 1 << (i % (Word::BITS as usize));
+}
+
+/// The parity of the number of terms shared by the two polynomials
+fn and_parity<const L: usize>(lhs: &F2x<L>, rhs: &F2x<L>) -> bool {
+    let ones: u32 = (0..L)
+        .map(|i| (lhs.get_limb(i).unwrap() & rhs.get_limb(i).unwrap()).count_ones())
+        .sum();
+    ones % 2 == 1
+}
+
+/// The change-of-basis data between the polynomial basis and a normal basis of GF(2^m)
+#[derive(Debug, Clone)]
+pub struct NormalBasis<const L: usize> {
+    degree: usize,
+
+    /// conjugates[i] is b^(2^i) in the polynomial basis, which is also the image of the i-th
+    /// normal basis vector
+    conjugates: Vec<F2x<L>>,
+
+    /// Normal coordinate i of a polynomial p is the parity of (inverse[i] AND p)
+    inverse: Vec<F2x<L>>,
+}
+
+impl<const L: usize> NormalBasis<L> {
+    /// Search for a normal element of GF(2^degree), whose multiplication is given by the input
+    /// closure, and compute the conversion matrices. Candidates are tried in a fixed order, so the
+    /// result is deterministic.
+    pub fn find(degree: usize, mul: impl Fn(&F2x<L>, &F2x<L>) -> F2x<L>) -> Self {
+        if degree == 0 || degree > F2x::<L>::BITS {
+            panic!("degree is out of range");
+        }
+        let square = |poly: &F2x<L>| mul(poly, poly);
+        // Low-degree candidates are a poor choice because for sparse moduli they all tend to have
+        // trace 0, which disqualifies them. Successive powers of (x + 1) look a lot more random.
+        let base = F2x::<L>::from_exponents(&[1, 0]);
+        let mut candidate = base;
+        loop {
+            if let Some(basis) = Self::try_from_element(degree, &candidate, &square) {
+                return basis;
+            }
+            candidate = mul(&candidate, &base);
+        }
+    }
+
+    /// Return None if the element is not normal
+    fn try_from_element(
+        degree: usize,
+        elem: &F2x<L>,
+        square: &impl Fn(&F2x<L>) -> F2x<L>,
+    ) -> Option<Self> {
+        let mut conjugates = Vec::with_capacity(degree);
+        let mut conjugate = *elem;
+        for _ in 0..degree {
+            conjugates.push(conjugate);
+            conjugate = square(&conjugate);
+        }
+
+        // Gauss-Jordan elimination on [conjugates | I], where row k is the k-th conjugate augmented
+        // with the unit vector e_k. Once the left half is reduced, row r expresses x^r as a sum of
+        // conjugates.
+        let mut rows = conjugates
+            .iter()
+            .enumerate()
+            .map(|(k, conjugate)| {
+                let mut unit = F2x::<L>::ZERO;
+                flip_bit(&mut unit, k);
+                (*conjugate, unit)
+            })
+            .collect::<Vec<(F2x<L>, F2x<L>)>>();
+        for col in 0..degree {
+            let pivot = (col..degree).find(|&r| rows[r].0.bit(col))?;
+            rows.swap(col, pivot);
+            let (pivot_left, pivot_right) = rows[col];
+            for (r, row) in rows.iter_mut().enumerate() {
+                if r != col && row.0.bit(col) {
+                    row.0 = row.0.xor(&pivot_left);
+                    row.1 = row.1.xor(&pivot_right);
+                }
+            }
+        }
+
+        let mut inverse = vec![F2x::<L>::ZERO; degree];
+        for (r, (_, combination)) in rows.iter().enumerate() {
+            for (k, row) in inverse.iter_mut().enumerate() {
+                if combination.bit(k) {
+                    flip_bit(row, r);
+                }
+            }
+        }
+
+        Some(Self {
+            degree,
+            conjugates,
+            inverse,
+        })
+    }
+
+    /// The normal element b in the polynomial basis
+    pub fn generator(&self) -> &F2x<L> {
+        &self.conjugates[0]
+    }
+
+    /// Convert polynomial-basis coordinates into normal-basis coordinates. In the output, the
+    /// coefficient of x^i is the coefficient of b^(2^i).
+    pub fn to_normal(&self, poly: &F2x<L>) -> F2x<L> {
+        let mut coords = F2x::<L>::ZERO;
+        self.inverse
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| and_parity(row, poly))
+            .for_each(|(i, _)| flip_bit(&mut coords, i));
+        coords
+    }
+
+    /// Convert normal-basis coordinates back into polynomial-basis coordinates
+    pub fn to_poly(&self, coords: &F2x<L>) -> F2x<L> {
+        self.conjugates
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| coords.bit(*i))
+            .fold(F2x::<L>::ZERO, |acc, (_, conjugate)| acc.xor(conjugate))
+    }
+
+    /// Raise the element encoded by the normal-basis coordinates to the power 2^k, which is a
+    /// cyclic rotation of the coordinates by k positions
+    pub fn rotate(&self, coords: &F2x<L>, k: usize) -> F2x<L> {
+        let k = k % self.degree;
+        if k == 0 {
+            return *coords;
+        }
+        let unused = F2x::<L>::BITS - self.degree;
+        let high = coords.shl(k).shl(unused).shr(unused);
+        let low = coords.shr(self.degree - k);
+        high.xor(&low)
+    }
+
+    /// Raise the polynomial-basis element to the power 2^k. The cost is that of two changes of
+    /// basis regardless of k, instead of k squarings.
+    pub fn frobenius(&self, poly: &F2x<L>, k: usize) -> F2x<L> {
+        self.to_poly(&self.rotate(&self.to_normal(poly), k))
+    }
+
+    /// The absolute trace Tr(a) = a + a^2 + ... + a^(2^(m-1)) of the element encoded by the
+    /// normal-basis coordinates. Every basis vector has trace 1, so the trace is the parity of the
+    /// coordinates. Return true if the trace is 1.
+    pub fn trace(&self, coords: &F2x<L>) -> bool {
+        and_parity(coords, &F2x::<L>::ZERO.not())
+    }
+
+    /// The half trace H(a) = sum of a^(2^(2i)) for i = 0, ..., (m - 1) / 2, in normal-basis
+    /// coordinates. Return None if m is even, where the half trace is not defined.
+    pub fn half_trace(&self, coords: &F2x<L>) -> Option<F2x<L>> {
+        if self.degree.is_multiple_of(2) {
+            return None;
+        }
+        let half_trace = (0..=((self.degree - 1) / 2)).fold(F2x::<L>::ZERO, |acc, i| {
+            acc.xor(&self.rotate(coords, 2 * i))
+        });
+        Some(half_trace)
+    }
+
+    /// Solve w^2 + w = c in normal-basis coordinates. Since squaring is a rotation, coordinate i of
+    /// the left-hand side is w[i - 1] + w[i], so w can be solved one coordinate at a time. The
+    /// other solution is w + 1. Return None if there is no solution, which is the case exactly
+    /// when Tr(c) = 1.
+    pub fn solve_artin_schreier(&self, coords: &F2x<L>) -> Option<F2x<L>> {
+        if self.trace(coords) {
+            return None;
+        }
+        let mut solution = F2x::<L>::ZERO;
+        let mut prev = false;
+        for i in 1..self.degree {
+            let current = prev ^ coords.bit(i);
+            if current {
+                flip_bit(&mut solution, i);
+            }
+            prev = current;
+        }
+        Some(solution)
+    }
+}
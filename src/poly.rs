@@ -1,18 +1,39 @@
-//! General polynomials
+//! Dense polynomials over any field
+//!
+//! `Poly` holds a fixed number of coefficients inline and never allocates; `PolyVec` holds a
+//! number of coefficients chosen at runtime on the heap. Both store coefficients in little-endian
+//! order, so that coeffs[0] is the constant term.
+#[cfg(feature = "alloc")]
+use crate::error::SssError;
 use crate::f2x::Degree;
-use crate::galoisfields::{FieldArithmetic, GF2p256};
+#[cfg(feature = "alloc")]
+use crate::galoisfields::batch_inv;
+use crate::galoisfields::FieldArithmetic;
+#[cfg(feature = "gf256")]
+use crate::galoisfields::GF2p256;
+#[cfg(feature = "alloc")]
+use crate::hedged::HmacDrbg;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
 use rand::Rng;
+use rand_core::CryptoRngCore;
+#[cfg(feature = "alloc")]
 use sha3::Digest;
+#[cfg(feature = "alloc")]
+use zeroize::Zeroize;
 
 /// The canonical representation of a polynomial using its coefficients
 /// Coefficients are organized in little-endian order: the value at lower index encodes the
 /// coefficient of a lower-power term; coeffs[0] encodes the constant term
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Poly<E: FieldArithmetic> {
+pub struct PolyVec<E: FieldArithmetic> {
     pub coeffs: Vec<E>,
 }
 
-impl<E: FieldArithmetic> Poly<E> {
+#[cfg(feature = "alloc")]
+impl<E: FieldArithmetic> PolyVec<E> {
     pub fn from_coeffs(coeffs: Vec<E>) -> Self {
         Self { coeffs }
     }
@@ -47,10 +68,16 @@ impl<E: FieldArithmetic> Poly<E> {
         output
     }
 
-    /// Convenient method for updating a hasher without needing to explicitly allocate memory
+    /// Feed the serialization of the polynomial to a hasher, one coefficient at a time so that
+    /// the whole serialization is never buffered. The buffer is wiped afterwards, since the
+    /// polynomial is usually secret
     pub fn update_hasher(&self, hasher: &mut impl Digest) {
-        let buf = vec![0u8; self.bytes()];
-        hasher.update(&buf);
+        let mut buf = vec![0u8; E::bytes()];
+        for coeff in self.coeffs.iter() {
+            coeff.write_be_bytes(&mut buf);
+            hasher.update(&buf);
+        }
+        buf.zeroize();
     }
 
     /// The length of the coefficient vector.
@@ -68,20 +95,50 @@ impl<E: FieldArithmetic> Poly<E> {
         Self { coeffs }
     }
 
-    /// Fill self with random elements from the finite field
+    /// Fill self with random elements from the finite field using `rand::thread_rng`
+    #[cfg(feature = "std")]
     pub fn fill_random(&mut self) {
-        self.coeffs.iter_mut().for_each(|coeff| {
-            *coeff = E::random();
-        });
+        self.fill_random_with_rng(&mut rand::thread_rng());
     }
 
     /// Fill self with random elements according to the input RNG
-    pub fn fill_random_with_rng(&mut self, rng: &mut impl Rng) {
+    pub fn fill_random_with_rng(&mut self, rng: &mut impl CryptoRngCore) {
         self.coeffs.iter_mut().for_each(|coeff| {
-            *coeff = E::random_with_rng(rng);
+            *coeff = E::random(rng);
         });
     }
 
+    /// Deal a polynomial of the specified capacity whose constant term is the secret. The other
+    /// coefficients are drawn from an `HmacDrbg` seeded with the secret, the evaluation points of
+    /// the participants, the nonce, and 32 bytes from the input RNG, so that they stay
+    /// unpredictable without the secret even if the RNG is broken. See `crate::hedged`. Will panic
+    /// if the capacity is zero
+    pub fn hedged_with_rng(
+        secret: &E,
+        capacity: usize,
+        participants: &[E],
+        nonce: &[u8],
+        rng: &mut impl CryptoRngCore,
+    ) -> Self {
+        let mut secret_bytes = vec![0u8; E::bytes()];
+        secret.write_be_bytes(&mut secret_bytes);
+        let mut participant_bytes = vec![0u8; participants.len() * E::bytes()];
+        participant_bytes
+            .chunks_mut(E::bytes())
+            .zip(participants)
+            .for_each(|(dst, x)| x.write_be_bytes(dst));
+        let mut entropy = [0u8; 32];
+        rng.fill(&mut entropy);
+
+        let mut drbg = HmacDrbg::new(&[&secret_bytes, &participant_bytes, nonce, &entropy]);
+        secret_bytes.zeroize();
+        let mut poly = Self::zero_with_capacity(capacity);
+        poly.fill_random_with_rng(&mut drbg);
+        poly.coeffs[0] = *secret;
+
+        poly
+    }
+
     /// Return true if self encodes a zero polynomial. An empty vector encodes a zero polynomial
     pub fn is_zero(&self) -> bool {
         for coeff in self.coeffs.iter() {
@@ -89,7 +146,7 @@ impl<E: FieldArithmetic> Poly<E> {
                 return false;
             }
         }
-        return true;
+        true
     }
 
     /// The degree of a polynomial is the power of the highest-power term with a non-zero
@@ -102,16 +159,10 @@ impl<E: FieldArithmetic> Poly<E> {
             .coeffs
             .iter()
             .enumerate()
-            .filter_map(|(i, coeff)| {
-                if coeff.is_zero() {
-                    return None;
-                } else {
-                    return Some(i);
-                }
-            })
+            .filter_map(|(i, coeff)| if coeff.is_zero() { None } else { Some(i) })
             .max()
             .expect("Non-zero polynomial has no non-zero coefficient");
-        return Degree::NonNegative(degree);
+        Degree::NonNegative(degree)
     }
 
     pub fn add(&self, rhs: &Self) -> Self {
@@ -136,7 +187,7 @@ impl<E: FieldArithmetic> Poly<E> {
             .coeffs
             .iter()
             .zip(rhs.coeffs.iter())
-            .map(|(a, b)| a.modmul(b))
+            .map(|(a, b)| a.modsub(b))
             .collect::<Vec<E>>();
 
         Self::from_coeffs(sum_coeffs)
@@ -156,7 +207,7 @@ impl<E: FieldArithmetic> Poly<E> {
                     if (i + j) >= self.capacity() {
                         panic!("attempt to multiply polynomials with overflow");
                     } else {
-                        prod.coeffs[i + j] = prod.coeffs[i + j].modadd(&c);
+                        prod.coeffs[i + j].modadd_assign(&c);
                     }
                 }
             }
@@ -176,17 +227,257 @@ impl<E: FieldArithmetic> Poly<E> {
         Self::from_coeffs(coeffs)
     }
 
-    /// Evaluate the polynomial at the given points
-    pub fn evaluate(&self, at: &E) -> E {
-        let mut acc = E::zero();
-        let mut indeterminate = E::one();
+    /// The formal derivative, with the capacity of self: the coefficient of x^i is (i + 1) times
+    /// the coefficient of x^(i + 1), where the integer factor is reduced in the coefficient field
+    pub fn derivative(&self) -> Self {
+        let mut derivative = Self::zero_with_capacity(self.capacity());
+        derivative_into(&self.coeffs, &mut derivative.coeffs);
+        derivative
+    }
+
+    /// Euclidean long division, returning (quotient, remainder), both with the capacity of self.
+    /// Will panic if rhs is zero or the capacities do not match
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        if self.capacity() != rhs.capacity() {
+            panic!("Polynomial capacities do not match");
+        }
+        let mut quot = Self::zero_with_capacity(self.capacity());
+        let mut rem = self.clone();
+        div_rem_into(&mut rem.coeffs, &rhs.coeffs, &mut quot.coeffs);
+
+        (quot, rem)
+    }
+
+    /// Use [Extended Euclid's algorithm](https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm)
+    /// to compute (s, t, d) such that s * lhs + t * rhs = d and d is a GCD of lhs and rhs. Unlike
+    /// `gcd`, d is not made monic. Will panic if the capacities do not match
+    pub fn xgcd(lhs: &Self, rhs: &Self) -> (Self, Self, Self) {
+        let (mut rr, mut r) = (lhs.clone(), rhs.clone());
+        let ((mut ss, mut s), (mut tt, mut t)) = Self::xgcd_init(lhs.capacity());
+
+        while !r.is_zero() {
+            let (quot, rem) = rr.div_rem(&r);
+            (rr, r) = (r, rem);
+            (ss, s) = (s.clone(), ss.sub(&quot.mul(&s)));
+            (tt, t) = (t.clone(), tt.sub(&quot.mul(&t)));
+        }
+
+        (ss, tt, rr)
+    }
+
+    /// Run the Extended Euclidean Algorithm on lhs and rhs only until the remainder has degree less
+    /// than the input degree, and return (s, t, r) such that s * lhs + t * rhs = r. See
+    /// `F2x::partial_xgcd`. Will panic if the capacities do not match
+    pub fn partial_xgcd(lhs: &Self, rhs: &Self, degree: usize) -> (Self, Self, Self) {
+        let (mut rr, mut r) = (lhs.clone(), rhs.clone());
+        let ((mut ss, mut s), (mut tt, mut t)) = Self::xgcd_init(lhs.capacity());
+
+        while rr.degree() >= Degree::NonNegative(degree) {
+            if r.is_zero() {
+                return (s, t, r);
+            }
+            let (quot, rem) = rr.div_rem(&r);
+            (rr, r) = (r, rem);
+            (ss, s) = (s.clone(), ss.sub(&quot.mul(&s)));
+            (tt, t) = (t.clone(), tt.sub(&quot.mul(&t)));
+        }
+
+        (ss, tt, rr)
+    }
+
+    /// The initial Bezout coefficients ((1, 0), (0, 1)) of the Extended Euclidean Algorithm
+    fn xgcd_init(capacity: usize) -> ((Self, Self), (Self, Self)) {
+        let zero = Self::zero_with_capacity(capacity);
+        let mut one = zero.clone();
+        if let Some(constant) = one.coeffs.first_mut() {
+            *constant = E::one();
+        }
+        ((one.clone(), zero.clone()), (zero, one))
+    }
+
+    /// The inverse of self modulo x^n as a polynomial of capacity n, or None if the constant term
+    /// is zero. Newton's iteration g <- g - g(fg - 1) doubles the number of correct coefficients
+    /// each round, so the cost is a constant number of multiplications at the full precision. In
+    /// characteristic 2 the iteration is simply g <- fg^2.
+    pub fn inv_mod_xn(&self, n: usize) -> Option<Self> {
+        if n == 0 {
+            return Some(Self::zero_with_capacity(0));
+        }
+        let mut inv = vec![self.coeffs.first()?.modinv()?];
+        let mut precision = 1;
+        while precision < n {
+            precision = (2 * precision).min(n);
+            let mut error = mul_truncated(&self.coeffs, &inv, precision);
+            error[0].modsub_assign(&E::one());
+            let correction = mul_truncated(&inv, &error, precision);
+            inv.resize(precision, E::zero());
+            inv.iter_mut()
+                .zip(correction.iter())
+                .for_each(|(coeff, correction)| coeff.modsub_assign(correction));
+        }
+
+        Some(Self::from_coeffs(inv))
+    }
+
+    /// Use [Euclid's algorithm](https://en.wikipedia.org/wiki/Euclidean_algorithm) to compute the
+    /// monic polynomial of the highest degree that divides both lhs and rhs. The GCD of two zero
+    /// polynomials is zero.
+    pub fn gcd(lhs: &Self, rhs: &Self) -> Self {
+        let (mut a, mut b) = (lhs.clone(), rhs.clone());
+
+        while !b.is_zero() {
+            let (_, rem) = a.div_rem(&b);
+            (a, b) = (b, rem);
+        }
+        make_monic(&mut a.coeffs);
+
+        a
+    }
+
+    /// The [resultant](https://en.wikipedia.org/wiki/Resultant) of lhs and rhs, which is zero if
+    /// and only if they have a common factor of positive degree or one of them is zero. Computed
+    /// with the Euclidean algorithm: for lhs = q * rhs + r, Res(lhs, rhs) is
+    /// (-1)^(deg lhs * deg rhs) * lc(rhs)^(deg lhs - deg r) * Res(rhs, r). The capacities must match
+    pub fn resultant(lhs: &Self, rhs: &Self) -> E {
+        let (mut a, mut b) = (lhs.clone(), rhs.clone());
+        let mut res = E::one();
+
+        loop {
+            let (a_degree, b_degree) = match (a.degree(), b.degree()) {
+                (Degree::NonNegative(a_degree), Degree::NonNegative(b_degree)) => {
+                    (a_degree, b_degree)
+                }
+                _ => return E::zero(),
+            };
+            let b_lead = b.coeffs[b_degree];
+            if b_degree == 0 {
+                return res.modmul(&b_lead.modexp(a_degree as u64));
+            }
+            let (_, rem) = a.div_rem(&b);
+            let rem_degree = match rem.degree() {
+                Degree::NonNegative(rem_degree) => rem_degree,
+                Degree::NegativeInfinity => return E::zero(),
+            };
+            if (a_degree * b_degree) % 2 == 1 {
+                res = E::zero().modsub(&res);
+            }
+            res.modmul_assign(&b_lead.modexp((a_degree - rem_degree) as u64));
+            (a, b) = (b, rem);
+        }
+    }
+
+    /// The discriminant (-1)^(n(n-1)/2) * Res(f, f') / lc(f) of a polynomial f of degree n >= 1,
+    /// where f' is taken with formal degree n - 1. It is zero if and only if f has a repeated root
+    /// in some extension field. Will panic if self is constant
+    pub fn discriminant(&self) -> E {
+        let degree = match self.degree() {
+            Degree::NonNegative(degree) if degree > 0 => degree,
+            _ => panic!("the discriminant of a constant is not defined"),
+        };
+        let derivative = self.derivative();
+        let derivative_degree = match derivative.degree() {
+            Degree::NonNegative(derivative_degree) => derivative_degree,
+            Degree::NegativeInfinity => return E::zero(),
+        };
+        let lead = self.coeffs[degree];
+        // A derivative whose degree falls below n - 1 scales the resultant by a power of lc(f)
+        let mut disc = Self::resultant(self, &derivative)
+            .modmul(&lead.modexp((degree - 1 - derivative_degree) as u64))
+            .modmul(&lead.modinv().expect("non-zero elements are invertible"));
+        if (degree * (degree - 1) / 2) % 2 == 1 {
+            disc = E::zero().modsub(&disc);
+        }
+        disc
+    }
 
-        for i in 0..self.capacity() {
-            acc = acc.modadd(&self.coeffs[i].modmul(&indeterminate));
-            indeterminate = indeterminate.modmul(at);
+    /// The composition self(inner(x)), computed with Horner's rule and with the capacity of self.
+    /// Will panic if the capacities do not match or the result does not fit
+    pub fn compose(&self, inner: &Self) -> Self {
+        if self.capacity() != inner.capacity() {
+            panic!("Polynomial capacities do not match");
+        }
+        let mut composed = Self::zero_with_capacity(self.capacity());
+        for coeff in self.coeffs.iter().rev() {
+            composed = composed.mul(inner);
+            if let Some(constant) = composed.coeffs.first_mut() {
+                constant.modadd_assign(coeff);
+            }
         }
+        composed
+    }
+
+    /// The composition self(inner(x)) reduced modulo the modulus, with the capacity of self, using
+    /// the baby-step giant-step method of Brent and Kung: for k about the square root of the number
+    /// of coefficients, the powers inner^0, ..., inner^k are computed once, each block of k
+    /// coefficients of self becomes a linear combination of them, and the blocks are combined by
+    /// Horner's rule in inner^k. This takes about 2 sqrt(deg self) modular multiplications instead
+    /// of deg self. Will panic if the capacities do not match or the modulus is zero
+    pub fn compose_mod(&self, inner: &Self, modulus: &Self) -> Self {
+        if self.capacity() != inner.capacity() || self.capacity() != modulus.capacity() {
+            panic!("Polynomial capacities do not match");
+        }
+        let modulus_degree = match modulus.degree() {
+            Degree::NegativeInfinity => panic!("attempt to divide by zero"),
+            Degree::NonNegative(degree) => degree,
+        };
+        let terms = match self.degree() {
+            Degree::NonNegative(degree) if modulus_degree > 0 => degree + 1,
+            _ => return Self::zero_with_capacity(self.capacity()),
+        };
+        // Products of two remainders have degree below 2 deg(modulus)
+        let wide = 2 * modulus_degree;
+        let (_, inner) = inner.div_rem(modulus);
+        let inner = resize(&inner, wide);
+        let modulus = resize(modulus, wide);
+        let mul_mod = |lhs: &Self, rhs: &Self| lhs.mul(rhs).div_rem(&modulus).1;
+
+        let step = (1..=terms).find(|k| k * k >= terms).unwrap_or(1);
+        let mut powers = vec![resize(&Self::from_coeffs(vec![E::one()]), wide)];
+        for i in 1..=step {
+            let power = mul_mod(&powers[i - 1], &inner);
+            powers.push(power);
+        }
+        let giant_step = powers[step].clone();
+
+        let mut composed = Self::zero_with_capacity(wide);
+        for block in self.coeffs[..terms].chunks(step).rev() {
+            composed = mul_mod(&composed, &giant_step);
+            for (coeff, power) in block.iter().zip(powers.iter()) {
+                composed = composed.add(&power.mul_coeff(coeff));
+            }
+        }
+
+        resize(&composed, self.capacity())
+    }
+
+    /// Evaluate the polynomial at the given point using Horner's rule
+    pub fn evaluate(&self, at: &E) -> E {
+        horner(&self.coeffs, at)
+    }
+
+    /// Evaluate the polynomial at each of the given points in a single pass over the coefficients.
+    /// See `horner_many`
+    pub fn evaluate_many(&self, xs: &[E]) -> Vec<E> {
+        let mut ys = vec![E::zero(); xs.len()];
+        horner_many(&self.coeffs, xs, &mut ys);
+        ys
+    }
 
-        return acc;
+    /// Like `interpolate`, but return an error instead of an undefined result when two points share
+    /// an x-coordinate, or when there are more points than the capacity
+    pub fn try_interpolate(points: &[(E, E)], capacity: usize) -> Result<Self, SssError> {
+        if points.len() > capacity {
+            return Err(SssError::InvalidThreshold {
+                threshold: capacity,
+                shares: points.len(),
+            });
+        }
+        for (i, (alpha_i, _)) in points.iter().enumerate() {
+            if points[..i].iter().any(|(alpha_j, _)| alpha_j == alpha_i) {
+                return Err(SssError::DuplicateShareIndex);
+            }
+        }
+        Ok(Self::interpolate(points, capacity))
     }
 
     /// Compute the Lagrange polynomial on the points (x, f(x)).
@@ -196,36 +487,577 @@ impl<E: FieldArithmetic> Poly<E> {
         if points.len() > capacity {
             panic!("Cannot interpolate more points than capacity");
         }
+        // The denominator of the i-th basis polynomial is the product of (alpha_i - alpha_j); all
+        // of them are inverted at once
+        let mut denominators = points
+            .iter()
+            .map(|(alpha_i, _)| {
+                points
+                    .iter()
+                    .filter(|(alpha_j, _)| alpha_j != alpha_i)
+                    .fold(E::one(), |acc, (alpha_j, _)| {
+                        acc.modmul(&alpha_i.modsub(alpha_j))
+                    })
+            })
+            .collect::<Vec<E>>();
+        batch_inv(&mut denominators);
         let mut lagrange = Self::zero_with_capacity(capacity);
 
-        for (alpha_i, r) in points {
-            let mut basis = Self::zero_with_capacity(capacity);
+        let mut basis = Self::zero_with_capacity(capacity);
+
+        for ((alpha_i, r), denominator) in points.iter().zip(denominators.iter()) {
+            basis.coeffs.fill(E::zero());
             basis.coeffs[0] = E::one();
 
             for (alpha_j, _) in points {
                 if alpha_j != alpha_i {
-                    let mut factor = Self::zero_with_capacity(capacity);
-                    // factor is (x - alpha_j)/(alpha_i - alpha_j)
-                    factor.coeffs[0] = *alpha_j;
-                    factor.coeffs[1] = E::one();
-                    factor = factor.mul_coeff(
-                        &alpha_i
-                            .modsub(alpha_j)
-                            .modinv()
-                            .expect("alpha_i - alpha_j should be non_zero!"),
-                    );
-                    basis = basis.mul(&factor);
+                    // Multiply by the factor (x - alpha_j) in place, from the highest power down
+                    for k in (0..capacity).rev() {
+                        let scaled = basis.coeffs[k].modmul(alpha_j);
+                        let lower = if k > 0 {
+                            basis.coeffs[k - 1]
+                        } else {
+                            E::zero()
+                        };
+                        basis.coeffs[k] = lower.modsub(&scaled);
+                    }
                 }
             }
 
-            lagrange = lagrange.add(&basis.mul_coeff(r));
+            let scale = denominator.modmul(r);
+            for (coeff, basis_coeff) in lagrange.coeffs.iter_mut().zip(basis.coeffs.iter()) {
+                coeff.modadd_assign(&basis_coeff.modmul(&scale));
+            }
         }
 
         lagrange
     }
 }
 
+/// Evaluate the polynomial with the input coefficients, constant term first, using Horner's rule
+pub fn horner<F: FieldArithmetic>(coeffs: &[F], at: &F) -> F {
+    coeffs
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coeff| acc.modmul(at).modadd(coeff))
+}
+
+/// Write the formal derivative of the polynomial with coefficients `coeffs` into `dst`, which must
+/// be as long as `coeffs`; the highest coefficient of `dst` is always zero
+fn derivative_into<F: FieldArithmetic>(coeffs: &[F], dst: &mut [F]) {
+    dst.fill(F::zero());
+    for (i, coeff) in coeffs.iter().enumerate().skip(1) {
+        dst[i - 1] = mul_integer(coeff, i);
+    }
+}
+
+/// Add elem to itself n times, by doubling and adding, so that the integer n is reduced in the
+/// characteristic of the field; in characteristic 2 this keeps elem for odd n and zeroes it for
+/// even n
+fn mul_integer<F: FieldArithmetic>(elem: &F, n: usize) -> F {
+    let mut prod = F::zero();
+    for i in (0..usize::BITS - n.leading_zeros()).rev() {
+        prod = prod.modadd(&prod);
+        if (n >> i) & 1 == 1 {
+            prod = prod.modadd(elem);
+        }
+    }
+    prod
+}
+
+/// Copy the polynomial into a new capacity. Will panic if a non-zero coefficient does not fit
+#[cfg(feature = "alloc")]
+pub(crate) fn resize<F: FieldArithmetic>(poly: &PolyVec<F>, capacity: usize) -> PolyVec<F> {
+    if poly
+        .coeffs
+        .iter()
+        .skip(capacity)
+        .any(|coeff| !coeff.is_zero())
+    {
+        panic!("polynomial does not fit in the capacity");
+    }
+    let mut resized = PolyVec::zero_with_capacity(capacity);
+    resized
+        .coeffs
+        .iter_mut()
+        .zip(poly.coeffs.iter())
+        .for_each(|(dst, coeff)| *dst = *coeff);
+    resized
+}
+
+/// The first n coefficients of the product of two polynomials, zero-padded to length n
+#[cfg(feature = "alloc")]
+fn mul_truncated<F: FieldArithmetic>(lhs: &[F], rhs: &[F], n: usize) -> Vec<F> {
+    let mut prod = vec![F::zero(); n];
+    for (i, a) in lhs.iter().enumerate().take(n) {
+        for (j, b) in rhs.iter().enumerate().take(n - i) {
+            prod[i + j].modadd_assign(&a.modmul(b));
+        }
+    }
+    prod
+}
+
+/// Divide the polynomial in `rem` by `divisor` in place: the remainder is left in `rem` and the
+/// quotient is written into `quot`, which must be as long as `rem`. Will panic if the divisor is
+/// zero
+fn div_rem_into<F: FieldArithmetic>(rem: &mut [F], divisor: &[F], quot: &mut [F]) {
+    let divisor_degree = divisor
+        .iter()
+        .rposition(|coeff| !coeff.is_zero())
+        .expect("attempt to divide by zero");
+    let lead_inv = divisor[divisor_degree]
+        .modinv()
+        .expect("non-zero elements are invertible");
+    quot.fill(F::zero());
+
+    for k in (divisor_degree..rem.len()).rev() {
+        if rem[k].is_zero() {
+            continue;
+        }
+        // Cancel the term of power k with a multiple of the divisor shifted by k - divisor_degree
+        let factor = rem[k].modmul(&lead_inv);
+        let shift = k - divisor_degree;
+        quot[shift] = factor;
+        for (coeff, divisor_coeff) in rem[shift..=k].iter_mut().zip(divisor) {
+            coeff.modsub_assign(&divisor_coeff.modmul(&factor));
+        }
+    }
+}
+
+/// Scale the coefficients so that the highest-power non-zero coefficient is one. The zero
+/// polynomial is left unchanged
+fn make_monic<F: FieldArithmetic>(coeffs: &mut [F]) {
+    let lead_inv = match coeffs.iter().rfind(|coeff| !coeff.is_zero()) {
+        Some(lead) => lead.modinv().expect("non-zero elements are invertible"),
+        None => return,
+    };
+    coeffs
+        .iter_mut()
+        .for_each(|coeff| *coeff = coeff.modmul(&lead_inv));
+}
+
+/// Evaluate the polynomial with the input coefficients at every point of `xs` into `ys`. All
+/// points advance through Horner's rule together, so each coefficient is read once instead of
+/// once per point. Will panic if `xs` and `ys` have different lengths
+pub fn horner_many<F: FieldArithmetic>(coeffs: &[F], xs: &[F], ys: &mut [F]) {
+    if xs.len() != ys.len() {
+        panic!("operand lengths do not match");
+    }
+    ys.fill(F::zero());
+    for coeff in coeffs.iter().rev() {
+        for (y, x) in ys.iter_mut().zip(xs) {
+            *y = y.modmul(x).modadd(coeff);
+        }
+    }
+}
+
+/// Fail with `SssError::DuplicateShareIndex` if two x-coordinates are equal, which makes a
+/// Vandermonde matrix singular
+#[cfg(feature = "alloc")]
+fn check_distinct<F: FieldArithmetic>(xs: &[F]) -> Result<(), SssError> {
+    for (i, x) in xs.iter().enumerate() {
+        if xs[..i].contains(x) {
+            return Err(SssError::DuplicateShareIndex);
+        }
+    }
+    Ok(())
+}
+
+/// The inverses of x_i - x_(i - k - 1) for i = k + 1, ..., n - 1, which are the divisors of the
+/// k-th round of both Bjorck-Pereyra algorithms
+#[cfg(feature = "alloc")]
+fn vandermonde_divisors<F: FieldArithmetic>(xs: &[F], k: usize) -> Vec<F> {
+    let mut divisors = (k + 1..xs.len())
+        .map(|i| xs[i].modsub(&xs[i - k - 1]))
+        .collect::<Vec<F>>();
+    batch_inv(&mut divisors);
+    divisors
+}
+
+/// Solve the Vandermonde system sum_j c_j x_i^j = y_i for the coefficients c of the polynomial
+/// through the points (x_i, y_i), using the Bjorck-Pereyra algorithm (Golub and Van Loan,
+/// algorithm 4.6.1): Newton divided differences followed by expansion into coefficients, in
+/// O(n^2) operations and exact field arithmetic. Fails with `SssError::DuplicateShareIndex` if
+/// the x-coordinates are not distinct. Will panic if `xs` and `ys` have different lengths
+#[cfg(feature = "alloc")]
+pub fn solve_vandermonde<F: FieldArithmetic>(xs: &[F], ys: &[F]) -> Result<Vec<F>, SssError> {
+    if xs.len() != ys.len() {
+        panic!("operand lengths do not match");
+    }
+    check_distinct(xs)?;
+    let n = xs.len();
+    let mut coeffs = ys.to_vec();
+
+    for k in 0..n.saturating_sub(1) {
+        let divisors = vandermonde_divisors(xs, k);
+        for i in (k + 1..n).rev() {
+            let diff = coeffs[i].modsub(&coeffs[i - 1]);
+            coeffs[i] = diff.modmul(&divisors[i - k - 1]);
+        }
+    }
+    for k in (0..n.saturating_sub(1)).rev() {
+        for i in k..n - 1 {
+            let next = coeffs[i + 1].modmul(&xs[k]);
+            coeffs[i].modsub_assign(&next);
+        }
+    }
+
+    Ok(coeffs)
+}
+
+/// Solve the transposed Vandermonde system sum_i w_i x_i^j = b_j for j = 0, ..., n - 1, whose
+/// matrix has the powers of x_i in column i instead of row i, using the Bjorck-Pereyra algorithm
+/// (Golub and Van Loan, algorithm 4.6.2) in O(n^2) operations. For b = (1, t, ..., t^(n - 1)),
+/// the solution is the Lagrange basis at t: w_i = L_i(t), so that sum_i w_i y_i is the value at t
+/// of the polynomial through the points (x_i, y_i). Fails with `SssError::DuplicateShareIndex` if
+/// the x-coordinates are not distinct. Will panic if `xs` and `bs` have different lengths
+#[cfg(feature = "alloc")]
+pub fn solve_vandermonde_transposed<F: FieldArithmetic>(
+    xs: &[F],
+    bs: &[F],
+) -> Result<Vec<F>, SssError> {
+    if xs.len() != bs.len() {
+        panic!("operand lengths do not match");
+    }
+    check_distinct(xs)?;
+    let n = xs.len();
+    let mut weights = bs.to_vec();
+
+    for (k, x_k) in xs.iter().enumerate().take(n.saturating_sub(1)) {
+        for i in (k + 1..n).rev() {
+            let prev = weights[i - 1].modmul(x_k);
+            weights[i].modsub_assign(&prev);
+        }
+    }
+    for k in (0..n.saturating_sub(1)).rev() {
+        let divisors = vandermonde_divisors(xs, k);
+        for i in k + 1..n {
+            weights[i].modmul_assign(&divisors[i - k - 1]);
+        }
+        for i in k..n - 1 {
+            let next = weights[i + 1];
+            weights[i].modsub_assign(&next);
+        }
+    }
+
+    Ok(weights)
+}
+
+/// A polynomial with N coefficients stored inline. Like `PolyVec`, the number of coefficients is
+/// fixed, and multiplication panics if the product does not fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Poly<F: FieldArithmetic, const N: usize> {
+    pub coeffs: [F; N],
+}
+
+impl<F: FieldArithmetic, const N: usize> Poly<F, N> {
+    pub fn from_coeffs(coeffs: [F; N]) -> Self {
+        Self { coeffs }
+    }
+
+    pub fn zero() -> Self {
+        Self::from_coeffs([F::zero(); N])
+    }
+
+    /// Sample every coefficient with the input RNG
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self::from_coeffs(core::array::from_fn(|_| F::random(rng)))
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.iter().all(|coeff| coeff.is_zero())
+    }
+
+    /// The power of the highest-power term with a non-zero coefficient; the degree of 0 is minus
+    /// infinity
+    pub fn degree(&self) -> Degree {
+        match self.coeffs.iter().rposition(|coeff| !coeff.is_zero()) {
+            Some(degree) => Degree::NonNegative(degree),
+            None => Degree::NegativeInfinity,
+        }
+    }
+
+    pub fn add(&self, rhs: &Self) -> Self {
+        Self::from_coeffs(core::array::from_fn(|i| {
+            self.coeffs[i].modadd(&rhs.coeffs[i])
+        }))
+    }
+
+    pub fn sub(&self, rhs: &Self) -> Self {
+        Self::from_coeffs(core::array::from_fn(|i| {
+            self.coeffs[i].modsub(&rhs.coeffs[i])
+        }))
+    }
+
+    /// School-book multiplication. Return None if the product has a non-zero term of power N or
+    /// higher
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let mut prod = Self::zero();
+
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                let c = a.modmul(b);
+                if !c.is_zero() {
+                    prod.coeffs.get_mut(i + j)?.modadd_assign(&c);
+                }
+            }
+        }
+
+        Some(prod)
+    }
+
+    /// School-book multiplication. Will panic if the product will overflow.
+    pub fn mul(&self, rhs: &Self) -> Self {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply polynomials with overflow")
+    }
+
+    /// Multiplication by a single scalar
+    pub fn mul_coeff(&self, rhs: &F) -> Self {
+        Self::from_coeffs(self.coeffs.map(|coeff| coeff.modmul(rhs)))
+    }
+
+    /// Addition of a single scalar, which only changes the constant term. Will panic if N is 0
+    pub fn add_coeff(&self, rhs: &F) -> Self {
+        let mut sum = *self;
+        sum.coeffs[0].modadd_assign(rhs);
+        sum
+    }
+
+    /// The formal derivative; see `PolyVec::derivative`
+    pub fn derivative(&self) -> Self {
+        let mut derivative = Self::zero();
+        derivative_into(&self.coeffs, &mut derivative.coeffs);
+        derivative
+    }
+
+    /// Euclidean long division, returning (quotient, remainder). Will panic if rhs is zero
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let mut quot = Self::zero();
+        let mut rem = *self;
+        div_rem_into(&mut rem.coeffs, &rhs.coeffs, &mut quot.coeffs);
+
+        (quot, rem)
+    }
+
+    /// The monic greatest common divisor of lhs and rhs; see `PolyVec::gcd`
+    pub fn gcd(lhs: &Self, rhs: &Self) -> Self {
+        let (mut a, mut b) = (*lhs, *rhs);
+
+        while !b.is_zero() {
+            let (_, rem) = a.div_rem(&b);
+            (a, b) = (b, rem);
+        }
+        make_monic(&mut a.coeffs);
+
+        a
+    }
+
+    /// Evaluate the polynomial at the given point
+    pub fn evaluate(&self, at: &F) -> F {
+        horner(&self.coeffs, at)
+    }
+
+    /// Evaluate the polynomial at each of the given points. See `horner_many`
+    pub fn evaluate_many<const M: usize>(&self, xs: &[F; M]) -> [F; M] {
+        let mut ys = [F::zero(); M];
+        horner_many(&self.coeffs, xs, &mut ys);
+        ys
+    }
+}
+
+impl<F: FieldArithmetic, const N: usize> Default for Poly<F, N> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: FieldArithmetic, const N: usize> From<Poly<F, N>> for PolyVec<F> {
+    fn from(poly: Poly<F, N>) -> Self {
+        Self::from_coeffs(poly.coeffs.to_vec())
+    }
+}
+
+/// Interpolation in Newton's form, p(x) = c_0 + c_1 (x - x_0) + c_2 (x - x_0)(x - x_1) + ...,
+/// where c_k is the divided difference of the first k + 1 points. Adding a point appends one term
+/// and leaves the earlier ones unchanged, so `push` costs O(n) operations instead of redoing the
+/// whole O(n^2) interpolation; after n points, the polynomial is the unique one of degree below n
+/// through all of them.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewtonInterpolator<F: FieldArithmetic> {
+    xs: Vec<F>,
+    /// coeffs[k] is the divided difference over xs[0..=k]
+    coeffs: Vec<F>,
+    /// diffs[j] is the divided difference over xs[j..], i.e. the last row of the table
+    diffs: Vec<F>,
+}
+
+#[cfg(feature = "alloc")]
+impl<F: FieldArithmetic> NewtonInterpolator<F> {
+    pub fn new() -> Self {
+        Self {
+            xs: vec![],
+            coeffs: vec![],
+            diffs: vec![],
+        }
+    }
+
+    /// The number of points absorbed so far
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// Absorb the point (x, y). Fails with `SssError::DuplicateShareIndex` if x has already been
+    /// absorbed
+    pub fn push(&mut self, x: F, y: F) -> Result<(), SssError> {
+        if self.xs.contains(&x) {
+            return Err(SssError::DuplicateShareIndex);
+        }
+        // Extend each divided difference over xs[j..] by the new point, from the shortest up
+        let mut diff = y;
+        for (x_j, diff_j) in self.xs.iter().zip(self.diffs.iter_mut()).rev() {
+            diff = diff
+                .modsub(diff_j)
+                .modmul(&x.modsub(x_j).modinv().expect("x-coordinates are distinct"));
+            *diff_j = diff;
+        }
+        self.diffs.push(y);
+        self.xs.push(x);
+        self.coeffs.push(diff);
+
+        Ok(())
+    }
+
+    /// Evaluate the interpolating polynomial at the given point with Horner's rule on the Newton
+    /// form. The interpolating polynomial of no points is zero
+    pub fn evaluate(&self, at: &F) -> F {
+        self.xs
+            .iter()
+            .zip(self.coeffs.iter())
+            .rev()
+            .fold(F::zero(), |acc, (x, coeff)| {
+                acc.modmul(&at.modsub(x)).modadd(coeff)
+            })
+    }
+
+    /// Expand the interpolating polynomial into coefficients. Will panic if there are more points
+    /// than the capacity
+    pub fn to_poly(&self, capacity: usize) -> PolyVec<F> {
+        if self.len() > capacity {
+            panic!("Cannot interpolate more points than capacity");
+        }
+        let mut poly = PolyVec::zero_with_capacity(capacity);
+        for (x, coeff) in self.xs.iter().zip(self.coeffs.iter()).rev() {
+            // Multiply by (X - x) in place, from the highest power down, then add the coefficient
+            for k in (0..capacity).rev() {
+                let lower = if k > 0 { poly.coeffs[k - 1] } else { F::zero() };
+                poly.coeffs[k] = lower.modsub(&poly.coeffs[k].modmul(x));
+            }
+            poly.coeffs[0].modadd_assign(coeff);
+        }
+        poly
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: FieldArithmetic> Default for NewtonInterpolator<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The weights of the barycentric form of Lagrange interpolation over a fixed set of distinct
+/// x-coordinates: w_j is the inverse of the product of (x_j - x_k) over k != j. For any y-values,
+/// the interpolating polynomial at t is l(t) * sum(w_j y_j / (t - x_j)), where l(t) is the product
+/// of (t - x_k). Computing the weights costs O(n^2) operations once; afterwards each evaluation,
+/// at any point and for any y-values, costs O(n) operations and a single inversion.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BarycentricWeights<F: FieldArithmetic> {
+    xs: Vec<F>,
+    weights: Vec<F>,
+}
+
+#[cfg(feature = "alloc")]
+impl<F: FieldArithmetic> BarycentricWeights<F> {
+    /// Fails with `SssError::DuplicateShareIndex` if two x-coordinates are equal
+    pub fn new(xs: &[F]) -> Result<Self, SssError> {
+        for (i, x) in xs.iter().enumerate() {
+            if xs[..i].contains(x) {
+                return Err(SssError::DuplicateShareIndex);
+            }
+        }
+        let mut weights = xs
+            .iter()
+            .enumerate()
+            .map(|(j, x_j)| {
+                xs.iter()
+                    .enumerate()
+                    .filter(|(k, _)| *k != j)
+                    .fold(F::one(), |acc, (_, x_k)| acc.modmul(&x_j.modsub(x_k)))
+            })
+            .collect::<Vec<F>>();
+        batch_inv(&mut weights);
+
+        Ok(Self {
+            xs: xs.to_vec(),
+            weights,
+        })
+    }
+
+    pub fn xs(&self) -> &[F] {
+        &self.xs
+    }
+
+    pub fn weights(&self) -> &[F] {
+        &self.weights
+    }
+
+    /// The value of every Lagrange basis polynomial at the given point, so that the
+    /// interpolating polynomial of any y-values evaluates to sum(basis[j] * y_j) there. Computing
+    /// this once lets many sets of y-values over the same x-coordinates be evaluated at the same
+    /// point, e.g. many secrets dealt to the same participants.
+    pub fn basis_at(&self, at: &F) -> Vec<F> {
+        if let Some(j) = self.xs.iter().position(|x| x == at) {
+            let mut basis = vec![F::zero(); self.xs.len()];
+            basis[j] = F::one();
+            return basis;
+        }
+        let mut basis = self.xs.iter().map(|x| at.modsub(x)).collect::<Vec<F>>();
+        let node = basis.iter().fold(F::one(), |acc, diff| acc.modmul(diff));
+        batch_inv(&mut basis);
+        basis
+            .iter_mut()
+            .zip(self.weights.iter())
+            .for_each(|(basis, weight)| *basis = basis.modmul(weight).modmul(&node));
+        basis
+    }
+
+    /// Evaluate the polynomial through (xs[j], ys[j]) at the given point. Will panic if there are
+    /// not as many y-values as x-coordinates
+    pub fn evaluate(&self, ys: &[F], at: &F) -> F {
+        if ys.len() != self.xs.len() {
+            panic!("operand lengths do not match");
+        }
+        self.basis_at(at)
+            .iter()
+            .zip(ys)
+            .fold(F::zero(), |acc, (basis, y)| acc.modadd(&basis.modmul(y)))
+    }
+}
+
 /// A point on the polynomial is represented by two field elements
+
 #[derive(Debug)]
 pub struct PolyPoint<E> {
     pub x: E,
@@ -238,18 +1070,54 @@ impl<E: FieldArithmetic> PolyPoint<E> {
     }
 }
 
-pub type Poly256 = Poly<GF2p256>;
+#[cfg(feature = "alloc")]
+impl<E: FieldArithmetic + zeroize::Zeroize> zeroize::Zeroize for PolyVec<E> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.coeffs);
+    }
+}
+
+impl<F: FieldArithmetic + zeroize::Zeroize, const N: usize> zeroize::Zeroize for Poly<F, N> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.coeffs);
+    }
+}
+
+impl<E: zeroize::Zeroize> zeroize::Zeroize for PolyPoint<E> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.x);
+        zeroize::Zeroize::zeroize(&mut self.fx);
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "gf256"))]
+pub type Poly256 = PolyVec<GF2p256>;
+#[cfg(feature = "gf256")]
 pub type Poly256Point = PolyPoint<GF2p256>;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
-    use super::{Poly, Poly256};
+    #[cfg(feature = "gf256")]
+    use super::Poly256;
+    #[cfg(feature = "gf128")]
+    use super::{horner, horner_many};
+    use super::{
+        solve_vandermonde, solve_vandermonde_transposed, BarycentricWeights, NewtonInterpolator,
+        Poly, PolyVec,
+    };
     use crate::f2x::Degree;
-    use crate::galoisfields::{FieldArithmetic, GF2p256, F3329};
+    #[cfg(feature = "gf256")]
+    use crate::galoisfields::GF2p256;
+    #[cfg(feature = "gf128")]
+    use crate::galoisfields::{DynGf2, GF2p128};
+    use crate::galoisfields::{FieldArithmetic, F3329};
+    use crate::primefields::Fp25519;
+    use rand::thread_rng;
 
-    type Poly3329 = Poly<F3329>;
+    type Poly3329 = PolyVec<F3329>;
 
     #[test]
+    #[cfg(all(feature = "std", feature = "gf256"))]
     fn sanity() {
         let cap = 10;
         let mut poly = Poly256::zero_with_capacity(cap);
@@ -258,11 +1126,41 @@ mod tests {
         one.coeffs[0] = GF2p256::ONE;
         assert_eq!(poly.add(&poly), Poly256::zero_with_capacity(cap));
         assert!(Poly256::zero_with_capacity(cap)
-            .evaluate(&GF2p256::random())
+            .evaluate(&GF2p256::random(&mut thread_rng()))
             .is_zero());
         assert_eq!(poly.mul(&one), poly);
     }
 
+    #[test]
+    #[cfg(feature = "gf256")]
+    fn hedged_dealing() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let secret = GF2p256::random(&mut thread_rng());
+        let participants = (0..5)
+            .map(|_| GF2p256::random(&mut thread_rng()))
+            .collect::<Vec<_>>();
+        let deal = |secret: &GF2p256, nonce: &[u8]| {
+            // A broken RNG whose output is known to everyone
+            let mut rng = StdRng::seed_from_u64(0);
+            Poly256::hedged_with_rng(secret, 3, &participants, nonce, &mut rng)
+        };
+        let poly = deal(&secret, b"nonce");
+        assert_eq!(poly.coeffs[0], secret);
+        assert_eq!(poly.capacity(), 3);
+        assert_eq!(poly, deal(&secret, b"nonce"));
+        assert_ne!(poly.coeffs[1..], deal(&secret, b"other nonce").coeffs[1..]);
+        assert_ne!(
+            poly.coeffs[1..],
+            deal(&GF2p256::random(&mut thread_rng()), b"nonce").coeffs[1..]
+        );
+
+        let mut rng = rand::thread_rng();
+        let hedged = Poly256::hedged_with_rng(&secret, 3, &participants, b"nonce", &mut rng);
+        assert_eq!(hedged.coeffs[0], secret);
+        assert_ne!(hedged, poly);
+    }
+
     #[test]
     fn poly3329_eval() {
         let mut poly = Poly3329::zero_with_capacity(3);
@@ -275,6 +1173,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(all(feature = "std", feature = "gf256"))]
     fn interpolate_random_polynomial() {
         let cap = 3;
         let mut poly = Poly256::zero_with_capacity(cap);
@@ -283,17 +1182,21 @@ mod tests {
             .map(|_| {
                 // Does not check distinctness of field ordering, but the chance of collision is
                 // cryptographically small
-                let alpha = GF2p256::random();
+                let alpha = GF2p256::random(&mut thread_rng());
                 let r = poly.evaluate(&alpha);
                 (alpha, r)
             })
             .collect::<Vec<(GF2p256, GF2p256)>>();
         let interpolate = Poly256::interpolate(&points, cap);
-        assert_eq!(interpolate.degree(), Degree::NonNegative(cap - 1));
+        assert_eq!(
+            interpolate.degree(),
+            crate::f2x::Degree::NonNegative(cap - 1)
+        );
         assert_eq!(poly, Poly256::interpolate(&points, cap));
     }
 
     #[test]
+    #[cfg(all(feature = "std", feature = "gf256"))]
     fn random_poly256_serde() {
         let cap = 10;
         let mut lhs = Poly256::zero_with_capacity(cap);
@@ -303,4 +1206,429 @@ mod tests {
         let rhs = Poly256::deserialize(&buf, cap);
         assert_eq!(lhs, rhs);
     }
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    fn interpolate_dyn_gf2_polynomial() {
+        let cap = 3;
+        let mut rng = rand::thread_rng();
+        let coeffs = (0..cap)
+            .map(|_| DynGf2::random_with_modulus(GF2p128::MODULUS, &mut rng))
+            .collect::<Vec<DynGf2<{ GF2p128::LIMBS }>>>();
+        let poly = PolyVec::from_coeffs(coeffs);
+        let points = (0..cap)
+            .map(|_| {
+                let alpha = DynGf2::random_with_modulus(GF2p128::MODULUS, &mut rng);
+                (alpha, poly.evaluate(&alpha))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(poly, PolyVec::interpolate(&points, cap));
+    }
+
+    #[test]
+    fn interpolate_prime_field_polynomial() {
+        let cap = 4;
+        let mut rng = thread_rng();
+        let poly = PolyVec::from(Poly::<Fp25519, 4>::random(&mut rng));
+        let points = (0..cap)
+            .map(|_| {
+                let alpha = Fp25519::random(&mut rng);
+                (alpha, poly.evaluate(&alpha))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(poly, PolyVec::interpolate(&points, cap));
+    }
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    fn random_fixed_poly_arithmetic() {
+        let mut rng = thread_rng();
+        for _ in 0..10 {
+            // Degree at most 1, so that the product of two of them fits into 3 coefficients
+            let mut lhs = Poly::<F3329, 3>::random(&mut rng);
+            let mut rhs = Poly::<F3329, 3>::random(&mut rng);
+            lhs.coeffs[2] = F3329::zero();
+            rhs.coeffs[2] = F3329::zero();
+            let (lhs_vec, rhs_vec) = (PolyVec::from(lhs), PolyVec::from(rhs));
+            assert_eq!(PolyVec::from(lhs.add(&rhs)), lhs_vec.add(&rhs_vec));
+            assert_eq!(PolyVec::from(lhs.sub(&rhs)), lhs_vec.sub(&rhs_vec));
+            assert_eq!(PolyVec::from(lhs.mul(&rhs)), lhs_vec.mul(&rhs_vec));
+            assert_eq!(lhs.sub(&rhs).add(&rhs), lhs);
+
+            let (at, scalar) = (F3329::random(&mut rng), F3329::random(&mut rng));
+            assert_eq!(lhs.evaluate(&at), lhs_vec.evaluate(&at));
+            assert_eq!(horner(&lhs.coeffs, &at), lhs.evaluate(&at));
+            assert_eq!(
+                lhs.mul(&rhs).evaluate(&at),
+                lhs.evaluate(&at).modmul(&rhs.evaluate(&at))
+            );
+            assert_eq!(
+                lhs.mul_coeff(&scalar).evaluate(&at),
+                lhs.evaluate(&at).modmul(&scalar)
+            );
+            assert_eq!(
+                lhs.add_coeff(&scalar).evaluate(&at),
+                lhs.evaluate(&at).modadd(&scalar)
+            );
+        }
+
+        let x = Poly::<GF2p128, 2>::from_coeffs([GF2p128::ZERO, GF2p128::ONE]);
+        assert_eq!(x.degree(), Degree::NonNegative(1));
+        assert!(x.checked_mul(&x).is_none());
+        assert!(Poly::<GF2p128, 2>::zero().is_zero());
+        assert_eq!(
+            Poly::<GF2p128, 2>::default().degree(),
+            Degree::NegativeInfinity
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    fn random_evaluate_many() {
+        let mut rng = thread_rng();
+        let poly = Poly::<GF2p128, 5>::random(&mut rng);
+        let xs: [GF2p128; 7] = core::array::from_fn(|_| GF2p128::random(&mut rng));
+        let ys = poly.evaluate_many(&xs);
+        for (x, y) in xs.iter().zip(ys) {
+            assert_eq!(poly.evaluate(x), y);
+        }
+        assert_eq!(PolyVec::from(poly).evaluate_many(&xs), ys.to_vec());
+        assert_eq!(poly.evaluate_many(&[]), []);
+
+        // A point at zero evaluates to the constant term
+        let mut ys = [GF2p128::ONE; 2];
+        horner_many(&poly.coeffs, &[GF2p128::ZERO, GF2p128::ONE], &mut ys);
+        assert_eq!(ys[0], poly.coeffs[0]);
+        assert_eq!(
+            ys[1],
+            poly.coeffs
+                .iter()
+                .fold(GF2p128::ZERO, |acc, c| acc.modadd(c))
+        );
+    }
+
+    /// Multiply random monic polynomials of the specified degrees into a `PolyVec` of capacity 8
+    fn random_product<F: FieldArithmetic>(degrees: &[usize]) -> PolyVec<F> {
+        let mut rng = thread_rng();
+        let mut prod = PolyVec::zero_with_capacity(8);
+        prod.coeffs[0] = F::one();
+        for degree in degrees {
+            let mut factor = PolyVec::zero_with_capacity(8);
+            factor.coeffs[..*degree]
+                .iter_mut()
+                .for_each(|coeff| *coeff = F::random(&mut rng));
+            factor.coeffs[*degree] = F::one();
+            prod = prod.mul(&factor);
+        }
+        prod
+    }
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    fn random_div_rem() {
+        for _ in 0..10 {
+            let (lhs, rhs) = (random_product::<GF2p128>(&[6]), random_product(&[3]));
+            let (quot, rem) = lhs.div_rem(&rhs);
+            assert_eq!(quot.mul(&rhs).add(&rem), lhs);
+            assert!(rem.degree() < rhs.degree());
+            assert_eq!(quot.degree(), Degree::NonNegative(3));
+
+            let (lhs, rhs) = (random_product::<F3329>(&[5]), random_product(&[2]));
+            let (quot, rem) = lhs.div_rem(&rhs);
+            assert_eq!(quot.mul(&rhs).add(&rem), lhs);
+            assert!(rem.degree() < rhs.degree());
+
+            // The divisor need not be monic
+            let scale = GF2p128::random(&mut thread_rng());
+            let (lhs, rhs) = (random_product::<GF2p128>(&[4]), random_product(&[2]));
+            let rhs = rhs.mul_coeff(&scale);
+            let (quot, rem) = lhs.div_rem(&rhs);
+            assert_eq!(quot.mul(&rhs).add(&rem), lhs);
+        }
+
+        // Dividing by x shifts the coefficients down and leaves the constant term
+        let lhs = Poly::<GF2p128, 4>::random(&mut thread_rng());
+        let mut x = Poly::zero();
+        x.coeffs[1] = GF2p128::ONE;
+        let (quot, rem) = lhs.div_rem(&x);
+
+        assert_eq!(rem.coeffs[0], lhs.coeffs[0]);
+        assert_eq!(quot.coeffs[..3], lhs.coeffs[1..]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_by_zero() {
+        Poly::<F3329, 3>::random(&mut thread_rng()).div_rem(&Poly::zero());
+    }
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    fn random_gcd() {
+        for _ in 0..10 {
+            // Random factors over a large field are coprime with overwhelming probability
+            let common = random_product::<GF2p128>(&[2]);
+            let lhs = common.mul(&random_product(&[3]));
+            let rhs = common.mul(&random_product(&[2]));
+            let scale = GF2p128::random(&mut thread_rng());
+            assert_eq!(PolyVec::gcd(&lhs.mul_coeff(&scale), &rhs), common);
+            assert_eq!(PolyVec::gcd(&rhs, &lhs), common);
+            assert_eq!(PolyVec::gcd(&lhs, &PolyVec::zero_with_capacity(8)), lhs);
+
+            let to_fixed = |poly: &PolyVec<GF2p128>| {
+                Poly::<GF2p128, 8>::from_coeffs(core::array::from_fn(|i| poly.coeffs[i]))
+            };
+            assert_eq!(
+                PolyVec::from(Poly::gcd(&to_fixed(&lhs), &to_fixed(&rhs))),
+                common
+            );
+        }
+        let zero = PolyVec::<F3329>::zero_with_capacity(3);
+        assert!(PolyVec::gcd(&zero, &zero).is_zero());
+    }
+
+    /// The Euclidean algorithms over an odd characteristic, where a sign error in a subtraction
+    /// would not cancel out as it does in characteristic 2
+    #[test]
+    fn random_prime_field_euclid() {
+        let mut rng = thread_rng();
+        for _ in 0..10 {
+            let scale = Fp25519::random(&mut rng);
+            let (lhs, rhs) = (random_product::<Fp25519>(&[6]), random_product(&[3]));
+            let rhs = rhs.mul_coeff(&scale);
+            let (quot, rem) = lhs.div_rem(&rhs);
+            assert_eq!(quot.mul(&rhs).add(&rem), lhs);
+            assert!(rem.degree() < rhs.degree());
+
+            let common = random_product::<Fp25519>(&[1]);
+            let (lhs, rhs) = (random_product(&[3]), random_product(&[2]));
+            let (lhs, rhs) = (common.mul(&lhs), common.mul(&rhs));
+            assert_eq!(PolyVec::gcd(&lhs.mul_coeff(&scale), &rhs), common);
+
+            let (s, t, d) = PolyVec::xgcd(&lhs, &rhs);
+            assert_eq!(s.mul(&lhs).add(&t.mul(&rhs)), d);
+            assert_eq!(PolyVec::gcd(&d, &d), common);
+
+            let (s, t, r) = PolyVec::partial_xgcd(&lhs, &rhs, 2);
+            assert_eq!(s.mul(&lhs).add(&t.mul(&rhs)), r);
+            assert!(r.degree() < Degree::NonNegative(2));
+
+            let (lhs, rhs) = (random_product::<Fp25519>(&[3]), random_product(&[4]));
+            assert_eq!(
+                lhs.mul(&rhs).derivative(),
+                lhs.derivative().mul(&rhs).add(&lhs.mul(&rhs.derivative()))
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    fn random_derivative() {
+        // 536 + 49x + 1873x^2 + 3000x^3 -> 49 + 417x + 2342x^2 modulo 3329
+        let poly = Poly::<F3329, 4>::from_coeffs([536, 49, 1873, 3000].map(F3329::from));
+        assert_eq!(
+            poly.derivative().coeffs,
+            [49, 417, 2342, 0].map(F3329::from)
+        );
+
+        let mut rng = thread_rng();
+        for _ in 0..10 {
+            // The product rule (fg)' = f'g + fg'
+            let (lhs, rhs) = (random_product::<F3329>(&[3]), random_product(&[4]));
+            assert_eq!(
+                lhs.mul(&rhs).derivative(),
+                lhs.derivative().mul(&rhs).add(&lhs.mul(&rhs.derivative()))
+            );
+            let (lhs, rhs) = (random_product::<GF2p128>(&[3]), random_product(&[4]));
+            assert_eq!(
+                lhs.mul(&rhs).derivative(),
+                lhs.derivative().mul(&rhs).add(&lhs.mul(&rhs.derivative()))
+            );
+
+            // In characteristic 2 the odd-power terms of the derivative vanish
+            let poly = Poly::<GF2p128, 6>::random(&mut rng);
+            let derivative = poly.derivative();
+            for (i, coeff) in derivative.coeffs.iter().enumerate() {
+                if i % 2 == 0 && i + 1 < 6 {
+                    assert_eq!(*coeff, poly.coeffs[i + 1]);
+                } else {
+                    assert!(coeff.is_zero());
+                }
+            }
+        }
+    }
+
+    /// Recover random polynomials point by point; each prefix of the points is interpolated
+    #[test]
+    fn random_newton_interpolation() {
+        let mut rng = thread_rng();
+        let poly = Poly::<Fp25519, 6>::random(&mut rng);
+        let xs: [Fp25519; 6] = core::array::from_fn(|_| Fp25519::random(&mut rng));
+        let mut newton = NewtonInterpolator::new();
+        for (i, x) in xs.iter().enumerate() {
+            newton.push(*x, poly.evaluate(x)).unwrap();
+            assert_eq!(newton.len(), i + 1);
+            for x in xs[..=i].iter() {
+                assert_eq!(newton.evaluate(x), poly.evaluate(x));
+            }
+            let at = Fp25519::random(&mut rng);
+            assert_eq!(newton.to_poly(8).evaluate(&at), newton.evaluate(&at));
+        }
+        assert_eq!(newton.to_poly(6), PolyVec::from(poly));
+        assert!(newton.push(xs[0], Fp25519::zero()).is_err());
+        assert_eq!(newton.len(), 6);
+        assert_eq!(
+            NewtonInterpolator::<Fp25519>::new().evaluate(&Fp25519::ONE),
+            Fp25519::ZERO
+        );
+    }
+
+    #[test]
+    fn random_barycentric_evaluation() {
+        let mut rng = thread_rng();
+        let polys: [Poly<Fp25519, 5>; 3] = core::array::from_fn(|_| Poly::random(&mut rng));
+        let xs: [Fp25519; 5] = core::array::from_fn(|_| Fp25519::random(&mut rng));
+        let weights = BarycentricWeights::new(&xs).unwrap();
+        for at in [Fp25519::random(&mut rng), Fp25519::zero(), xs[2]] {
+            let basis = weights.basis_at(&at);
+            for poly in polys.iter() {
+                let ys = poly.evaluate_many(&xs);
+                assert_eq!(weights.evaluate(&ys, &at), poly.evaluate(&at));
+                let sum = basis
+                    .iter()
+                    .zip(ys.iter())
+                    .fold(Fp25519::zero(), |acc, (b, y)| acc.modadd(&b.modmul(y)));
+                assert_eq!(sum, poly.evaluate(&at));
+            }
+        }
+        assert!(BarycentricWeights::new(&[xs[0], xs[1], xs[0]]).is_err());
+    }
+
+    #[test]
+    fn random_inv_mod_xn() {
+        let mut rng = thread_rng();
+        for n in [1, 2, 5, 8, 13] {
+            let mut poly = PolyVec::<Fp25519>::zero_with_capacity(16);
+            poly.fill_random_with_rng(&mut rng);
+            if poly.coeffs[0].is_zero() {
+                poly.coeffs[0] = Fp25519::one();
+            }
+            let inv = poly.inv_mod_xn(n).unwrap();
+            assert_eq!(inv.capacity(), n);
+            let mut inv_wide = PolyVec::zero_with_capacity(32);
+            inv_wide.coeffs[..n].copy_from_slice(&inv.coeffs);
+            let mut poly_wide = PolyVec::zero_with_capacity(32);
+            poly_wide.coeffs[..16].copy_from_slice(&poly.coeffs);
+            let prod = poly_wide.mul(&inv_wide);
+            assert!(prod.coeffs[0].is_one());
+            assert!(prod.coeffs[1..n].iter().all(|coeff| coeff.is_zero()));
+        }
+        let mut poly = PolyVec::<Fp25519>::zero_with_capacity(4);
+        poly.coeffs[1] = Fp25519::one();
+        assert!(poly.inv_mod_xn(3).is_none());
+        assert_eq!(poly.inv_mod_xn(0).unwrap().capacity(), 0);
+    }
+
+    #[test]
+    fn random_resultant() {
+        let mut rng = thread_rng();
+        // Res(f, g) is the product of g over the roots of a monic f
+        let roots: [Fp25519; 3] = core::array::from_fn(|_| Fp25519::random(&mut rng));
+        let mut f = PolyVec::<Fp25519>::zero_with_capacity(8);
+        f.coeffs[0] = Fp25519::one();
+        for root in roots.iter() {
+            let mut linear = PolyVec::zero_with_capacity(8);
+            linear.coeffs[..2].copy_from_slice(&[Fp25519::zero().modsub(root), Fp25519::one()]);
+            f = f.mul(&linear);
+        }
+        let mut g = PolyVec::<Fp25519>::zero_with_capacity(8);
+        g.coeffs[..4]
+            .iter_mut()
+            .for_each(|c| *c = Fp25519::random(&mut rng));
+        let expected = roots
+            .iter()
+            .fold(Fp25519::one(), |acc, root| acc.modmul(&g.evaluate(root)));
+        assert_eq!(PolyVec::resultant(&f, &g), expected);
+
+        // A common factor makes the resultant vanish
+        let product = random_product::<Fp25519>(&[1, 2]);
+        let other = random_product::<Fp25519>(&[1]).mul(&PolyVec::gcd(&product, &product));
+        assert_eq!(PolyVec::resultant(&product, &other), Fp25519::zero());
+        let mut constant = PolyVec::zero_with_capacity(8);
+        constant.coeffs[0] = roots[0];
+        assert_eq!(PolyVec::resultant(&f, &constant), roots[0].modexp(3));
+        assert_eq!(
+            PolyVec::resultant(&f, &PolyVec::zero_with_capacity(8)),
+            Fp25519::zero()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    fn quadratic_discriminant() {
+        let mut rng = thread_rng();
+        let (b, c) = (F3329::random(&mut rng), F3329::random(&mut rng));
+        let poly = PolyVec::from_coeffs(vec![c, b, F3329::one()]);
+        let four = F3329::one().modadd(&F3329::one()).square();
+        assert_eq!(poly.discriminant(), b.square().modsub(&four.modmul(&c)));
+
+        // In characteristic 2, x^2 + bx + c has a repeated root if and only if b = 0
+        let (b, c) = (GF2p128::random(&mut rng), GF2p128::random(&mut rng));
+        let poly = PolyVec::from_coeffs(vec![c, b, GF2p128::ONE]);
+        assert_eq!(poly.discriminant(), b.square());
+        let line = PolyVec::from_coeffs(vec![c, b, GF2p128::ZERO]);
+        assert_eq!(line.discriminant(), GF2p128::ONE);
+    }
+
+    #[test]
+    fn random_vandermonde() {
+        let mut rng = thread_rng();
+        let poly = Poly::<Fp25519, 6>::random(&mut rng);
+        let xs: [Fp25519; 6] = core::array::from_fn(|_| Fp25519::random(&mut rng));
+        let ys = poly.evaluate_many(&xs);
+        assert_eq!(solve_vandermonde(&xs, &ys).unwrap(), poly.coeffs.to_vec());
+
+        // The transposed solution at b = (1, t, t^2, ...) holds the Lagrange basis at t
+        let at = Fp25519::random(&mut rng);
+        let powers = (0..6).map(|j| at.modexp(j)).collect::<Vec<Fp25519>>();
+        let weights = solve_vandermonde_transposed(&xs, &powers).unwrap();
+        assert_eq!(weights, BarycentricWeights::new(&xs).unwrap().basis_at(&at));
+
+        assert!(solve_vandermonde(&[xs[0], xs[0]], &ys[..2]).is_err());
+        assert!(solve_vandermonde_transposed(&[xs[1], xs[1]], &ys[..2]).is_err());
+        assert_eq!(solve_vandermonde(&xs[..1], &ys[..1]).unwrap(), vec![ys[0]]);
+        assert!(solve_vandermonde::<Fp25519>(&[], &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn random_compose() {
+        let mut rng = thread_rng();
+        let outer = PolyVec::from(Poly::<Fp25519, 5>::random(&mut rng));
+        let inner = PolyVec::from(Poly::<Fp25519, 4>::random(&mut rng));
+        let (mut outer_wide, mut inner_wide) = (
+            PolyVec::zero_with_capacity(16),
+            PolyVec::zero_with_capacity(16),
+        );
+        outer_wide.coeffs[..5].copy_from_slice(&outer.coeffs);
+        inner_wide.coeffs[..4].copy_from_slice(&inner.coeffs);
+        let composed = outer_wide.compose(&inner_wide);
+        let at = Fp25519::random(&mut rng);
+        assert_eq!(composed.evaluate(&at), outer.evaluate(&inner.evaluate(&at)));
+
+        // compose_mod agrees with reducing the full composition, for outer polynomials with
+        // numbers of coefficients that are and are not perfect squares
+        let mut modulus = PolyVec::zero_with_capacity(16);
+        modulus.coeffs[..4]
+            .iter_mut()
+            .for_each(|c| *c = Fp25519::random(&mut rng));
+        modulus.coeffs[3] = Fp25519::one();
+        for terms in [1, 2, 4, 5] {
+            let mut outer = outer_wide.clone();
+            outer.coeffs[terms..].fill(Fp25519::zero());
+            let (_, expected) = outer.compose(&inner_wide).div_rem(&modulus);
+            assert_eq!(outer.compose_mod(&inner_wide, &modulus), expected);
+        }
+        let zero = PolyVec::zero_with_capacity(16);
+        assert_eq!(zero.compose_mod(&inner_wide, &modulus), zero);
+    }
 }
@@ -0,0 +1,1012 @@
+//! Prime fields F_p with Montgomery arithmetic
+//!
+//! Unlike `F2x`, multi-precision integers in this module are encoded in little-endian order:
+//! limbs[0] holds the least significant 64 bits.
+use crate::error::SssError;
+use crate::galoisfields::FieldArithmetic;
+use core::marker::PhantomData;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use rand_core::CryptoRngCore;
+
+/// Compile-time parameters of a prime field. The modulus must be an odd prime that fits into N
+/// 64-bit limbs, encoded in little-endian order.
+pub trait FpParams<const N: usize>: Copy + Clone + PartialEq + Eq + core::fmt::Debug {
+    const MODULUS: [u64; N];
+}
+
+/// Compute a + b + carry, returning (sum, carry)
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let sum = (a as u128) + (b as u128) + (carry as u128);
+    (sum as u64, (sum >> 64) as u64)
+}
+
+/// Compute a - b - borrow, returning (difference, borrow) where borrow is either 0 or 1
+const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let diff = (a as u128).wrapping_sub((b as u128) + (borrow as u128));
+    (diff as u64, ((diff >> 64) as u64) & 1)
+}
+
+/// Compute a + b * c + carry, returning (low, high). This can never overflow.
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let res = (a as u128) + (b as u128) * (c as u128) + (carry as u128);
+    (res as u64, (res >> 64) as u64)
+}
+
+/// Multi-precision addition, returning (sum, carry)
+const fn add_limbs<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], u64) {
+    let mut sum = [0; N];
+    let mut carry = 0;
+    let mut i = 0;
+    while i < N {
+        (sum[i], carry) = adc(a[i], b[i], carry);
+        i += 1;
+    }
+    (sum, carry)
+}
+
+/// Multi-precision subtraction, returning (difference, borrow)
+const fn sub_limbs<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], u64) {
+    let mut diff = [0; N];
+    let mut borrow = 0;
+    let mut i = 0;
+    while i < N {
+        (diff[i], borrow) = sbb(a[i], b[i], borrow);
+        i += 1;
+    }
+    (diff, borrow)
+}
+
+/// The multi-precision integer with the value of a single word
+const fn small_limbs<const N: usize>(val: u64) -> [u64; N] {
+    let mut limbs = [0; N];
+    limbs[0] = val;
+    limbs
+}
+
+/// Compute -p^(-1) mod 2^64 using Newton's iteration, each of which doubles the number of correct
+/// bits. p must be odd.
+const fn neg_inv_mod_word(p: u64) -> u64 {
+    let mut inv: u64 = 1;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+/// Compute 2^exp mod p by repeated modular doubling
+const fn pow2_mod<const N: usize>(p: &[u64; N], exp: usize) -> [u64; N] {
+    let mut acc = small_limbs::<N>(1);
+    let mut i = 0;
+    while i < exp {
+        let (doubled, carry) = add_limbs(&acc, &acc);
+        let (reduced, borrow) = sub_limbs(&doubled, p);
+        acc = if carry == 1 || borrow == 0 {
+            reduced
+        } else {
+            doubled
+        };
+        i += 1;
+    }
+    acc
+}
+
+/// The number of significant bits of a multi-precision integer
+const fn bit_length<const N: usize>(limbs: &[u64; N]) -> usize {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if limbs[i] != 0 {
+            return 64 * i + (u64::BITS - limbs[i].leading_zeros()) as usize;
+        }
+    }
+    0
+}
+
+/// An element of the prime field whose modulus is specified by P. Elements are internally kept in
+/// Montgomery form (aR mod p where R = 2^(64N)). `Debug` redacts the limbs unless the
+/// `debug-secrets` feature is enabled.
+#[cfg_attr(feature = "debug-secrets", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Fp<P: FpParams<N>, const N: usize> {
+    mont: [u64; N],
+    _params: PhantomData<P>,
+}
+
+#[cfg(not(feature = "debug-secrets"))]
+impl<P: FpParams<N>, const N: usize> core::fmt::Debug for Fp<P, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Fp")
+            .field("mont", &format_args!("[REDACTED; {N}]"))
+            .finish()
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> Fp<P, N> {
+    pub const MODULUS: [u64; N] = P::MODULUS;
+    pub const BITS: usize = 64 * N;
+    pub const BYTES: usize = 8 * N;
+    pub const ZERO: Self = Self::from_mont([0; N]);
+    pub const ONE: Self = Self::from_mont(Self::R);
+
+    /// R mod p
+    const R: [u64; N] = pow2_mod(&P::MODULUS, 64 * N);
+    /// R^2 mod p, used for converting into Montgomery form
+    const R2: [u64; N] = pow2_mod(&P::MODULUS, 128 * N);
+    /// -p^(-1) mod 2^64
+    const INV: u64 = neg_inv_mod_word(P::MODULUS[0]);
+    /// The mask of the bits of the most significant limb that are below the bit length of p
+    const TOP_MASK: u64 = u64::MAX >> (64 * N - bit_length(&P::MODULUS));
+    /// p - 2, the exponent for Fermat inversion
+    const P_MINUS_2: [u64; N] = sub_limbs(&P::MODULUS, &small_limbs::<N>(2)).0;
+
+    const fn from_mont(mont: [u64; N]) -> Self {
+        Self {
+            mont,
+            _params: PhantomData,
+        }
+    }
+
+    /// Convert from the canonical integer representation, which is reduced under the modulus.
+    /// Return None if the input is not less than the modulus
+    pub fn from_limbs(limbs: [u64; N]) -> Option<Self> {
+        let (_, borrow) = sub_limbs(&limbs, &P::MODULUS);
+        if borrow == 1 {
+            Some(Self::from_limbs_reduced(limbs))
+        } else {
+            None
+        }
+    }
+
+    /// The element congruent to `val`, reduced under the modulus
+    pub fn from_u64(val: u64) -> Self {
+        Self::from_limbs_reduced(small_limbs::<N>(val))
+    }
+
+    /// See `from_u64`
+    pub fn from_u16(val: u16) -> Self {
+        Self::from_u64(val as u64)
+    }
+
+    /// Convert any N-limb integer into a field element by reducing under the modulus
+    const fn from_limbs_reduced(limbs: [u64; N]) -> Self {
+        Self::from_mont(Self::mont_mul(&limbs, &Self::R2))
+    }
+
+    /// The canonical integer representation of this element, which is less than the modulus
+    pub fn to_limbs(&self) -> [u64; N] {
+        Self::mont_mul(&self.mont, &small_limbs::<N>(1))
+    }
+
+    /// Read a big-endian integer, returning an error if it is not less than the modulus. Unlike
+    /// `FieldArithmetic::from_be_bytes`, this rejects non-canonical encodings instead of silently
+    /// reducing them, which is the behavior expected when parsing private keys.
+    pub fn from_be_bytes_canonical(src: &[u8]) -> Result<Self, SssError> {
+        if src.len() != Self::BYTES {
+            return Err(SssError::FormatError {
+                what: "field element",
+            });
+        }
+        let mut limbs = [0u64; N];
+        limbs.iter_mut().rev().enumerate().for_each(|(i, limb)| {
+            let mut be_bytes = [0; 8];
+            be_bytes.copy_from_slice(&src[(8 * i)..(8 * i + 8)]);
+            *limb = u64::from_be_bytes(be_bytes);
+        });
+        Self::from_limbs(limbs).ok_or(SssError::NonCanonicalElement)
+    }
+
+    /// Write the canonical integer representation in little-endian order, which is the encoding
+    /// used by Ed25519 and X25519
+    pub fn write_le_bytes(&self, dst: &mut [u8]) {
+        if dst.len() != Self::BYTES {
+            panic!("input buffer size is incorrect");
+        }
+        self.to_limbs()
+            .iter()
+            .enumerate()
+            .for_each(|(i, limb)| dst[(8 * i)..(8 * i + 8)].copy_from_slice(&limb.to_le_bytes()));
+    }
+
+    /// Read a little-endian integer and reduce it under the modulus
+    pub fn from_le_bytes(src: &[u8]) -> Self {
+        if src.len() != Self::BYTES {
+            panic!("input buffer size is incorrect");
+        }
+        let mut limbs = [0u64; N];
+        limbs.iter_mut().enumerate().for_each(|(i, limb)| {
+            let mut le_bytes = [0; 8];
+            le_bytes.copy_from_slice(&src[(8 * i)..(8 * i + 8)]);
+            *limb = u64::from_le_bytes(le_bytes);
+        });
+        Self::from_limbs_reduced(limbs)
+    }
+
+    /// Compute a * b * R^(-1) mod p using coarsely integrated operand scanning (CIOS). The output
+    /// is fully reduced as long as a * b < pR, which is the case if one operand is less than p
+    const fn mont_mul(a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let p = &P::MODULUS;
+        let mut t = [0u64; N];
+        // t_n and t_n1 are the two extra words of the (N + 2)-word accumulator
+        let mut t_n = 0u64;
+
+        let mut i = 0;
+        while i < N {
+            let mut carry = 0;
+            let mut j = 0;
+            while j < N {
+                (t[j], carry) = mac(t[j], a[j], b[i], carry);
+                j += 1;
+            }
+            let (sum, t_n1) = adc(t_n, carry, 0);
+            t_n = sum;
+
+            let m = t[0].wrapping_mul(Self::INV);
+            let (_, mut carry) = mac(t[0], m, p[0], 0);
+            let mut j = 1;
+            while j < N {
+                (t[j - 1], carry) = mac(t[j], m, p[j], carry);
+                j += 1;
+            }
+            let (sum, carry) = adc(t_n, carry, 0);
+            t[N - 1] = sum;
+            t_n = t_n1 + carry;
+            i += 1;
+        }
+
+        let (reduced, borrow) = sub_limbs(&t, p);
+        if t_n != 0 || borrow == 0 {
+            reduced
+        } else {
+            t
+        }
+    }
+
+    /// Square-and-multiply exponentiation with a little-endian multi-precision exponent. This is
+    /// a `const fn` so that the constants of ff's `PrimeField` can be derived from the modulus.
+    const fn pow_limbs(&self, exp: &[u64; N]) -> Self {
+        let mut acc = Self::ONE.mont;
+
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            let mut bit = 64;
+            while bit > 0 {
+                bit -= 1;
+                acc = Self::mont_mul(&acc, &acc);
+                if (exp[i] >> bit) & 1 == 1 {
+                    acc = Self::mont_mul(&acc, &self.mont);
+                }
+            }
+        }
+
+        Self::from_mont(acc)
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> Default for Fp<P, N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+/// Rejection sampling: draw integers with as many bits as the modulus until one is less than the
+/// modulus. Reducing a full-width integer instead would favor the small residues. At least half of
+/// the candidates are accepted, and rejected candidates are independent of the accepted one.
+impl<P: FpParams<N>, const N: usize> Distribution<Fp<P, N>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Fp<P, N> {
+        loop {
+            let mut limbs = [0u64; N];
+            rng.fill(&mut limbs[..]);
+            limbs[N - 1] &= Fp::<P, N>::TOP_MASK;
+            if let Some(elem) = Fp::from_limbs(limbs) {
+                return elem;
+            }
+        }
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> FieldArithmetic for Fp<P, N> {
+    fn is_zero(&self) -> bool {
+        self.mont == [0; N]
+    }
+    fn is_one(&self) -> bool {
+        self.mont == Self::R
+    }
+    fn zero() -> Self {
+        Self::ZERO
+    }
+    fn one() -> Self {
+        Self::ONE
+    }
+    /// Sample uniformly by rejection: draw integers with the bit length of the modulus until one
+    /// is below it
+    fn random(rng: &mut impl CryptoRngCore) -> Self {
+        rng.gen()
+    }
+
+    fn modadd(&self, rhs: &Self) -> Self {
+        let (sum, carry) = add_limbs(&self.mont, &rhs.mont);
+        let (reduced, borrow) = sub_limbs(&sum, &P::MODULUS);
+        if carry == 1 || borrow == 0 {
+            Self::from_mont(reduced)
+        } else {
+            Self::from_mont(sum)
+        }
+    }
+
+    fn modsub(&self, rhs: &Self) -> Self {
+        let (diff, borrow) = sub_limbs(&self.mont, &rhs.mont);
+        if borrow == 1 {
+            Self::from_mont(add_limbs(&diff, &P::MODULUS).0)
+        } else {
+            Self::from_mont(diff)
+        }
+    }
+
+    fn modmul(&self, rhs: &Self) -> Self {
+        Self::from_mont(Self::mont_mul(&self.mont, &rhs.mont))
+    }
+
+    /// Fermat's little theorem: a^(p-2) is the inverse of a
+    fn modinv(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Some(self.inv_ct())
+    }
+
+    /// The exponent p - 2 is fixed, so the squarings and multiplications do not depend on self
+    fn inv_ct(&self) -> Self {
+        self.pow_limbs(&Self::P_MINUS_2)
+    }
+
+    fn bytes() -> usize {
+        Self::BYTES
+    }
+
+    /// Write the canonical integer representation in big-endian order
+    fn write_be_bytes(&self, dst: &mut [u8]) {
+        if dst.len() != Self::BYTES {
+            panic!("input buffer size is incorrect");
+        }
+        self.to_limbs()
+            .iter()
+            .rev()
+            .enumerate()
+            .for_each(|(i, limb)| dst[(8 * i)..(8 * i + 8)].copy_from_slice(&limb.to_be_bytes()));
+    }
+
+    /// Read a big-endian integer and reduce it under the modulus
+    fn from_be_bytes(src: &[u8]) -> Self {
+        if src.len() != Self::BYTES {
+            panic!("input buffer size is incorrect");
+        }
+        let mut limbs = [0u64; N];
+        limbs.iter_mut().rev().enumerate().for_each(|(i, limb)| {
+            let mut be_bytes = [0; 8];
+            be_bytes.copy_from_slice(&src[(8 * i)..(8 * i + 8)]);
+            *limb = u64::from_be_bytes(be_bytes);
+        });
+        Self::from_limbs_reduced(limbs)
+    }
+
+    fn try_from_u64(val: u64) -> Option<Self> {
+        Self::from_limbs(small_limbs::<N>(val))
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> From<u64> for Fp<P, N> {
+    fn from(val: u64) -> Self {
+        Self::from_u64(val)
+    }
+}
+
+/// Implement `Add`, `Sub`, `Mul`, and their assigning forms for owned and borrowed operands by
+/// delegating to `FieldArithmetic`, like `__field_ops` does for the binary fields, which it cannot
+/// be reused for because `Fp` is generic
+macro_rules! fp_binop {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $field_method:ident) => {
+        impl<P: FpParams<N>, const N: usize> core::ops::$op<&Fp<P, N>> for &Fp<P, N> {
+            type Output = Fp<P, N>;
+
+            fn $method(self, rhs: &Fp<P, N>) -> Fp<P, N> {
+                self.$field_method(rhs)
+            }
+        }
+
+        impl<P: FpParams<N>, const N: usize> core::ops::$op<&Fp<P, N>> for Fp<P, N> {
+            type Output = Fp<P, N>;
+
+            fn $method(self, rhs: &Fp<P, N>) -> Fp<P, N> {
+                self.$field_method(rhs)
+            }
+        }
+
+        impl<P: FpParams<N>, const N: usize> core::ops::$op<Fp<P, N>> for &Fp<P, N> {
+            type Output = Fp<P, N>;
+
+            fn $method(self, rhs: Fp<P, N>) -> Fp<P, N> {
+                self.$field_method(&rhs)
+            }
+        }
+
+        impl<P: FpParams<N>, const N: usize> core::ops::$op<Fp<P, N>> for Fp<P, N> {
+            type Output = Fp<P, N>;
+
+            fn $method(self, rhs: Fp<P, N>) -> Fp<P, N> {
+                self.$field_method(&rhs)
+            }
+        }
+
+        impl<P: FpParams<N>, const N: usize> core::ops::$op_assign<&Fp<P, N>> for Fp<P, N> {
+            fn $method_assign(&mut self, rhs: &Fp<P, N>) {
+                *self = self.$field_method(rhs);
+            }
+        }
+
+        impl<P: FpParams<N>, const N: usize> core::ops::$op_assign<Fp<P, N>> for Fp<P, N> {
+            fn $method_assign(&mut self, rhs: Fp<P, N>) {
+                *self = self.$field_method(&rhs);
+            }
+        }
+    };
+}
+
+fp_binop!(Add, add, AddAssign, add_assign, modadd);
+fp_binop!(Sub, sub, SubAssign, sub_assign, modsub);
+fp_binop!(Mul, mul, MulAssign, mul_assign, modmul);
+
+impl<P: FpParams<N>, const N: usize> core::ops::Neg for Fp<P, N> {
+    type Output = Fp<P, N>;
+
+    fn neg(self) -> Fp<P, N> {
+        -&self
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> core::ops::Neg for &Fp<P, N> {
+    type Output = Fp<P, N>;
+
+    fn neg(self) -> Fp<P, N> {
+        Fp::ZERO.modsub(self)
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> core::iter::Sum for Fp<P, N> {
+    fn sum<I: Iterator<Item = Fp<P, N>>>(iter: I) -> Fp<P, N> {
+        iter.fold(Fp::ZERO, |acc, elem| acc.modadd(&elem))
+    }
+}
+
+impl<'a, P: FpParams<N>, const N: usize> core::iter::Sum<&'a Fp<P, N>> for Fp<P, N> {
+    fn sum<I: Iterator<Item = &'a Fp<P, N>>>(iter: I) -> Fp<P, N> {
+        iter.fold(Fp::ZERO, |acc, elem| acc.modadd(elem))
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> core::iter::Product for Fp<P, N> {
+    fn product<I: Iterator<Item = Fp<P, N>>>(iter: I) -> Fp<P, N> {
+        iter.fold(Fp::ONE, |acc, elem| acc.modmul(&elem))
+    }
+}
+
+impl<'a, P: FpParams<N>, const N: usize> core::iter::Product<&'a Fp<P, N>> for Fp<P, N> {
+    fn product<I: Iterator<Item = &'a Fp<P, N>>>(iter: I) -> Fp<P, N> {
+        iter.fold(Fp::ONE, |acc, elem| acc.modmul(elem))
+    }
+}
+
+/// Elements are compared in Montgomery form, which is a bijection of the canonical form
+#[cfg(feature = "subtle")]
+impl<P: FpParams<N>, const N: usize> subtle::ConstantTimeEq for Fp<P, N> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&self.mont[..], &other.mont[..])
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: FpParams<N>, const N: usize> subtle::ConditionallySelectable for Fp<P, N> {
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        Self::from_mont(core::array::from_fn(|i| {
+            subtle::ConditionallySelectable::conditional_select(&a.mont[i], &b.mont[i], choice)
+        }))
+    }
+}
+
+/// The parameters of a prime field beyond its modulus that ff's `PrimeField` asks for
+#[cfg(feature = "ff")]
+pub trait PrimeFieldParams<const N: usize>: FpParams<N> + Send + Sync + 'static {
+    /// The modulus as a hexadecimal string, which `PrimeField::MODULUS` reports
+    const MODULUS_HEX: &'static str;
+    /// A generator of the multiplicative group, which is necessarily a quadratic non-residue
+    const MULTIPLICATIVE_GENERATOR: u64;
+}
+
+/// The canonical integer representation of an `Fp` element in little-endian order, as the eight
+/// bytes of each limb
+#[cfg(feature = "ff")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FpRepr<const N: usize>(pub [[u8; 8]; N]);
+
+#[cfg(feature = "ff")]
+impl<const N: usize> Default for FpRepr<N> {
+    fn default() -> Self {
+        Self([[0; 8]; N])
+    }
+}
+
+#[cfg(feature = "ff")]
+impl<const N: usize> AsRef<[u8]> for FpRepr<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_flattened()
+    }
+}
+
+#[cfg(feature = "ff")]
+impl<const N: usize> AsMut<[u8]> for FpRepr<N> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.0.as_flattened_mut()
+    }
+}
+
+/// Shift a multi-precision integer right by `shift` bits
+#[cfg(feature = "ff")]
+const fn shr_limbs<const N: usize>(a: &[u64; N], shift: u32) -> [u64; N] {
+    let words = (shift / 64) as usize;
+    let bits = shift % 64;
+    let mut shifted = [0; N];
+    let mut i = 0;
+    while i + words < N {
+        shifted[i] = a[i + words] >> bits;
+        if bits > 0 && i + words + 1 < N {
+            shifted[i] |= a[i + words + 1] << (64 - bits);
+        }
+        i += 1;
+    }
+    shifted
+}
+
+/// The number of trailing zero bits of a nonzero multi-precision integer
+#[cfg(feature = "ff")]
+const fn trailing_zeros<const N: usize>(limbs: &[u64; N]) -> u32 {
+    let mut i = 0;
+    while limbs[i] == 0 {
+        i += 1;
+    }
+    64 * i as u32 + limbs[i].trailing_zeros()
+}
+
+/// Constants of ff's `PrimeField`, derived from the modulus and the multiplicative generator at
+/// compile time
+#[cfg(feature = "ff")]
+impl<P: PrimeFieldParams<N>, const N: usize> Fp<P, N> {
+    /// The largest S such that 2^S divides p - 1
+    const TWO_ADICITY: u32 = trailing_zeros(&sub_limbs(&P::MODULUS, &small_limbs::<N>(1)).0);
+    /// The odd T such that p - 1 = 2^S * T
+    const ODD_PART: [u64; N] = shr_limbs(&P::MODULUS, Self::TWO_ADICITY);
+    /// (T - 1) / 2, the exponent of the first step of Tonelli-Shanks
+    const T_MINUS_1_DIV_2: [u64; N] = shr_limbs(&P::MODULUS, Self::TWO_ADICITY + 1);
+    const GENERATOR: Self = Self::from_limbs_reduced(small_limbs::<N>(P::MULTIPLICATIVE_GENERATOR));
+
+    /// The generator raised to 2^S by S squarings
+    const fn delta() -> Self {
+        let mut acc = Self::GENERATOR.mont;
+        let mut i = 0;
+        while i < Self::TWO_ADICITY {
+            acc = Self::mont_mul(&acc, &acc);
+            i += 1;
+        }
+        Self::from_mont(acc)
+    }
+}
+
+#[cfg(feature = "ff")]
+impl<P: PrimeFieldParams<N>, const N: usize> ff::Field for Fp<P, N> {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    fn random(mut rng: impl rand_core::RngCore) -> Self {
+        rng.gen()
+    }
+
+    fn square(&self) -> Self {
+        FieldArithmetic::square(self)
+    }
+
+    fn double(&self) -> Self {
+        self.modadd(self)
+    }
+
+    fn invert(&self) -> subtle::CtOption<Self> {
+        subtle::CtOption::new(self.inv_ct(), !ff::Field::is_zero(self))
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (subtle::Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
+
+    /// Constant-time Tonelli-Shanks, which `sqrt_ratio` is built on
+    fn sqrt(&self) -> subtle::CtOption<Self> {
+        ff::helpers::sqrt_tonelli_shanks(self, Self::T_MINUS_1_DIV_2)
+    }
+}
+
+#[cfg(feature = "ff")]
+impl<P: PrimeFieldParams<N>, const N: usize> ff::PrimeField for Fp<P, N> {
+    type Repr = FpRepr<N>;
+
+    /// Read a little-endian integer, failing if it is not less than the modulus
+    fn from_repr(repr: FpRepr<N>) -> subtle::CtOption<Self> {
+        let limbs = repr.0.map(u64::from_le_bytes);
+        let (_, borrow) = sub_limbs(&limbs, &P::MODULUS);
+        subtle::CtOption::new(
+            Self::from_limbs_reduced(limbs),
+            subtle::Choice::from(borrow as u8),
+        )
+    }
+
+    fn to_repr(&self) -> FpRepr<N> {
+        FpRepr(self.to_limbs().map(u64::to_le_bytes))
+    }
+
+    fn is_odd(&self) -> subtle::Choice {
+        subtle::Choice::from((self.to_limbs()[0] & 1) as u8)
+    }
+
+    const MODULUS: &'static str = P::MODULUS_HEX;
+    const NUM_BITS: u32 = bit_length(&P::MODULUS) as u32;
+    const CAPACITY: u32 = Self::NUM_BITS - 1;
+    /// (p + 1) / 2
+    const TWO_INV: Self =
+        Self::from_limbs_reduced(add_limbs(&shr_limbs(&P::MODULUS, 1), &small_limbs::<N>(1)).0);
+    const MULTIPLICATIVE_GENERATOR: Self = Self::GENERATOR;
+    const S: u32 = Self::TWO_ADICITY;
+    const ROOT_OF_UNITY: Self = Self::GENERATOR.pow_limbs(&Self::ODD_PART);
+    const ROOT_OF_UNITY_INV: Self =
+        <Self as ff::PrimeField>::ROOT_OF_UNITY.pow_limbs(&Self::P_MINUS_2);
+    const DELTA: Self = Self::delta();
+}
+
+/// The Mersenne prime 2^127 - 1
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Mersenne127;
+
+impl FpParams<2> for Mersenne127 {
+    const MODULUS: [u64; 2] = [0xFFFFFFFFFFFFFFFF, 0x7FFFFFFFFFFFFFFF];
+}
+
+#[cfg(feature = "ff")]
+impl PrimeFieldParams<2> for Mersenne127 {
+    const MODULUS_HEX: &'static str = "0x7fffffffffffffffffffffffffffffff";
+    const MULTIPLICATIVE_GENERATOR: u64 = 43;
+}
+
+/// The prime 2^255 - 19 underlying Curve25519
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Curve25519Base;
+
+impl FpParams<4> for Curve25519Base {
+    const MODULUS: [u64; 4] = [
+        0xFFFFFFFFFFFFFFED,
+        0xFFFFFFFFFFFFFFFF,
+        0xFFFFFFFFFFFFFFFF,
+        0x7FFFFFFFFFFFFFFF,
+    ];
+}
+
+#[cfg(feature = "ff")]
+impl PrimeFieldParams<4> for Curve25519Base {
+    const MODULUS_HEX: &'static str =
+        "0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed";
+    const MULTIPLICATIVE_GENERATOR: u64 = 2;
+}
+
+/// The prime order of the Ed25519 base point, 2^252 + 27742317777372353535851937790883648493
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Ed25519Order;
+
+impl FpParams<4> for Ed25519Order {
+    const MODULUS: [u64; 4] = [
+        0x5812631A5CF5D3ED,
+        0x14DEF9DEA2F79CD6,
+        0x0000000000000000,
+        0x1000000000000000,
+    ];
+}
+
+#[cfg(feature = "ff")]
+impl PrimeFieldParams<4> for Ed25519Order {
+    const MODULUS_HEX: &'static str =
+        "0x1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed";
+    const MULTIPLICATIVE_GENERATOR: u64 = 2;
+}
+
+/// The prime order of the secp256k1 generator
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Secp256k1Order;
+
+impl FpParams<4> for Secp256k1Order {
+    const MODULUS: [u64; 4] = [
+        0xBFD25E8CD0364141,
+        0xBAAEDCE6AF48A03B,
+        0xFFFFFFFFFFFFFFFE,
+        0xFFFFFFFFFFFFFFFF,
+    ];
+}
+
+#[cfg(feature = "ff")]
+impl PrimeFieldParams<4> for Secp256k1Order {
+    const MODULUS_HEX: &'static str =
+        "0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+    const MULTIPLICATIVE_GENERATOR: u64 = 7;
+}
+
+pub type Fp127 = Fp<Mersenne127, 2>;
+pub type Fp25519 = Fp<Curve25519Base, 4>;
+/// The scalar field of Ed25519. An Ed25519 secret scalar (the clamped lower half of the SHA-512
+/// expansion of the seed) can be read with `from_le_bytes`, split into shares, and the
+/// reconstructed scalar signs exactly like the original because scalars only matter modulo the
+/// group order.
+pub type Ed25519Scalar = Fp<Ed25519Order, 4>;
+/// The scalar field of secp256k1. Bitcoin and Ethereum private keys are 32-byte big-endian
+/// integers in [1, n), which should be parsed with `from_be_bytes_canonical`.
+pub type Secp256k1Scalar = Fp<Secp256k1Order, 4>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::thread_rng;
+
+    const NTESTS: usize = 10;
+
+    #[test]
+    fn montgomery_constants() {
+        // 2^128 = 2 * (2^127 - 1) + 2
+        assert_eq!(Fp127::R, [2, 0]);
+        assert_eq!(Fp127::R2, [4, 0]);
+        assert_eq!(Fp127::INV.wrapping_mul(Mersenne127::MODULUS[0]), u64::MAX,);
+        assert_eq!(Fp25519::R, [38, 0, 0, 0]);
+    }
+
+    #[test]
+    fn fp25519_mul_inv() {
+        let lhs = Fp25519::from_limbs([
+            0x1AEDAAC2F439AD6A,
+            0x42A74ACE34E5278A,
+            0xB4887AC243FE7860,
+            0x10842B4874DDA9AD,
+        ])
+        .unwrap();
+        let rhs = Fp25519::from_limbs([
+            0x9BFF8FC848AE2C23,
+            0x339F8ECA4C0B0F50,
+            0x725C1244AA7129A7,
+            0x18F82DC8B51BD194,
+        ])
+        .unwrap();
+        let prod = [
+            0x5CB9703BB1899C4D,
+            0x68CF594083E357D6,
+            0x3A3CE01919E24CE2,
+            0x58F26B3D9A0BF740,
+        ];
+        let inv = [
+            0x249FBD6DB0B9B73E,
+            0x9587BA638F0E52DF,
+            0x4943A0A30F4FD94D,
+            0x6EB3D8A4C2AF366E,
+        ];
+        assert_eq!(lhs.modmul(&rhs).to_limbs(), prod);
+        assert_eq!(lhs.modinv().unwrap().to_limbs(), inv);
+    }
+
+    #[test]
+    fn uniform_sampling() {
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        struct Eleven;
+        impl FpParams<1> for Eleven {
+            const MODULUS: [u64; 1] = [11];
+        }
+        type F11 = Fp<Eleven, 1>;
+
+        assert_eq!(F11::TOP_MASK, 0xF);
+        assert_eq!(Fp127::TOP_MASK, u64::MAX >> 1);
+        assert_eq!(Ed25519Scalar::TOP_MASK, u64::MAX >> 3);
+        assert_eq!(Secp256k1Scalar::TOP_MASK, u64::MAX);
+
+        let mut counts = [0usize; 11];
+        for _ in 0..11_000 {
+            counts[F11::random(&mut thread_rng()).to_limbs()[0] as usize] += 1;
+        }
+        assert!(counts.iter().all(|count| (800..1200).contains(count)));
+    }
+
+    #[test]
+    fn fp127_edge_cases() {
+        assert!(Fp127::from_limbs(Mersenne127::MODULUS).is_none());
+        let minus_one = Fp127::ZERO.modsub(&Fp127::ONE);
+        assert_eq!(
+            minus_one.to_limbs(),
+            [0xFFFFFFFFFFFFFFFE, 0x7FFFFFFFFFFFFFFF]
+        );
+        assert!(minus_one.modmul(&minus_one).is_one());
+        assert!(minus_one.modadd(&Fp127::ONE).is_zero());
+        assert!(Fp127::ZERO.modinv().is_none());
+        assert!(matches!(
+            Fp127::ZERO.try_inv(),
+            Err(SssError::NotInvertible)
+        ));
+    }
+
+    #[test]
+    fn random_fp_inv() {
+        for _ in 0..NTESTS {
+            let elem = Fp127::random(&mut thread_rng());
+            if !elem.is_zero() {
+                assert!(elem.modmul(&elem.modinv().unwrap()).is_one());
+            }
+            let elem = Fp25519::random(&mut thread_rng());
+            if !elem.is_zero() {
+                assert!(elem.modmul(&elem.modinv().unwrap()).is_one());
+            }
+        }
+    }
+
+    #[test]
+    fn random_fp_serde() {
+        for _ in 0..NTESTS {
+            let lhs = Fp25519::random(&mut thread_rng());
+            let mut buf = [0u8; Fp25519::BYTES];
+            lhs.write_be_bytes(&mut buf);
+            assert_eq!(Fp25519::from_be_bytes(&buf), lhs);
+        }
+        // Non-canonical input is reduced: 2^256 - 1 = 2 * (2^255 - 19) + 37
+        let elem = Fp25519::from_be_bytes(&[0xFF; 32]);
+        assert_eq!(elem.to_limbs(), [37, 0, 0, 0]);
+    }
+
+    #[test]
+    fn ed25519_scalar_le_bytes() {
+        // 2^256 - 1 reduced under the group order
+        let elem = Ed25519Scalar::from_le_bytes(&[0xFF; 32]);
+        assert_eq!(
+            elem.to_limbs(),
+            [
+                0xD6EC31748D98951C,
+                0xC6EF5BF4737DCF70,
+                0xFFFFFFFFFFFFFFFE,
+                0x0FFFFFFFFFFFFFFF,
+            ]
+        );
+        let bytes: [u8; 32] = [
+            0xf5, 0xb1, 0x65, 0x22, 0x4a, 0x58, 0xb7, 0x91, 0xdf, 0x6a, 0xf1, 0xd8, 0x30, 0x3e,
+            0x61, 0xcd, 0xc4, 0xbb, 0x86, 0xc3, 0xd1, 0xc4, 0x27, 0x10, 0x3c, 0x34, 0x4c, 0x41,
+            0x71, 0xfd, 0xc5, 0x03,
+        ];
+        let elem = Ed25519Scalar::from_le_bytes(&bytes);
+        assert_eq!(
+            elem.to_limbs(),
+            [
+                0x91B7584A2265B1F5,
+                0xCD613E30D8F16ADF,
+                0x1027C4D1C386BBC4,
+                0x03C5FD71414C343C,
+            ]
+        );
+        let mut buf = [0u8; 32];
+        elem.write_le_bytes(&mut buf);
+        assert_eq!(buf, bytes);
+        assert!(Ed25519Scalar::from_limbs(Ed25519Order::MODULUS).is_none());
+    }
+
+    #[test]
+    fn random_ed25519_scalar_inv() {
+        for _ in 0..NTESTS {
+            let elem = Ed25519Scalar::random(&mut thread_rng());
+            if !elem.is_zero() {
+                assert!(elem.modmul(&elem.modinv().unwrap()).is_one());
+            }
+        }
+    }
+
+    #[test]
+    fn secp256k1_scalar_canonical_bytes() {
+        let mut n_bytes = [0u8; 32];
+        n_bytes
+            .iter_mut()
+            .zip(
+                [
+                    "FFFFFFFFFFFFFFFF",
+                    "FFFFFFFFFFFFFFFE",
+                    "BAAEDCE6AF48A03B",
+                    "BFD25E8CD0364141",
+                ]
+                .iter()
+                .flat_map(|limb| u64::from_str_radix(limb, 16).unwrap().to_be_bytes()),
+            )
+            .for_each(|(dst, src)| *dst = src);
+        assert!(matches!(
+            Secp256k1Scalar::from_be_bytes_canonical(&n_bytes),
+            Err(SssError::NonCanonicalElement)
+        ));
+        assert!(matches!(
+            Secp256k1Scalar::from_be_bytes_canonical(&[0xFF; 32]),
+            Err(SssError::NonCanonicalElement)
+        ));
+        assert!(matches!(
+            Secp256k1Scalar::from_be_bytes_canonical(&[0x01; 31]),
+            Err(SssError::FormatError { .. })
+        ));
+
+        n_bytes[31] -= 1;
+        let minus_one = Secp256k1Scalar::from_be_bytes_canonical(&n_bytes).unwrap();
+        assert!(minus_one.modadd(&Secp256k1Scalar::ONE).is_zero());
+        let mut buf = [0u8; 32];
+        minus_one.write_be_bytes(&mut buf);
+        assert_eq!(buf, n_bytes);
+    }
+
+    #[test]
+    fn random_secp256k1_scalar_inv() {
+        for _ in 0..NTESTS {
+            let elem = Secp256k1Scalar::random(&mut thread_rng());
+            if !elem.is_zero() {
+                assert!(elem.modmul(&elem.modinv().unwrap()).is_one());
+            }
+        }
+    }
+
+    /// Check the constants of ff's `PrimeField` against their definitions, and the square roots
+    /// and encodings of random elements, only through the ff traits
+    #[cfg(feature = "ff")]
+    fn check_prime_field<F: ff::PrimeField>(two_adicity: u32) {
+        assert_eq!(F::S, two_adicity);
+        assert_eq!(F::TWO_INV.double(), F::ONE);
+        assert_eq!(F::ROOT_OF_UNITY * F::ROOT_OF_UNITY_INV, F::ONE);
+        let root_powers: [F; 2] = core::array::from_fn(|i| {
+            (0..(F::S - 1 + i as u32)).fold(F::ROOT_OF_UNITY, |acc, _| acc.square())
+        });
+        assert_eq!(root_powers, [-F::ONE, F::ONE]);
+        let delta = (0..F::S).fold(F::MULTIPLICATIVE_GENERATOR, |acc, _| acc.square());
+        assert_eq!(F::DELTA, delta);
+        // The generator is a quadratic non-residue
+        assert!(bool::from(F::MULTIPLICATIVE_GENERATOR.sqrt().is_none()));
+
+        let mut rng = thread_rng();
+        for _ in 0..NTESTS {
+            let elem = F::random(&mut rng);
+            let square = elem.square();
+            let root = square.sqrt().unwrap();
+            assert!(root == elem || root == -elem);
+            let other = F::random(&mut rng);
+            let (is_square, root) = F::sqrt_ratio(&(square * other), &other);
+            assert!(bool::from(is_square));
+            assert_eq!(root.square(), square);
+            assert_eq!(F::from_repr(elem.to_repr()).unwrap(), elem);
+            assert_eq!(elem.is_odd().unwrap_u8() ^ elem.is_even().unwrap_u8(), 1);
+        }
+        let minus_one = -F::ONE;
+        assert!(bool::from(minus_one.is_even()));
+        let mut modulus = minus_one.to_repr();
+        modulus.as_mut()[0] += 1;
+        assert!(bool::from(F::from_repr(modulus).is_none()));
+        assert_eq!(F::from(7) * F::from(6), F::from(42));
+    }
+
+    #[test]
+    #[cfg(feature = "ff")]
+    fn ff_prime_field() {
+        check_prime_field::<Fp127>(1);
+        check_prime_field::<Fp25519>(2);
+        check_prime_field::<Ed25519Scalar>(2);
+        check_prime_field::<Secp256k1Scalar>(6);
+        assert_eq!(
+            <Fp25519 as ff::PrimeField>::MODULUS,
+            "0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+        );
+        assert_eq!(<Fp25519 as ff::PrimeField>::NUM_BITS, 255);
+    }
+}
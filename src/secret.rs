@@ -0,0 +1,58 @@
+//! A wrapper that keeps reconstructed secrets from being logged, copied, or left in freed memory
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A secret value that can only be read through an explicit call to `expose`. It does not
+/// implement `Clone`, its `Debug` output is redacted, and the value is zeroized when dropped.
+pub struct Secret<T: Zeroize> {
+    inner: T,
+}
+
+impl<T: Zeroize> Secret<T> {
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Borrow the secret value
+    pub fn expose(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutably borrow the secret value
+    pub fn expose_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T: Zeroize> From<T> for Secret<T> {
+    fn from(inner: T) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<T: Zeroize> core::fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Secret([REDACTED])")
+    }
+}
+
+impl<T: Zeroize> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.inner.zeroize();
+    }
+}
+
+impl<T: Zeroize> ZeroizeOnDrop for Secret<T> {}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expose_and_redact() {
+        let mut secret = Secret::new(b"Hello, world".to_vec());
+        assert_eq!(secret.expose(), b"Hello, world");
+        assert_eq!(format!("{secret:?}"), "Secret([REDACTED])");
+        secret.expose_mut().zeroize();
+        assert!(secret.expose().is_empty());
+    }
+}
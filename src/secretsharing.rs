@@ -1,6 +1,10 @@
 //! Top level secret sharing data structures and routines
+use crate::deterministic::DeterministicSampler;
+use crate::error::SssError;
 use crate::galoisfields::{FieldArithmetic, GF2p256};
+use crate::health::HealthCheckedRng;
 use crate::poly::{Poly256, Poly256Point};
+use crate::secret::Secret;
 use aes_gcm::{
     aead::{Aead, AeadCore, KeyInit},
     Aes256Gcm, Key,
@@ -10,35 +14,6 @@ use rand::{rngs::OsRng, CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Sha3_256};
 
-pub type SecretSharingResult<T> = Result<T, SecretSharingError>;
-
-#[derive(Debug)]
-pub enum SecretSharingError {
-    NoCiphertext,
-    TooFewShards {
-        expect: usize,
-        has: usize,
-    },
-    InvalidShares(String),
-    InvalidNonce(String),
-    EmptySharesInput,
-    AesGcmError(aes_gcm::Error),
-    /// some input base64 encoding is invalid
-    Base64DecodingError,
-    /// Something went wrong when serializaing to TOML
-    TOMLSerializationError,
-    /// something went wrong when deserializing from TOML
-    TOMLDeserializationError,
-}
-
-impl core::fmt::Display for SecretSharingError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
-    }
-}
-
-impl core::error::Error for SecretSharingError {}
-
 /// secret sharing using polynomials in GF(2^256)[x]
 pub struct SecretSharing256 {
     secret_poly: Poly256,
@@ -53,8 +28,10 @@ pub struct SecretSharing256 {
     pub shards: Vec<Poly256Point>,
 }
 
-/// Convenient struct for serde
-#[derive(Debug, Serialize, Deserialize)]
+/// Convenient struct for serde. `Debug` redacts the share's point unless the `debug-secrets`
+/// feature is enabled.
+#[cfg_attr(feature = "debug-secrets", derive(Debug))]
+#[derive(Serialize, Deserialize)]
 pub struct SecretShare {
     threshold: usize,
     nonce: String,
@@ -63,15 +40,82 @@ pub struct SecretShare {
     secret_fx: String,
 }
 
+#[cfg(not(feature = "debug-secrets"))]
+impl core::fmt::Debug for SecretShare {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SecretShare")
+            .field("threshold", &self.threshold)
+            .field("nonce", &self.nonce)
+            .field("ciphertext", &self.ciphertext)
+            .field("secret_x", &format_args!("REDACTED"))
+            .field("secret_fx", &format_args!("REDACTED"))
+            .finish()
+    }
+}
+
 impl SecretShare {
-    pub fn to_string(&self) -> Result<String, SecretSharingError> {
-        toml::to_string_pretty(&self).map_err(|_e| SecretSharingError::TOMLSerializationError)
+    pub fn to_string(&self) -> Result<String, SssError> {
+        toml::to_string_pretty(&self).map_err(|_e| SssError::FormatError { what: "TOML" })
+    }
+    pub fn from_string(from_str: &str) -> Result<Self, SssError> {
+        toml::from_str(from_str).map_err(|_e| SssError::FormatError { what: "TOML" })
+    }
+}
+
+impl zeroize::Zeroize for SecretShare {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.threshold);
+        zeroize::Zeroize::zeroize(&mut self.nonce);
+        zeroize::Zeroize::zeroize(&mut self.ciphertext);
+        zeroize::Zeroize::zeroize(&mut self.secret_x);
+        zeroize::Zeroize::zeroize(&mut self.secret_fx);
     }
-    pub fn from_string(from_str: &str) -> Result<Self, SecretSharingError> {
-        toml::from_str(from_str).map_err(|_e| SecretSharingError::TOMLDeserializationError)
+}
+
+impl Drop for SecretShare {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
     }
 }
 
+impl zeroize::ZeroizeOnDrop for SecretShare {}
+
+/// Shares with a well-formed encoding: the nonce is 12 bytes and the point is a pair of
+/// serialized `GF2p256` elements, all base64-encoded. The threshold, the ciphertext, and the point
+/// are otherwise arbitrary, so the share need not decrypt anything.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SecretShare {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let encode = |elem: GF2p256| {
+            let mut buf = [0u8; GF2p256::BYTES];
+            elem.write_be_bytes(&mut buf);
+            BASE64_STANDARD.encode(buf)
+        };
+        let threshold = u.int_in_range(1..=u8::MAX as usize)?;
+        let nonce: [u8; 12] = u.arbitrary()?;
+        let ciphertext: Vec<u8> = u.arbitrary()?;
+
+        Ok(Self {
+            threshold,
+            nonce: BASE64_STANDARD.encode(nonce),
+            ciphertext: BASE64_STANDARD.encode(ciphertext),
+            secret_x: encode(u.arbitrary()?),
+            secret_fx: encode(u.arbitrary()?),
+        })
+    }
+}
+
+/// Wipe the secret polynomial and the shards, which are enough to recover the AES key. The cipher
+/// itself keeps its own copy of the expanded key.
+impl Drop for SecretSharing256 {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.secret_poly);
+        zeroize::Zeroize::zeroize(&mut self.shards);
+    }
+}
+
+impl zeroize::ZeroizeOnDrop for SecretSharing256 {}
+
 impl SecretSharing256 {
     /// Generate a random polynomial and hash it into an AES key. The nonce will be randomly
     /// generated and will be included in the ciphertext.
@@ -83,7 +127,7 @@ impl SecretSharing256 {
         secret_poly.update_hasher(&mut hasher);
         let result = hasher.finalize(); // GenericArray<u8, OutputSize>
                                         // println!("Polynomial hashes into: {:?}", &result);
-        let key: Key<Aes256Gcm> = result.into();
+        let key: Key<Aes256Gcm> = result;
         let cipher = Aes256Gcm::new(&key);
         let nonce: [u8; 12] = Aes256Gcm::generate_nonce(rng).into();
 
@@ -101,17 +145,86 @@ impl SecretSharing256 {
         self.secret_poly.capacity()
     }
 
+    /// Check the parameters of a `threshold`-of-`n` sharing. A threshold below 2 hands the whole
+    /// secret to every shareholder, and a threshold above `n` can never be met. There is no upper
+    /// bound on `n` itself, since a usize cannot exceed the 2^256 - 1 non-zero x-coordinates.
+    pub fn check_parameters(threshold: usize, n: usize) -> Result<(), SssError> {
+        if threshold < 2 || threshold > n {
+            return Err(SssError::InvalidThreshold {
+                threshold,
+                shares: n,
+            });
+        }
+        Ok(())
+    }
+
+    /// Initialize a session and deal `n` shares after checking the parameters with
+    /// `check_parameters`. A zero or repeated x-coordinate happens with probability about 2^-256
+    /// for a working RNG, so instead of resampling, it is reported as an error.
+    pub fn split(
+        rng: &mut (impl Rng + CryptoRng),
+        threshold: usize,
+        n: usize,
+    ) -> Result<Self, SssError> {
+        Self::check_parameters(threshold, n)?;
+        let mut secret_sharing = Self::init_with_rng(rng, threshold);
+        let mut xs = Vec::with_capacity(n);
+        for _ in 0..n {
+            let x = GF2p256::random(rng);
+            if x.is_zero() {
+                return Err(SssError::ZeroShareIndex);
+            }
+            if xs.contains(&x) {
+                return Err(SssError::DuplicateShareIndex);
+            }
+            xs.push(x);
+        }
+        secret_sharing.shards = secret_sharing.evaluate_shards(xs);
+        Ok(secret_sharing)
+    }
+
+    /// Initialize a session and deal `n` shares with every random choice drawn from a
+    /// `DeterministicSampler`, so that the same seed always produces the same polynomial, nonce,
+    /// and shares. This is meant for reproducible test vectors: the seed reveals the secret.
+    pub fn split_deterministic(
+        seed: &[u8; 32],
+        threshold: usize,
+        n: usize,
+    ) -> Result<Self, SssError> {
+        Self::split(&mut DeterministicSampler::new(seed, b"split"), threshold, n)
+    }
+
+    /// Initialize a session and deal `n` shares like `init_with_rng` and `safe_split_with_rng`,
+    /// while running the continuous health tests of `crate::health` on every byte drawn from the
+    /// RNG. If a test fails, the dealt polynomial is discarded and an error is returned.
+    pub fn split_with_health_checks(
+        rng: &mut (impl Rng + CryptoRng),
+        threshold: usize,
+        n: usize,
+    ) -> Result<Self, SssError> {
+        let mut rng = HealthCheckedRng::new(rng);
+        let secret_sharing = Self::split(&mut rng, threshold, n);
+        if !rng.is_healthy() {
+            return Err(SssError::UnhealthyRng);
+        }
+        secret_sharing
+    }
+
     /// Use the default OsRng to initialize a secret sharing session
     pub fn init(threshold: usize) -> Self {
         Self::init_with_rng(&mut OsRng, threshold)
     }
 
-    /// Feed a segment of the msg into the cipher
-    pub fn encrypt(&mut self, msg: &[u8]) -> Result<(), SecretSharingError> {
+    /// Feed a segment of the msg into the cipher. An empty message is rejected, because its
+    /// ciphertext would be indistinguishable from having no ciphertext at all.
+    pub fn encrypt(&mut self, msg: &[u8]) -> Result<(), SssError> {
+        if msg.is_empty() {
+            return Err(SssError::EmptySecret);
+        }
         let ciphertext = self
             .cipher
             .encrypt(&self.nonce.into(), msg)
-            .map_err(|aes_gcm_error| SecretSharingError::AesGcmError(aes_gcm_error))?;
+            .map_err(SssError::AesGcmError)?;
         self.ciphertext = ciphertext;
         Ok(())
     }
@@ -121,28 +234,50 @@ impl SecretSharing256 {
         self.shards.iter().any(|shard| shard.x == *val)
     }
 
+    /// Evaluate the secret polynomial at each of the input points. The evaluations are
+    /// independent, so with the `parallel` feature they are spread across threads with rayon
+    #[cfg(feature = "parallel")]
+    fn evaluate_shards(&self, xs: Vec<GF2p256>) -> Vec<Poly256Point> {
+        use rayon::prelude::*;
+
+        xs.into_par_iter()
+            .map(|x| Poly256Point::from_vals(x, self.secret_poly.evaluate(&x)))
+            .collect()
+    }
+
+    /// Evaluate the secret polynomial at each of the input points
+    #[cfg(not(feature = "parallel"))]
+    fn evaluate_shards(&self, xs: Vec<GF2p256>) -> Vec<Poly256Point> {
+        let fxs = self.secret_poly.evaluate_many(&xs);
+        xs.into_iter()
+            .zip(fxs)
+            .map(|(x, fx)| Poly256Point::from_vals(x, fx))
+            .collect()
+    }
+
     /// Sample the specified number of points. This method will check to ensure all points are
-    /// distinct, which might incur performance penalty
+    /// distinct and non-zero, which might incur performance penalty
     pub fn safe_split_with_rng(&mut self, n: usize, rng: &mut (impl Rng + CryptoRng)) {
-        (0..n).for_each(|_| 'rejsample: loop {
-            let x = GF2p256::random_with_rng(rng);
-            if !self.contains_point(&x) {
-                self.shards
-                    .push(Poly256Point::from_vals(x, self.secret_poly.evaluate(&x)));
-                break 'rejsample;
+        let mut xs = Vec::with_capacity(n);
+        while xs.len() < n {
+            let x = GF2p256::random(rng);
+            if !x.is_zero() && !self.contains_point(&x) && !xs.contains(&x) {
+                xs.push(x);
             }
-        });
+        }
+        let shards = self.evaluate_shards(xs);
+        self.shards.extend(shards);
     }
 
     /// Sample the specified number of points. This method will not check that all points are
     /// distinct and is thus faster. However, the probability of collision is cryptographically
     /// small with a large field such as GF(2^256)
     pub fn fast_split_with_rng(&mut self, n: usize, rng: &mut (impl Rng + CryptoRng)) {
-        for _ in 0..n {
-            let x = GF2p256::random_with_rng(rng);
-            self.shards
-                .push(Poly256Point::from_vals(x, self.secret_poly.evaluate(&x)));
-        }
+        let xs = (0..n)
+            .map(|_| GF2p256::random(rng))
+            .collect::<Vec<GF2p256>>();
+        let shards = self.evaluate_shards(xs);
+        self.shards.extend(shards);
     }
 
     /// Sample the specified number of points using the default OsRng. This method will check all
@@ -159,27 +294,34 @@ impl SecretSharing256 {
 
     /// Decrypt the ciphertext using a symmetric key derived from hashing the secret polynomial
     /// interpolating the input shards
-    /// Caller is responsible for supplying the correct number of shards
+    /// Caller is responsible for supplying the correct number of shards. The plaintext is wrapped
+    /// in a `Secret` so that it is zeroized once the caller is done with it
     pub fn decrypt(
         ciphertext: &[u8],
         nonce: &[u8],
         shards: &[Poly256Point],
-    ) -> Result<Vec<u8>, SecretSharingError> {
+    ) -> Result<Secret<Vec<u8>>, SssError> {
+        if shards.is_empty() {
+            return Err(SssError::NotEnoughShares { expect: 1, has: 0 });
+        }
+        if nonce.len() != 12 {
+            return Err(SssError::FormatError { what: "nonce" });
+        }
         let points = shards
             .iter()
-            .map(|shard| (shard.x.clone(), shard.fx.clone()))
+            .map(|shard| (shard.x, shard.fx))
             .collect::<Vec<(GF2p256, GF2p256)>>();
-        let recovered_poly = Poly256::interpolate(&points, shards.len());
+        let recovered_poly = Poly256::try_interpolate(&points, shards.len())?;
         let mut hasher: Sha3_256 = Digest::new();
         recovered_poly.update_hasher(&mut hasher);
         let result = hasher.finalize(); // GenericArray<u8, OutputSize>
                                         // println!("Polynomial hashes into: {:?}", &result);
-        let key: Key<Aes256Gcm> = result.into();
+        let key: Key<Aes256Gcm> = result;
         let cipher = Aes256Gcm::new(&key);
 
         match cipher.decrypt(nonce.into(), ciphertext) {
-            Ok(decryption) => Ok(decryption),
-            Err(e) => Err(SecretSharingError::AesGcmError(e)),
+            Ok(decryption) => Ok(Secret::new(decryption)),
+            Err(e) => Err(SssError::AesGcmError(e)),
         }
     }
 
@@ -187,17 +329,17 @@ impl SecretSharing256 {
     /// which can then be written to a file for further storage.
     /// If there is no ciphertext or if the number of shards is less than the threshold, then
     /// return appropriate error.
-    pub fn stringify_shards(&self) -> Result<Vec<SecretShare>, SecretSharingError> {
-        if self.ciphertext.len() == 0 {
-            return Err(SecretSharingError::NoCiphertext);
+    pub fn stringify_shards(&self) -> Result<Vec<SecretShare>, SssError> {
+        if self.ciphertext.is_empty() {
+            return Err(SssError::NoCiphertext);
         }
         if self.shards.len() < self.threshold() {
-            return Err(SecretSharingError::TooFewShards {
+            return Err(SssError::NotEnoughShares {
                 expect: self.threshold(),
                 has: self.shards.len(),
             });
         }
-        let nonce_str = BASE64_STANDARD.encode(&self.nonce);
+        let nonce_str = BASE64_STANDARD.encode(self.nonce);
         let ciphertext_str = BASE64_STANDARD.encode(&self.ciphertext);
         let shares = self
             .shards
@@ -205,9 +347,9 @@ impl SecretSharing256 {
             .map(|shard| {
                 let mut buf = [0u8; GF2p256::BYTES];
                 shard.x.write_be_bytes(&mut buf);
-                let secret_x_str = BASE64_STANDARD.encode(&buf);
+                let secret_x_str = BASE64_STANDARD.encode(buf);
                 shard.fx.write_be_bytes(&mut buf);
-                let secret_fx_str = BASE64_STANDARD.encode(&buf);
+                let secret_fx_str = BASE64_STANDARD.encode(buf);
 
                 SecretShare {
                     threshold: self.threshold(),
@@ -225,11 +367,9 @@ impl SecretSharing256 {
     /// Attempt to decrypt using the input set of shares
     /// The shares need to have identical threshold, nonce, ciphertext, and distinct secret_x, or
     /// they will be considered invalid input
-    pub fn decrypt_from_secret_shares(
-        shares: &[SecretShare],
-    ) -> Result<Vec<u8>, SecretSharingError> {
-        if shares.len() == 0 {
-            return Err(SecretSharingError::EmptySharesInput);
+    pub fn decrypt_from_secret_shares(shares: &[SecretShare]) -> Result<Secret<Vec<u8>>, SssError> {
+        if shares.is_empty() {
+            return Err(SssError::NotEnoughShares { expect: 1, has: 0 });
         }
         for i in 1..(shares.len()) {
             let share = &shares[i];
@@ -237,25 +377,27 @@ impl SecretSharing256 {
                 || share.threshold != shares[0].threshold
                 || share.ciphertext != shares[0].ciphertext
             {
-                return Err(SecretSharingError::InvalidShares(
-                    "Nonce, threshold, or ciphertext is inconsistent".into(),
-                ));
+                return Err(SssError::InconsistentShares);
             }
         }
         let nonce = BASE64_STANDARD
             .decode(&shares[0].nonce)
-            .map_err(|_b64_err| SecretSharingError::Base64DecodingError)?;
+            .map_err(|_b64_err| SssError::FormatError { what: "nonce" })?;
         if nonce.len() != aes_gcm::Aes256Gcm::generate_nonce(&mut OsRng).len() {
-            return Err(SecretSharingError::InvalidNonce(
-                "expect nonce to be 96-bit".into(),
-            ));
+            return Err(SssError::FormatError { what: "nonce" });
         }
         let ciphertext = BASE64_STANDARD
             .decode(&shares[0].ciphertext)
-            .map_err(|_b64_err| SecretSharingError::Base64DecodingError)?;
+            .map_err(|_b64_err| SssError::FormatError { what: "ciphertext" })?;
         let threshold = shares[0].threshold;
+        if threshold == 0 {
+            return Err(SssError::InvalidThreshold {
+                threshold,
+                shares: shares.len(),
+            });
+        }
         if shares.len() < threshold {
-            return Err(SecretSharingError::TooFewShards {
+            return Err(SssError::NotEnoughShares {
                 expect: threshold,
                 has: shares.len(),
             });
@@ -263,7 +405,7 @@ impl SecretSharing256 {
         let top_shares = match shares.get(..threshold) {
             Some(slice) => slice,
             None => {
-                return Err(SecretSharingError::TooFewShards {
+                return Err(SssError::NotEnoughShares {
                     expect: threshold,
                     has: shares.len(),
                 });
@@ -271,23 +413,24 @@ impl SecretSharing256 {
         };
         // This is the coolest shit!
         // An Iterator<Item = Result<T, E>> can be collected into a Result<Vec<T>, E>
+        let decode_elem = |encoded: &str| {
+            let bytes = BASE64_STANDARD
+                .decode(encoded)
+                .map_err(|_b64err| SssError::FormatError { what: "coordinate" })?;
+            if bytes.len() != GF2p256::BYTES {
+                return Err(SssError::FormatError { what: "coordinate" });
+            }
+            Ok(GF2p256::from_be_bytes(&bytes))
+        };
         let points = top_shares
             .iter()
             .map(|share| {
-                let x = GF2p256::from_be_bytes(
-                    &BASE64_STANDARD
-                        .decode(&share.secret_x)
-                        .map_err(|_b64err| SecretSharingError::Base64DecodingError)?,
-                );
-                let fx = GF2p256::from_be_bytes(
-                    &BASE64_STANDARD
-                        .decode(&share.secret_fx)
-                        .map_err(|_b64err| SecretSharingError::Base64DecodingError)?,
-                );
+                let x = decode_elem(&share.secret_x)?;
+                let fx = decode_elem(&share.secret_fx)?;
                 let point = Poly256Point::from_vals(x, fx);
                 Ok(point)
             })
-            .collect::<Result<Vec<Poly256Point>, SecretSharingError>>()?;
+            .collect::<Result<Vec<Poly256Point>, SssError>>()?;
 
         Self::decrypt(&ciphertext, &nonce, &points)
     }
@@ -298,7 +441,78 @@ mod tests {
     use super::*;
 
     #[test]
-    fn random_secret_sharing_256() -> Result<(), SecretSharingError> {
+    fn malformed_coordinates_are_rejected() -> Result<(), SssError> {
+        let mut secret_sharing = SecretSharing256::init(1);
+        secret_sharing.safe_split(1);
+        secret_sharing.encrypt(b"Hello, world")?;
+        let mut shares = secret_sharing.stringify_shards()?;
+        shares[0].secret_x = BASE64_STANDARD.encode([0u8; 5]);
+        assert!(matches!(
+            SecretSharing256::decrypt_from_secret_shares(&shares),
+            Err(SssError::FormatError { what: "coordinate" })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn arbitrary_share() -> Result<(), SssError> {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes = (0..=255).collect::<Vec<u8>>();
+        let share = SecretShare::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        let parsed = SecretShare::from_string(&share.to_string()?)?;
+        assert_eq!(parsed.threshold, share.threshold);
+        for (field, len) in [
+            (&parsed.nonce, 12),
+            (&parsed.secret_x, GF2p256::BYTES),
+            (&parsed.secret_fx, GF2p256::BYTES),
+        ] {
+            assert_eq!(BASE64_STANDARD.decode(field).unwrap().len(), len);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn zeroize_share() -> Result<(), SssError> {
+        use zeroize::Zeroize;
+
+        let mut secret_sharing = SecretSharing256::init(2);
+        secret_sharing.safe_split(2);
+        secret_sharing.encrypt(b"Hello, world")?;
+        let mut share = secret_sharing.stringify_shards()?.remove(0);
+        share.zeroize();
+        assert_eq!(share.threshold, 0);
+        assert!(share.secret_x.is_empty() && share.secret_fx.is_empty());
+
+        secret_sharing.secret_poly.zeroize();
+        assert!(secret_sharing.secret_poly.coeffs.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_shares_are_rejected() -> Result<(), SssError> {
+        let mut secret_sharing = SecretSharing256::init(2);
+        secret_sharing.safe_split(2);
+        secret_sharing.encrypt(b"Hello, world")?;
+        let shares = secret_sharing.stringify_shards()?;
+        let duplicated = [
+            SecretShare::from_string(&shares[0].to_string()?)?,
+            SecretShare::from_string(&shares[0].to_string()?)?,
+        ];
+        assert!(matches!(
+            SecretSharing256::decrypt_from_secret_shares(&duplicated),
+            Err(SssError::DuplicateShareIndex)
+        ));
+        assert!(matches!(
+            SecretSharing256::decrypt(&secret_sharing.ciphertext, &secret_sharing.nonce, &[]),
+            Err(SssError::NotEnoughShares { expect: 1, has: 0 })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn random_secret_sharing_256() -> Result<(), SssError> {
         let threshold = 3;
         let redundancy = 10;
         let secret_msg = b"Hello, world";
@@ -310,13 +524,164 @@ mod tests {
             &secret_sharing.nonce,
             &secret_sharing.shards[..threshold],
         )?;
-        assert_eq!(decryption, secret_msg);
+        assert_eq!(decryption.expose(), secret_msg);
 
         Ok(())
     }
 
     #[test]
-    fn random_secret_sharing_256_stringified() -> Result<(), SecretSharingError> {
+    fn tampered_shard_fails_to_decrypt() -> Result<(), SssError> {
+        let threshold = 3;
+        let mut secret_sharing = SecretSharing256::init(threshold);
+        secret_sharing.safe_split(threshold);
+        secret_sharing.encrypt(b"Hello, world")?;
+        let mut shards = secret_sharing
+            .shards
+            .iter()
+            .map(|shard| Poly256Point::from_vals(shard.x, shard.fx))
+            .collect::<Vec<Poly256Point>>();
+        shards[1].fx = shards[1].fx.modadd(&GF2p256::ONE);
+        assert!(matches!(
+            SecretSharing256::decrypt(&secret_sharing.ciphertext, &secret_sharing.nonce, &shards),
+            Err(SssError::AesGcmError(_))
+        ));
+        // Too few shards interpolate a different polynomial, and so a different key
+        assert!(SecretSharing256::decrypt(
+            &secret_sharing.ciphertext,
+            &secret_sharing.nonce,
+            &secret_sharing.shards[..threshold - 1],
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_deterministic_vector() {
+        let hex = |elem: &GF2p256| {
+            let mut buf = [0u8; GF2p256::BYTES];
+            elem.write_be_bytes(&mut buf);
+            buf.iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>()
+        };
+        let points = |secret_sharing: &SecretSharing256| {
+            secret_sharing
+                .shards
+                .iter()
+                .map(|shard| (shard.x, shard.fx))
+                .collect::<Vec<(GF2p256, GF2p256)>>()
+        };
+        let secret_sharing = SecretSharing256::split_deterministic(&[0; 32], 3, 5).unwrap();
+        let again = SecretSharing256::split_deterministic(&[0; 32], 3, 5).unwrap();
+        assert_eq!(secret_sharing.nonce, again.nonce);
+        assert_eq!(points(&secret_sharing), points(&again));
+        assert_ne!(
+            points(&secret_sharing),
+            points(&SecretSharing256::split_deterministic(&[1; 32], 3, 5).unwrap())
+        );
+
+        let shard = &secret_sharing.shards[0];
+        assert_eq!(
+            hex(&shard.x),
+            "9fc0855520765cf241ccf9e6a4dd34d68922ba5611604380958a5a241b2f0066"
+        );
+        assert_eq!(
+            hex(&shard.fx),
+            "80f69499a09bb87b1e0940c2f7d3d757d2244afe37c9c0b062968ea5078e8fa0"
+        );
+    }
+
+    #[test]
+    fn split_with_health_checks() {
+        let secret_sharing = SecretSharing256::split_with_health_checks(&mut OsRng, 3, 5).unwrap();
+        assert_eq!(secret_sharing.shards.len(), 5);
+
+        /// A generator stuck on one byte
+        struct Stuck;
+        impl rand::RngCore for Stuck {
+            fn next_u32(&mut self) -> u32 {
+                0
+            }
+            fn next_u64(&mut self) -> u64 {
+                0
+            }
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                dest.fill(0);
+            }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+        impl CryptoRng for Stuck {}
+
+        assert!(matches!(
+            SecretSharing256::split_with_health_checks(&mut Stuck, 2, 2),
+            Err(SssError::UnhealthyRng)
+        ));
+    }
+
+    #[test]
+    fn split_rejects_degenerate_parameters() {
+        for (threshold, n) in [(0, 5), (1, 5), (6, 5), (2, 0)] {
+            assert!(matches!(
+                SecretSharing256::split(&mut OsRng, threshold, n),
+                Err(SssError::InvalidThreshold { .. })
+            ));
+        }
+        let mut secret_sharing = SecretSharing256::split(&mut OsRng, 2, 2).unwrap();
+        assert!(matches!(
+            secret_sharing.encrypt(b""),
+            Err(SssError::EmptySecret)
+        ));
+
+        /// A generator whose x-coordinates are all zero, but which passes the health tests
+        struct ZeroPoints(u8);
+        impl rand::RngCore for ZeroPoints {
+            fn next_u32(&mut self) -> u32 {
+                rand_core::impls::next_u32_via_fill(self)
+            }
+            fn next_u64(&mut self) -> u64 {
+                rand_core::impls::next_u64_via_fill(self)
+            }
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                // The polynomial and the nonce take the first 76 bytes
+                for byte in dest.iter_mut() {
+                    *byte = if self.0 < 76 { self.0 } else { 0 };
+                    self.0 = self.0.saturating_add(1);
+                }
+            }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+        impl CryptoRng for ZeroPoints {}
+        assert!(matches!(
+            SecretSharing256::split(&mut ZeroPoints(0), 2, 2),
+            Err(SssError::ZeroShareIndex)
+        ));
+    }
+
+    #[test]
+    fn split_evaluates_secret_polynomial() {
+        let mut secret_sharing = SecretSharing256::init(4);
+        secret_sharing.fast_split(20);
+        secret_sharing.safe_split(20);
+        assert_eq!(secret_sharing.shards.len(), 40);
+        for shard in secret_sharing.shards.iter() {
+            assert_eq!(shard.fx, secret_sharing.secret_poly.evaluate(&shard.x));
+        }
+        for (i, shard) in secret_sharing.shards.iter().enumerate().skip(20) {
+            assert!(!secret_sharing.shards[..i]
+                .iter()
+                .any(|other| other.x == shard.x));
+        }
+    }
+
+    #[test]
+    fn random_secret_sharing_256_stringified() -> Result<(), SssError> {
         let threshold = 3;
         let redundancy = 10;
         let secret_msg = b"Hello, world";
@@ -330,10 +695,10 @@ mod tests {
             .collect::<Result<Vec<String>, _>>()?;
         let shares = shares_strs
             .iter()
-            .map(|share_str| SecretShare::from_string(&share_str))
+            .map(|share_str| SecretShare::from_string(share_str))
             .collect::<Result<Vec<SecretShare>, _>>()?;
         let decryption = SecretSharing256::decrypt_from_secret_shares(&shares)?;
-        assert_eq!(decryption, secret_msg);
+        assert_eq!(decryption.expose(), secret_msg);
 
         Ok(())
     }
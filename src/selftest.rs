@@ -0,0 +1,282 @@
+//! Power-on self-test of the field arithmetic and of secret sharing
+//!
+//! `self_test` runs a known-answer test for every field compiled into this crate (except the toy
+//! `F3329`, which cannot be serialized) and, with the `std` and `gf256` features, a full
+//! split/reconstruct round-trip, so that applications which must check their cryptographic
+//! primitives before use (e.g. at boot) can do so with a single call.
+//!
+//! Each known-answer test raises a small element to two fixed powers, multiplies the results, and
+//! inverts the first one. The outputs are hashed with SHA3-256 after stripping the leading zero
+//! bytes, so that the expected digest does not depend on the width of the limbs.
+use crate::galoisfields::FieldArithmetic;
+#[cfg(feature = "gf128")]
+use crate::galoisfields::GF2p128;
+#[cfg(feature = "gf16")]
+use crate::galoisfields::GF2p16;
+#[cfg(feature = "gf192")]
+use crate::galoisfields::GF2p192;
+#[cfg(feature = "gf256")]
+use crate::galoisfields::GF2p256;
+#[cfg(feature = "gf8")]
+use crate::galoisfields::GF2p8;
+#[cfg(feature = "binary-curves")]
+use crate::galoisfields::{GF2p233, GF2p283, GF2p409, GF2p571};
+#[cfg(feature = "gf128")]
+use crate::gcm::GF2p128Gcm;
+#[cfg(all(feature = "std", feature = "gf256"))]
+use crate::poly::Poly256;
+use crate::primefields::{Ed25519Scalar, Fp127, Fp25519, Secp256k1Scalar};
+#[cfg(all(feature = "std", feature = "gf256"))]
+use crate::secretsharing::SecretSharing256;
+#[cfg(feature = "gf128")]
+use crate::tower::GF2p16p8;
+use sha3::{Digest, Sha3_256};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SelfTestError {
+    /// The known-answer test of the named field did not reproduce the expected outputs
+    FieldArithmetic(&'static str),
+    /// The shares of a fixed secret did not interpolate a polynomial through all of them, or the
+    /// key derived from it did not decrypt the original message
+    RoundTrip,
+}
+
+impl core::fmt::Display for SelfTestError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl core::error::Error for SelfTestError {}
+
+/// The message encrypted under the secret of the round-trip test
+#[cfg(all(feature = "std", feature = "gf256"))]
+const ROUND_TRIP_MESSAGE: &[u8] = b"shamir secret sharing self-test";
+
+/// The exponents of the two operands of every known-answer test
+const KAT_EXPONENTS: (u64, u64) = (0x0123_4567_89AB_CDEF, 0xFEDC_BA98_7654_3210);
+
+/// The serialized size of the widest field under test, GF(2^571)
+const KAT_MAX_BYTES: usize = 72;
+
+/// Run the known-answer tests of every compiled-in field and a split/reconstruct round-trip
+pub fn self_test() -> Result<(), SelfTestError> {
+    #[cfg(feature = "gf8")]
+    field_kat::<GF2p8>(
+        "GF2p8",
+        1,
+        "cb4cab6cc8ae8fb46ab9ae418c4634ead8e2bc2422455055f1a5d9854338d9db",
+    )?;
+    #[cfg(feature = "gf16")]
+    field_kat::<GF2p16>(
+        "GF2p16",
+        2,
+        "daf675f73a5f92e1d6a88a452ca51f2a20800a2757a6c65f5cd604e9f4a56b05",
+    )?;
+    #[cfg(feature = "gf128")]
+    field_kat::<GF2p128>(
+        "GF2p128",
+        16,
+        "040bace1972fa7517352d5311d8cd70aa990240eccf2d03a6aafa174241a23fb",
+    )?;
+    #[cfg(feature = "gf128")]
+    field_kat::<GF2p128Gcm>(
+        "GF2p128Gcm",
+        16,
+        "089d2c4da69a8c7d3b2b518195ed3fc087dae21e0ea15ffbbc6be80944aac523",
+    )?;
+    #[cfg(feature = "gf128")]
+    field_kat::<GF2p16p8>(
+        "GF2p16p8",
+        16,
+        "e959d30c31481e5b86ce9d6038c09c261fa11fae71d82beb02e234583713d60f",
+    )?;
+    #[cfg(feature = "gf192")]
+    field_kat::<GF2p192>(
+        "GF2p192",
+        24,
+        "be158a585a56ba35a7e78e2cdfe397466dcb92e6c5865903d536b7f41f467376",
+    )?;
+    #[cfg(feature = "binary-curves")]
+    field_kat::<GF2p233>(
+        "GF2p233",
+        29,
+        "3fa89928583156865bd1cf43e1d39ed3e5097134faff9cf423ef1f6ec88e612d",
+    )?;
+    #[cfg(feature = "gf256")]
+    field_kat::<GF2p256>(
+        "GF2p256",
+        32,
+        "7735ae5d9e2f393701e0372fc0876d3dd374829657e32fa13bace57b6f7fb4e3",
+    )?;
+    #[cfg(feature = "binary-curves")]
+    field_kat::<GF2p283>(
+        "GF2p283",
+        35,
+        "259879dfde6c5b8a6b874105c16d4ec0db2e056dee12d9560f5af775d8eceb25",
+    )?;
+    #[cfg(feature = "binary-curves")]
+    field_kat::<GF2p409>(
+        "GF2p409",
+        51,
+        "1fe96368f39ce0725360af6abd6d5f054a77ac2dc530804816a5a4713502adce",
+    )?;
+    #[cfg(feature = "binary-curves")]
+    field_kat::<GF2p571>(
+        "GF2p571",
+        71,
+        "843255f1ab1cfa9c138b6af8d702a263e241e0d0a437e529ba74e3f223f908e6",
+    )?;
+    field_kat::<Fp127>(
+        "Fp127",
+        15,
+        "f0f2d67165482483bb65113d75c168daa479849ca913df65f6ee82705b985f84",
+    )?;
+    field_kat::<Fp25519>(
+        "Fp25519",
+        31,
+        "c0f189f7e3043fe1f7fb4781580380580ce26ad84b36a8fb174d52365ef922df",
+    )?;
+    field_kat::<Ed25519Scalar>(
+        "Ed25519Scalar",
+        31,
+        "63ee3c4d2dcb3983f715caae9976ef9cd09720255b116fba96183d770b8099f0",
+    )?;
+    field_kat::<Secp256k1Scalar>(
+        "Secp256k1Scalar",
+        31,
+        "59b9ee54d399536a4d9ffa7a401922c4f57c652fd42e90749cb52f262ea59418",
+    )?;
+    #[cfg(all(feature = "std", feature = "gf256"))]
+    round_trip()?;
+    Ok(())
+}
+
+/// Hash the outputs of the known-answer test of F. The base element is encoded in the lowest
+/// `base_bytes` bytes, which must be few enough for the encoding to already be reduced.
+fn kat_outputs<F: FieldArithmetic>(base_bytes: usize) -> Option<[u8; 32]> {
+    let mut storage = [0u8; KAT_MAX_BYTES];
+    let buf = &mut storage[..F::bytes()];
+    let start = buf.len() - base_bytes;
+    for (i, byte) in buf[start..].iter_mut().enumerate() {
+        *byte = (0x53 + 0x9D * i) as u8;
+    }
+    let base = F::from_be_bytes(buf);
+    let lhs = base.modexp(KAT_EXPONENTS.0);
+    let rhs = base.modexp(KAT_EXPONENTS.1);
+    let inv = lhs.modinv()?;
+    if !lhs.modmul(&inv).is_one() {
+        return None;
+    }
+
+    let mut hasher: Sha3_256 = Digest::new();
+    for output in [lhs.modmul(&rhs), inv] {
+        output.write_be_bytes(buf);
+        let start = buf.iter().position(|byte| *byte != 0).unwrap_or(buf.len());
+        hasher.update([(buf.len() - start) as u8]);
+        hasher.update(&buf[start..]);
+    }
+    Some(hasher.finalize().into())
+}
+
+/// The lowercase hex digits of the bytes
+fn hex_digits(bytes: &[u8]) -> impl Iterator<Item = char> + '_ {
+    bytes.iter().flat_map(|byte| {
+        [byte >> 4, byte & 0xF].map(|nibble| char::from_digit(nibble as u32, 16).unwrap())
+    })
+}
+
+/// Compare the outputs of the known-answer test of F against the expected hex digest
+fn field_kat<F: FieldArithmetic>(
+    name: &'static str,
+    base_bytes: usize,
+    expected: &str,
+) -> Result<(), SelfTestError> {
+    match kat_outputs::<F>(base_bytes) {
+        Some(digest) if hex_digits(&digest).eq(expected.chars()) => Ok(()),
+        _ => Err(SelfTestError::FieldArithmetic(name)),
+    }
+}
+
+/// Deal a 3-of-5 sharing from a fixed seed and check the reconstruction with `check_round_trip`
+#[cfg(all(feature = "std", feature = "gf256"))]
+fn round_trip() -> Result<(), SelfTestError> {
+    let mut secret_sharing = SecretSharing256::split_deterministic(&[0; 32], 3, 5)
+        .map_err(|_| SelfTestError::RoundTrip)?;
+    secret_sharing
+        .encrypt(ROUND_TRIP_MESSAGE)
+        .map_err(|_| SelfTestError::RoundTrip)?;
+    check_round_trip(&secret_sharing)
+}
+
+/// Interpolate the last `threshold` shares, check that the polynomial passes through every other
+/// dealt share, and decrypt the ciphertext with the key derived from it
+#[cfg(all(feature = "std", feature = "gf256"))]
+fn check_round_trip(secret_sharing: &SecretSharing256) -> Result<(), SelfTestError> {
+    let threshold = secret_sharing.threshold();
+    let (others, used) = secret_sharing
+        .shards
+        .split_at(secret_sharing.shards.len() - threshold);
+    let points = used
+        .iter()
+        .map(|shard| (shard.x, shard.fx))
+        .collect::<Vec<(GF2p256, GF2p256)>>();
+    let recovered =
+        Poly256::try_interpolate(&points, threshold).map_err(|_| SelfTestError::RoundTrip)?;
+    if others
+        .iter()
+        .any(|shard| recovered.evaluate(&shard.x) != shard.fx)
+    {
+        return Err(SelfTestError::RoundTrip);
+    }
+
+    let decryption =
+        SecretSharing256::decrypt(&secret_sharing.ciphertext, &secret_sharing.nonce, used)
+            .map_err(|_| SelfTestError::RoundTrip)?;
+    if decryption.expose() != ROUND_TRIP_MESSAGE {
+        return Err(SelfTestError::RoundTrip);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_test_passes() {
+        assert_eq!(self_test(), Ok(()));
+    }
+
+    #[test]
+    #[cfg(feature = "gf8")]
+    fn field_kat_detects_mismatch() {
+        let digest = hex_digits(&kat_outputs::<GF2p8>(1).unwrap()).collect::<String>();
+        assert_eq!(field_kat::<GF2p8>("GF2p8", 1, &digest), Ok(()));
+        assert_eq!(
+            field_kat::<GF2p8>("GF2p8", 1, &digest.replace('c', "d")),
+            Err(SelfTestError::FieldArithmetic("GF2p8"))
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "gf256"))]
+    fn round_trip_detects_tampered_share() {
+        let mut secret_sharing = SecretSharing256::split_deterministic(&[0; 32], 3, 5).unwrap();
+        secret_sharing.encrypt(ROUND_TRIP_MESSAGE).unwrap();
+        assert_eq!(check_round_trip(&secret_sharing), Ok(()));
+        // A share that was not interpolated
+        secret_sharing.shards[0].fx = secret_sharing.shards[0].fx.modadd(&GF2p256::ONE);
+        assert_eq!(
+            check_round_trip(&secret_sharing),
+            Err(SelfTestError::RoundTrip)
+        );
+        // A share that was interpolated
+        secret_sharing.shards[0].fx = secret_sharing.shards[0].fx.modadd(&GF2p256::ONE);
+        secret_sharing.shards[4].fx = secret_sharing.shards[4].fx.modadd(&GF2p256::ONE);
+        assert_eq!(
+            check_round_trip(&secret_sharing),
+            Err(SelfTestError::RoundTrip)
+        );
+    }
+}
@@ -0,0 +1,1019 @@
+//! Shamir's secret sharing of field elements
+//!
+//! `SecretSharing256` shares the key of an encrypted message. This module shares the secret itself:
+//! the secret is the constant term of a random polynomial of degree (threshold - 1), and the share
+//! of the i-th participant is the evaluation of the polynomial at the element whose encoding is the
+//! integer i, for i = 1, 2, ..., n.
+//!
+//! The core functions, `split_into`, `split_array`, and `reconstruct`, the fixed-capacity
+//! `ShareSet`, and `Shamir::split_set` take their shares in caller-provided slices and stack
+//! arrays instead of returning collections, and the field arithmetic underneath works on the
+//! stack, so dealing and reconstructing never allocate. They are available without the `alloc`
+//! and `std` features, when the crate is `no_std`. The `alloc` feature adds `Vec`-returning
+//! conveniences for a number of participants only known at runtime, and, with the `gf8` feature,
+//! for byte-string secrets, which are shared one byte at a time in GF(2^8). `Shamir::builder()`
+//! configures a reusable scheme that checks its parameters once instead of on every call.
+//! `IncrementalReconstructor`, also behind `alloc`, refines its estimate of the secret as shares
+//! arrive one at a time.
+use crate::error::SssError;
+use crate::galoisfields::FieldArithmetic;
+#[cfg(all(feature = "gf256", any(feature = "std", feature = "getrandom")))]
+use crate::galoisfields::GF2p256;
+#[cfg(all(feature = "alloc", feature = "gf8"))]
+use crate::galoisfields::GF2p8;
+#[cfg(feature = "alloc")]
+use crate::matrix::Matrix;
+use crate::poly::horner;
+#[cfg(feature = "alloc")]
+use crate::poly::{solve_vandermonde, NewtonInterpolator};
+#[cfg(all(feature = "alloc", feature = "gf8"))]
+use crate::secret::Secret;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use core::num::NonZeroU8;
+use rand_core::CryptoRngCore;
+#[cfg(all(feature = "gf256", any(feature = "std", feature = "getrandom")))]
+use rand_core::OsRng;
+
+/// The x-coordinate of a share, which is never zero: the polynomial evaluates to the secret
+/// itself at zero, so a "share" at zero would hand out the secret. The only way to build one is
+/// through constructors that reject zero.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShareIndex<F>(F);
+
+impl<F: FieldArithmetic> ShareIndex<F> {
+    /// Fails with `SssError::ZeroShareIndex` if x is zero
+    pub fn new(x: F) -> Result<Self, SssError> {
+        if x.is_zero() {
+            return Err(SssError::ZeroShareIndex);
+        }
+        Ok(Self(x))
+    }
+
+    /// The index of the i-th participant, which is the element whose encoding is the integer i.
+    /// Fails with `SssError::ZeroShareIndex` for 0 and `SssError::TooManyShares` if i does not fit
+    /// into the field.
+    pub fn from_u64(i: u64) -> Result<Self, SssError> {
+        let x = F::try_from_u64(i).ok_or(SssError::TooManyShares { shares: i as usize })?;
+        Self::new(x)
+    }
+
+    pub fn get(&self) -> &F {
+        &self.0
+    }
+}
+
+/// The evaluation of the secret polynomial at a non-zero x. `Debug` redacts y unless the
+/// `debug-secrets` feature is enabled.
+#[cfg_attr(feature = "debug-secrets", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Share<F> {
+    pub x: ShareIndex<F>,
+    pub y: F,
+}
+
+impl<F: FieldArithmetic> Share<F> {
+    /// Filler for buffers that are about to be overwritten
+    fn placeholder() -> Self {
+        Self {
+            x: ShareIndex(F::one()),
+            y: F::zero(),
+        }
+    }
+}
+
+#[cfg(not(feature = "debug-secrets"))]
+impl<F: core::fmt::Debug> core::fmt::Debug for Share<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Share")
+            .field("x", &self.x)
+            .field("y", &format_args!("REDACTED"))
+            .finish()
+    }
+}
+
+impl<F: zeroize::Zeroize> zeroize::Zeroize for Share<F> {
+    /// Only y is wiped: x is public, and wiping it would make it zero
+    fn zeroize(&mut self) {
+        self.y.zeroize();
+    }
+}
+
+/// Check the parameters of a `threshold`-of-`n` sharing in the field F: the threshold must be
+/// between 2 and n, and the participants must fit into the non-zero elements of F.
+pub fn check_parameters<F: FieldArithmetic>(threshold: usize, n: usize) -> Result<(), SssError> {
+    if threshold < 2 || threshold > n {
+        return Err(SssError::InvalidThreshold {
+            threshold,
+            shares: n,
+        });
+    }
+    if u64::try_from(n).ok().and_then(F::try_from_u64).is_none() {
+        return Err(SssError::TooManyShares { shares: n });
+    }
+    Ok(())
+}
+
+/// Deal one share of the secret into each element of `shares`. The length of `coeffs` is the
+/// threshold; it is scratch space for the secret polynomial and is overwritten with zeros before
+/// returning.
+pub fn split_into<F: FieldArithmetic>(
+    secret: &F,
+    coeffs: &mut [F],
+    shares: &mut [Share<F>],
+    rng: &mut impl CryptoRngCore,
+) -> Result<(), SssError> {
+    check_parameters::<F>(coeffs.len(), shares.len())?;
+    coeffs[0] = *secret;
+    coeffs[1..]
+        .iter_mut()
+        .for_each(|coeff| *coeff = F::random(rng));
+
+    for (i, share) in shares.iter_mut().enumerate() {
+        let x = ShareIndex::from_u64(i as u64 + 1)?;
+        let y = horner(coeffs, x.get());
+        *share = Share { x, y };
+    }
+    coeffs.fill(F::zero());
+
+    Ok(())
+}
+
+/// Deal N shares with threshold T entirely on the stack
+pub fn split_array<F: FieldArithmetic, const T: usize, const N: usize>(
+    secret: &F,
+    rng: &mut impl CryptoRngCore,
+) -> Result<[Share<F>; N], SssError> {
+    let mut coeffs = [F::zero(); T];
+    let mut shares = [Share::placeholder(); N];
+    split_into(secret, &mut coeffs, &mut shares, rng)?;
+    Ok(shares)
+}
+
+/// A collection of at most MAX_SHARES shares stored inline, in the manner of
+/// `heapless::Vec<Share<F>, MAX_SHARES>`, so that a runtime number of participants can be dealt
+/// and collected without a heap. MAX_SHARES also bounds the threshold, since the secret polynomial
+/// is kept in a stack buffer of the same size while dealing.
+#[derive(Copy, Clone)]
+pub struct ShareSet<F, const MAX_SHARES: usize> {
+    shares: [Share<F>; MAX_SHARES],
+    len: usize,
+}
+
+impl<F: FieldArithmetic, const MAX_SHARES: usize> ShareSet<F, MAX_SHARES> {
+    pub fn new() -> Self {
+        Self {
+            shares: [Share::placeholder(); MAX_SHARES],
+            len: 0,
+        }
+    }
+
+    /// Deal `n` shares of the secret with the given threshold. Fails with
+    /// `SssError::TooManyShares` if `n` exceeds MAX_SHARES.
+    pub fn split(
+        secret: &F,
+        threshold: usize,
+        n: usize,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Self, SssError> {
+        if n > MAX_SHARES {
+            return Err(SssError::TooManyShares { shares: n });
+        }
+        check_parameters::<F>(threshold, n)?;
+        let mut coeffs = [F::zero(); MAX_SHARES];
+        let mut set = Self::new();
+        split_into(secret, &mut coeffs[..threshold], &mut set.shares[..n], rng)?;
+        set.len = n;
+        Ok(set)
+    }
+
+    /// Append a share, failing with `SssError::TooManyShares` if the set is full
+    pub fn push(&mut self, share: Share<F>) -> Result<(), SssError> {
+        if self.len == MAX_SHARES {
+            return Err(SssError::TooManyShares {
+                shares: MAX_SHARES + 1,
+            });
+        }
+        self.shares[self.len] = share;
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn as_slice(&self) -> &[Share<F>] {
+        &self.shares[..self.len]
+    }
+
+    /// Recover the secret from every share in the set, like `reconstruct`
+    pub fn reconstruct(&self) -> Result<F, SssError> {
+        reconstruct(self.as_slice())
+    }
+}
+
+impl<F: FieldArithmetic, const MAX_SHARES: usize> Default for ShareSet<F, MAX_SHARES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F, const MAX_SHARES: usize> core::ops::Deref for ShareSet<F, MAX_SHARES> {
+    type Target = [Share<F>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.shares[..self.len]
+    }
+}
+
+impl<F: core::fmt::Debug, const MAX_SHARES: usize> core::fmt::Debug for ShareSet<F, MAX_SHARES> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<F: zeroize::Zeroize, const MAX_SHARES: usize> zeroize::Zeroize for ShareSet<F, MAX_SHARES> {
+    fn zeroize(&mut self) {
+        self.shares.iter_mut().for_each(zeroize::Zeroize::zeroize);
+        self.len = 0;
+    }
+}
+
+/// Recover the secret by Lagrange interpolation at zero. Every input share is used, and the
+/// caller is responsible for supplying at least as many shares as the threshold: with fewer, the
+/// output is some unrelated field element. The denominators are inverted with
+/// `FieldArithmetic::inv_ct`, so the inversions do not leak the shares through timing.
+pub fn reconstruct<F: FieldArithmetic>(shares: &[Share<F>]) -> Result<F, SssError> {
+    if shares.is_empty() {
+        return Err(SssError::NotEnoughShares { expect: 1, has: 0 });
+    }
+    for (i, share) in shares.iter().enumerate() {
+        if shares[..i].iter().any(|prev| prev.x == share.x) {
+            return Err(SssError::DuplicateShareIndex);
+        }
+    }
+
+    let mut secret = F::zero();
+    for (i, share_i) in shares.iter().enumerate() {
+        // The i-th Lagrange basis polynomial at zero is the product of x_j / (x_j - x_i)
+        let mut numerator = F::one();
+        let mut denominator = F::one();
+        for (j, share_j) in shares.iter().enumerate() {
+            if i != j {
+                numerator.modmul_assign(share_j.x.get());
+                denominator.modmul_assign(&share_j.x.get().modsub(share_i.x.get()));
+            }
+        }
+        // The x-coordinates are distinct, so the denominator is not zero
+        let basis = numerator.modmul(&denominator.inv_ct());
+        secret.modadd_assign(&share_i.y.modmul(&basis));
+    }
+
+    Ok(secret)
+}
+
+/// Recover the whole dealer polynomial, constant term first, by solving the Vandermonde system
+/// of the shares with `solve_vandermonde`. The secret is the first coefficient. As with
+/// `reconstruct`, every share is used, and with fewer shares than the threshold the output is
+/// unrelated to the dealer polynomial.
+#[cfg(feature = "alloc")]
+pub fn reconstruct_polynomial<F: FieldArithmetic>(shares: &[Share<F>]) -> Result<Vec<F>, SssError> {
+    if shares.is_empty() {
+        return Err(SssError::NotEnoughShares { expect: 1, has: 0 });
+    }
+    let xs = shares
+        .iter()
+        .map(|share| *share.x.get())
+        .collect::<Vec<F>>();
+    let ys = shares.iter().map(|share| share.y).collect::<Vec<F>>();
+    solve_vandermonde(&xs, &ys)
+}
+
+/// What `diagnose_shares` found out about a set of shares. `Debug` redacts the coefficients
+/// unless the `debug-secrets` feature is enabled.
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "debug-secrets", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub enum Diagnosis<F> {
+    /// Every share lies on the same polynomial of degree below the threshold, whose coefficients
+    /// are given constant term first; the secret is the first coefficient
+    Consistent(Vec<F>),
+    /// Only `rank` of the equations are linearly independent, fewer than the threshold: there are
+    /// too few shares with distinct x-coordinates to determine the polynomial
+    RankDeficient { rank: usize },
+    /// The shares do not lie on any single polynomial of degree below the threshold. The
+    /// polynomial is determined by the first shares whose equations are independent, and
+    /// `conflicting` holds the position in the input of every share that disagrees with it. If one
+    /// of those first shares is the bad one, all of the others are reported instead.
+    Inconsistent { conflicting: Vec<usize> },
+}
+
+#[cfg(all(feature = "alloc", not(feature = "debug-secrets")))]
+impl<F> core::fmt::Debug for Diagnosis<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Consistent(_) => f
+                .debug_tuple("Consistent")
+                .field(&format_args!("REDACTED"))
+                .finish(),
+            Self::RankDeficient { rank } => {
+                f.debug_struct("RankDeficient").field("rank", rank).finish()
+            }
+            Self::Inconsistent { conflicting } => f
+                .debug_struct("Inconsistent")
+                .field("conflicting", conflicting)
+                .finish(),
+        }
+    }
+}
+
+/// Solve the linear system of the shares for the coefficients of a polynomial of degree below the
+/// threshold, and report whether it has too few independent equations or conflicting ones. Unlike
+/// `reconstruct`, which returns some field element for any set of shares with distinct
+/// x-coordinates, this is meant for finding out what is wrong with a bad share set. Share i
+/// contributes the equation c_0 + c_1 x_i + ... + c_(t-1) x_i^(t-1) = y_i. Will panic if the
+/// threshold is zero
+#[cfg(feature = "alloc")]
+pub fn diagnose_shares<F: FieldArithmetic>(shares: &[Share<F>], threshold: usize) -> Diagnosis<F> {
+    if threshold == 0 {
+        panic!("threshold must be positive");
+    }
+    let mut entries = Vec::with_capacity(shares.len() * threshold);
+    for share in shares.iter() {
+        let mut power = F::one();
+        for _ in 0..threshold {
+            entries.push(power);
+            power = power.modmul(share.x.get());
+        }
+    }
+    let system = Matrix::from_entries(shares.len(), threshold, entries);
+
+    // The pivot columns of the transpose are the equations that are independent of earlier ones
+    let independent = system.transpose().row_reduce();
+    if independent.len() < threshold {
+        return Diagnosis::RankDeficient {
+            rank: independent.len(),
+        };
+    }
+    let rows = independent
+        .iter()
+        .map(|i| system.row(*i))
+        .collect::<Vec<&[F]>>();
+    let ys = independent.iter().map(|i| shares[*i].y).collect::<Vec<F>>();
+    let coeffs = Matrix::from_rows(&rows)
+        .solve(&ys)
+        .expect("independent equations are consistent");
+
+    let conflicting = system
+        .mul_vec(&coeffs)
+        .iter()
+        .zip(shares.iter())
+        .enumerate()
+        .filter(|(_, (y, share))| **y != share.y)
+        .map(|(i, _)| i)
+        .collect::<Vec<usize>>();
+    if conflicting.is_empty() {
+        Diagnosis::Consistent(coeffs)
+    } else {
+        Diagnosis::Inconsistent { conflicting }
+    }
+}
+
+/// Recover the secret from shares that arrive one at a time. Each share refines a Newton-form
+/// interpolation at the cost of O(n) field operations, and the estimate of the secret is the
+/// interpolating polynomial at zero, which equals the secret once at least as many shares as the
+/// threshold have arrived. `Debug` only prints the number of shares.
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct IncrementalReconstructor<F: FieldArithmetic> {
+    interpolator: NewtonInterpolator<F>,
+}
+
+#[cfg(feature = "alloc")]
+impl<F: FieldArithmetic> IncrementalReconstructor<F> {
+    pub fn new() -> Self {
+        Self {
+            interpolator: NewtonInterpolator::new(),
+        }
+    }
+
+    /// The number of shares absorbed so far
+    pub fn len(&self) -> usize {
+        self.interpolator.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.interpolator.is_empty()
+    }
+
+    /// Absorb a share and return the refined estimate of the secret. Fails with
+    /// `SssError::DuplicateShareIndex` if a share with the same x-coordinate has been absorbed
+    pub fn push(&mut self, share: &Share<F>) -> Result<F, SssError> {
+        self.interpolator.push(*share.x.get(), share.y)?;
+        self.estimate()
+    }
+
+    /// The secret as determined by the shares so far. Fails with `SssError::NotEnoughShares` if
+    /// there are none
+    pub fn estimate(&self) -> Result<F, SssError> {
+        if self.is_empty() {
+            return Err(SssError::NotEnoughShares { expect: 1, has: 0 });
+        }
+        Ok(self.interpolator.evaluate(&F::zero()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: FieldArithmetic> Default for IncrementalReconstructor<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: FieldArithmetic> core::fmt::Debug for IncrementalReconstructor<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("IncrementalReconstructor")
+            .field("shares", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A validated `threshold`-of-`n` configuration over the field F that draws randomness from R.
+/// The parameters are checked once by `ShamirBuilder::build`, and the scheme can then deal any
+/// number of secrets.
+pub struct Shamir<F, R> {
+    threshold: usize,
+    shares: usize,
+    rng: R,
+    _field: PhantomData<F>,
+}
+
+#[cfg(all(feature = "gf256", any(feature = "std", feature = "getrandom")))]
+impl Shamir<GF2p256, OsRng> {
+    /// Start configuring a scheme over `GF2p256` with the operating system's RNG; both can be
+    /// changed on the builder
+    pub fn builder() -> ShamirBuilder<GF2p256, OsRng> {
+        ShamirBuilder {
+            threshold: None,
+            shares: None,
+            rng: OsRng,
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: FieldArithmetic, R: CryptoRngCore> Shamir<F, R> {
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// The number of shares dealt for each secret
+    pub fn shares(&self) -> usize {
+        self.shares
+    }
+
+    /// Deal the configured number of shares into a `ShareSet`. Fails with
+    /// `SssError::TooManyShares` if the set cannot hold them.
+    pub fn split_set<const MAX_SHARES: usize>(
+        &mut self,
+        secret: &F,
+    ) -> Result<ShareSet<F, MAX_SHARES>, SssError> {
+        ShareSet::split(secret, self.threshold, self.shares, &mut self.rng)
+    }
+
+    /// Deal the configured number of shares
+    #[cfg(feature = "alloc")]
+    pub fn split(&mut self, secret: &F) -> Result<Vec<Share<F>>, SssError> {
+        split(secret, self.threshold, self.shares, &mut self.rng)
+    }
+
+    /// Recover the secret, rejecting fewer shares than the threshold
+    pub fn reconstruct(&self, shares: &[Share<F>]) -> Result<F, SssError> {
+        if shares.len() < self.threshold {
+            return Err(SssError::NotEnoughShares {
+                expect: self.threshold,
+                has: shares.len(),
+            });
+        }
+        reconstruct(shares)
+    }
+}
+
+impl<F, R> core::fmt::Debug for Shamir<F, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Shamir")
+            .field("threshold", &self.threshold)
+            .field("shares", &self.shares)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Fluent configuration of a `Shamir` scheme. The threshold and the number of shares are
+/// required; the field and the RNG default to `GF2p256` and `OsRng`.
+pub struct ShamirBuilder<F, R> {
+    threshold: Option<usize>,
+    shares: Option<usize>,
+    rng: R,
+    _field: PhantomData<F>,
+}
+
+impl<F, R> ShamirBuilder<F, R> {
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    pub fn shares(mut self, shares: usize) -> Self {
+        self.shares = Some(shares);
+        self
+    }
+
+    /// Share secrets in the field G instead
+    pub fn field<G>(self) -> ShamirBuilder<G, R> {
+        ShamirBuilder {
+            threshold: self.threshold,
+            shares: self.shares,
+            rng: self.rng,
+            _field: PhantomData,
+        }
+    }
+
+    /// Draw the random coefficients from the input RNG instead
+    pub fn rng<S>(self, rng: S) -> ShamirBuilder<F, S> {
+        ShamirBuilder {
+            threshold: self.threshold,
+            shares: self.shares,
+            rng,
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: FieldArithmetic, R: CryptoRngCore> ShamirBuilder<F, R> {
+    /// Check the parameters with `check_parameters`. A missing threshold or number of shares is
+    /// reported as `SssError::InvalidThreshold`.
+    pub fn build(self) -> Result<Shamir<F, R>, SssError> {
+        let threshold = self.threshold.unwrap_or(0);
+        let shares = self.shares.unwrap_or(0);
+        check_parameters::<F>(threshold, shares)?;
+        Ok(Shamir {
+            threshold,
+            shares,
+            rng: self.rng,
+            _field: PhantomData,
+        })
+    }
+}
+
+/// Deal `n` shares of the secret with the given threshold
+#[cfg(feature = "alloc")]
+pub fn split<F: FieldArithmetic>(
+    secret: &F,
+    threshold: usize,
+    n: usize,
+    rng: &mut impl CryptoRngCore,
+) -> Result<Vec<Share<F>>, SssError> {
+    let mut coeffs = vec![F::zero(); threshold];
+    let mut shares = vec![Share::placeholder(); n];
+    split_into(secret, &mut coeffs, &mut shares, rng)?;
+    Ok(shares)
+}
+
+/// A share of a byte string: byte k of `y` is the share of byte k of the secret, all evaluated
+/// at the same x in GF(2^8). `Debug` redacts y unless the `debug-secrets` feature is enabled.
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "debug-secrets", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct ByteShare {
+    pub x: NonZeroU8,
+    pub y: Vec<u8>,
+}
+
+#[cfg(all(feature = "alloc", not(feature = "debug-secrets")))]
+impl core::fmt::Debug for ByteShare {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ByteShare")
+            .field("x", &self.x)
+            .field("y", &format_args!("REDACTED"))
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl zeroize::Zeroize for ByteShare {
+    fn zeroize(&mut self) {
+        self.y.zeroize();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for ByteShare {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl zeroize::ZeroizeOnDrop for ByteShare {}
+
+#[cfg(all(feature = "alloc", feature = "gf8"))]
+fn byte_to_elem(byte: u8) -> GF2p8 {
+    GF2p8::from_u16(byte as u16)
+}
+
+#[cfg(all(feature = "alloc", feature = "gf8"))]
+fn elem_to_byte(elem: &GF2p8) -> u8 {
+    let mut buf = [0u8; GF2p8::BYTES];
+    elem.write_be_bytes(&mut buf);
+    buf[GF2p8::BYTES - 1]
+}
+
+/// Deal `n` shares of a non-empty byte string. Each byte is shared with its own random
+/// polynomial, so there can be at most 255 participants.
+#[cfg(all(feature = "alloc", feature = "gf8"))]
+pub fn split_bytes(
+    secret: &[u8],
+    threshold: usize,
+    n: usize,
+    rng: &mut impl CryptoRngCore,
+) -> Result<Vec<ByteShare>, SssError> {
+    if secret.is_empty() {
+        return Err(SssError::EmptySecret);
+    }
+    check_parameters::<GF2p8>(threshold, n)?;
+    let mut coeffs = vec![GF2p8::ZERO; threshold];
+    let mut column = vec![Share::placeholder(); n];
+    let mut shares = (1..=n)
+        .map(|x| ByteShare {
+            x: NonZeroU8::new(x as u8).expect("share indices are not zero"),
+            y: Vec::with_capacity(secret.len()),
+        })
+        .collect::<Vec<ByteShare>>();
+
+    for byte in secret {
+        split_into(&byte_to_elem(*byte), &mut coeffs, &mut column, rng)?;
+        shares
+            .iter_mut()
+            .zip(column.iter())
+            .for_each(|(share, point)| share.y.push(elem_to_byte(&point.y)));
+    }
+    column.fill(Share::placeholder());
+
+    Ok(shares)
+}
+
+/// Recover a byte string from shares dealt by `split_bytes`. Like `reconstruct`, every input
+/// share is used, and the caller is responsible for supplying at least the threshold.
+#[cfg(all(feature = "alloc", feature = "gf8"))]
+pub fn reconstruct_bytes(shares: &[ByteShare]) -> Result<Secret<Vec<u8>>, SssError> {
+    let len = match shares.first() {
+        Some(share) => share.y.len(),
+        None => return Err(SssError::NotEnoughShares { expect: 1, has: 0 }),
+    };
+    if shares.iter().any(|share| share.y.len() != len) {
+        return Err(SssError::InconsistentShares);
+    }
+
+    let mut column = shares
+        .iter()
+        .map(|share| Share {
+            x: ShareIndex(byte_to_elem(share.x.get())),
+            y: GF2p8::ZERO,
+        })
+        .collect::<Vec<Share<GF2p8>>>();
+    let mut secret = Secret::new(Vec::with_capacity(len));
+    for k in 0..len {
+        column
+            .iter_mut()
+            .zip(shares)
+            .for_each(|(point, share)| point.y = byte_to_elem(share.y[k]));
+        let byte = reconstruct(&column)?;
+        secret.expose_mut().push(elem_to_byte(&byte));
+    }
+
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deterministic::DeterministicSampler;
+    #[cfg(feature = "gf256")]
+    use crate::galoisfields::GF2p256;
+    #[cfg(feature = "gf8")]
+    use crate::galoisfields::GF2p8;
+    use crate::galoisfields::F3329;
+    use crate::primefields::Fp25519;
+    #[cfg(any(feature = "alloc", feature = "gf8", feature = "gf256"))]
+    use rand::thread_rng;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    std::thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Counts the allocations of each thread, so that tests running in parallel do not disturb
+    /// each other's counts
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.with(|count| count.set(count.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// The number of allocations the current thread makes while running f
+    fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+        let before = ALLOCATIONS.with(Cell::get);
+        let output = f();
+        (output, ALLOCATIONS.with(Cell::get) - before)
+    }
+
+    /// Deal and reconstruct through every core function, which must not touch the heap
+    fn check_no_allocations<F: FieldArithmetic>() {
+        let mut rng = DeterministicSampler::new(&[0; 32], b"allocations");
+        let secret = F::random(&mut rng);
+        let (recovered, allocations) = count_allocations(|| {
+            let shares = split_array::<F, 3, 5>(&secret, &mut rng).unwrap();
+            let mut coeffs = [F::zero(); 3];
+            let mut dealt = [Share::placeholder(); 4];
+            split_into(&secret, &mut coeffs, &mut dealt, &mut rng).unwrap();
+            let set = ShareSet::<F, 6>::split(&secret, 3, 6, &mut rng).unwrap();
+            [
+                reconstruct(&shares[2..]).unwrap(),
+                reconstruct(&dealt[..3]).unwrap(),
+                set.reconstruct().unwrap(),
+            ]
+        });
+        assert_eq!(allocations, 0);
+        assert!(recovered.iter().all(|elem| *elem == secret));
+    }
+
+    #[test]
+    #[cfg(feature = "gf256")]
+    fn split_array_round_trip() {
+        let mut rng = thread_rng();
+        let secret = GF2p256::random(&mut rng);
+        let shares = split_array::<GF2p256, 3, 5>(&secret, &mut rng).unwrap();
+        assert_eq!(reconstruct(&shares[..3]).unwrap(), secret);
+        assert_eq!(reconstruct(&shares[2..]).unwrap(), secret);
+        assert_eq!(reconstruct(&shares).unwrap(), secret);
+        assert_ne!(reconstruct(&shares[..2]).unwrap(), secret);
+    }
+
+    #[test]
+    #[cfg(feature = "gf8")]
+    fn reconstruct_rejects_bad_shares() -> Result<(), SssError> {
+        let mut rng = thread_rng();
+        let secret = Fp25519::random(&mut rng);
+        let mut shares = split_array::<Fp25519, 2, 3>(&secret, &mut rng).unwrap();
+        assert!(matches!(
+            reconstruct::<Fp25519>(&[]),
+            Err(SssError::NotEnoughShares { .. })
+        ));
+        shares[1].x = shares[0].x;
+        assert!(matches!(
+            reconstruct(&shares),
+            Err(SssError::DuplicateShareIndex)
+        ));
+        assert!(matches!(
+            ShareIndex::new(Fp25519::ZERO),
+            Err(SssError::ZeroShareIndex)
+        ));
+        assert!(matches!(
+            ShareIndex::<Fp25519>::from_u64(0),
+            Err(SssError::ZeroShareIndex)
+        ));
+        assert!(matches!(
+            ShareIndex::<GF2p8>::from_u64(256),
+            Err(SssError::TooManyShares { .. })
+        ));
+        assert_eq!(ShareIndex::new(Fp25519::ONE)?.get(), &Fp25519::ONE);
+        Ok(())
+    }
+
+    #[test]
+    fn core_functions_do_not_allocate() {
+        check_no_allocations::<Fp25519>();
+        check_no_allocations::<F3329>();
+        #[cfg(feature = "gf8")]
+        check_no_allocations::<GF2p8>();
+        #[cfg(feature = "gf256")]
+        check_no_allocations::<GF2p256>();
+        #[cfg(feature = "gf128")]
+        check_no_allocations::<crate::tower::GF2p16p8>();
+    }
+
+    #[test]
+    #[cfg(feature = "gf256")]
+    fn share_set_without_heap() {
+        let mut rng = thread_rng();
+        let secret = GF2p256::random(&mut rng);
+        let shares = ShareSet::<GF2p256, 8>::split(&secret, 3, 6, &mut rng).unwrap();
+        assert_eq!(shares.len(), 6);
+        assert_eq!(shares.reconstruct().unwrap(), secret);
+
+        let mut collected = ShareSet::<GF2p256, 3>::new();
+        for share in shares.iter().rev().take(3) {
+            collected.push(*share).unwrap();
+        }
+        assert_eq!(collected.reconstruct().unwrap(), secret);
+        assert!(matches!(
+            collected.push(shares[0]),
+            Err(SssError::TooManyShares { .. })
+        ));
+        assert!(matches!(
+            ShareSet::<GF2p256, 4>::split(&secret, 3, 5, &mut rng),
+            Err(SssError::TooManyShares { shares: 5 })
+        ));
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "gf8",
+        feature = "gf256",
+        any(feature = "std", feature = "getrandom")
+    ))]
+    fn builder_round_trip() -> Result<(), SssError> {
+        let mut scheme = Shamir::builder().threshold(3).shares(5).build()?;
+        assert_eq!((scheme.threshold(), scheme.shares()), (3, 5));
+        for _ in 0..3 {
+            let secret = GF2p256::random(&mut thread_rng());
+            let shares = scheme.split_set::<5>(&secret)?;
+            assert_eq!(scheme.reconstruct(&shares[1..4])?, secret);
+            assert!(matches!(
+                scheme.reconstruct(&shares[..2]),
+                Err(SssError::NotEnoughShares { expect: 3, has: 2 })
+            ));
+        }
+
+        let mut scheme = Shamir::builder()
+            .threshold(2)
+            .shares(3)
+            .field::<Fp25519>()
+            .rng(thread_rng())
+            .build()?;
+        let secret = Fp25519::random(&mut thread_rng());
+        let shares = scheme.split_set::<3>(&secret)?;
+        assert_eq!(scheme.reconstruct(&shares[1..])?, secret);
+
+        assert!(matches!(
+            Shamir::builder().threshold(3).build(),
+            Err(SssError::InvalidThreshold { .. })
+        ));
+        assert!(matches!(
+            Shamir::builder()
+                .threshold(2)
+                .shares(256)
+                .field::<GF2p8>()
+                .build(),
+            Err(SssError::TooManyShares { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "gf8", feature = "gf256"))]
+    fn parameters_are_checked() {
+        assert!(check_parameters::<F3329>(2, 3328).is_ok());
+        assert!(matches!(
+            check_parameters::<F3329>(2, 3329),
+            Err(SssError::TooManyShares { shares: 3329 })
+        ));
+        assert!(matches!(
+            check_parameters::<GF2p8>(2, 256),
+            Err(SssError::TooManyShares { shares: 256 })
+        ));
+        assert!(matches!(
+            check_parameters::<GF2p256>(1, 3),
+            Err(SssError::InvalidThreshold { .. })
+        ));
+        assert!(matches!(
+            check_parameters::<GF2p256>(4, 3),
+            Err(SssError::InvalidThreshold { .. })
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn split_vec_round_trip() {
+        let mut rng = thread_rng();
+        let secret = F3329::from(1234);
+        let shares = split(&secret, 4, 10, &mut rng).unwrap();
+        assert_eq!(shares.len(), 10);
+        assert_eq!(reconstruct(&shares[3..7]).unwrap(), secret);
+    }
+
+    #[cfg(all(feature = "alloc", feature = "gf8"))]
+    #[test]
+    fn split_bytes_round_trip() {
+        let mut rng = thread_rng();
+        let secret = b"Hello, world";
+        let shares = split_bytes(secret, 3, 255, &mut rng).unwrap();
+        assert_eq!(shares.len(), 255);
+        let recovered = reconstruct_bytes(&shares[100..103]).unwrap();
+        assert_eq!(recovered.expose(), secret);
+
+        assert!(matches!(
+            split_bytes(b"", 3, 5, &mut rng),
+            Err(SssError::EmptySecret)
+        ));
+        assert!(matches!(
+            split_bytes(secret, 3, 256, &mut rng),
+            Err(SssError::TooManyShares { .. })
+        ));
+        let mut truncated = shares[..3].to_vec();
+        truncated[0].y.pop();
+        assert!(matches!(
+            reconstruct_bytes(&truncated),
+            Err(SssError::InconsistentShares)
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn incremental_reconstruction() {
+        let mut rng = thread_rng();
+        let secret = F3329::random(&mut rng);
+        let shares = split(&secret, 4, 7, &mut rng).unwrap();
+        let mut reconstructor = IncrementalReconstructor::new();
+        assert!(reconstructor.estimate().is_err());
+        for (i, share) in shares.iter().enumerate() {
+            let estimate = reconstructor.push(share).unwrap();
+            assert_eq!(estimate, reconstruct(&shares[..=i]).unwrap());
+            if i + 1 >= 4 {
+                assert_eq!(estimate, secret);
+            }
+        }
+        assert!(matches!(
+            reconstructor.push(&shares[0]),
+            Err(SssError::DuplicateShareIndex)
+        ));
+        assert_eq!(reconstructor.len(), 7);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn reconstruct_polynomial_round_trip() {
+        let mut rng = thread_rng();
+        let secret = F3329::random(&mut rng);
+        let shares = split(&secret, 3, 5, &mut rng).unwrap();
+        let recovered = reconstruct_polynomial(&shares[1..4]).unwrap();
+        assert_eq!(recovered.len(), 3);
+        assert_eq!(recovered[0], secret);
+        for share in shares.iter() {
+            assert_eq!(horner(&recovered, share.x.get()), share.y);
+        }
+        // Extra shares lie on the same polynomial, so the higher coefficients vanish
+        let all = reconstruct_polynomial(&shares).unwrap();
+        assert_eq!(all[..3], recovered);
+        assert!(all[3..].iter().all(|coeff| coeff.is_zero()));
+        assert!(reconstruct_polynomial::<F3329>(&[]).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn diagnose_share_sets() {
+        let mut rng = thread_rng();
+        let secret = F3329::random(&mut rng);
+        let mut shares = split(&secret, 3, 6, &mut rng).unwrap();
+        match diagnose_shares(&shares, 3) {
+            Diagnosis::Consistent(coeffs) => assert_eq!(coeffs[0], secret),
+            _ => panic!("honest shares should be consistent"),
+        }
+        assert!(diagnose_shares(&shares[..2], 3) == Diagnosis::RankDeficient { rank: 2 });
+
+        // A repeated x-coordinate adds no independent equation
+        let repeated = [shares[0], shares[1], shares[1]];
+        assert!(diagnose_shares(&repeated, 3) == Diagnosis::RankDeficient { rank: 2 });
+
+        shares[4].y.modadd_assign(&F3329::one());
+        assert!(
+            diagnose_shares(&shares, 3)
+                == Diagnosis::Inconsistent {
+                    conflicting: vec![4]
+                }
+        );
+        // A bad share among the first three shifts the blame onto the others
+        shares.swap(0, 4);
+        assert!(
+            diagnose_shares(&shares, 3)
+                == Diagnosis::Inconsistent {
+                    conflicting: vec![3, 4, 5]
+                }
+        );
+        let diagnosis = diagnose_shares(&shares[1..], 3);
+        assert!(matches!(diagnosis, Diagnosis::Consistent(_)));
+        #[cfg(not(feature = "debug-secrets"))]
+        assert_eq!(format!("{diagnosis:?}"), "Consistent(REDACTED)");
+    }
+}
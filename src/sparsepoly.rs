@@ -0,0 +1,231 @@
+//! Sparse polynomials over any field
+//!
+//! `SparsePoly` stores only the non-zero terms as (exponent, coefficient) pairs, so a polynomial
+//! such as x^571 + x^10 + x^5 + x^2 + 1 takes five terms instead of 572 coefficients. Products cost
+//! O(st) for s and t terms regardless of the degrees, and evaluation costs one exponentiation by
+//! the gap between consecutive exponents per term.
+use crate::f2x::Degree;
+use crate::galoisfields::FieldArithmetic;
+use crate::poly::PolyVec;
+use alloc::{vec, vec::Vec};
+
+/// A polynomial as a list of (exponent, coefficient) pairs in ascending order of exponent, with
+/// no zero coefficients and no repeated exponents. The zero polynomial has no terms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparsePoly<F: FieldArithmetic> {
+    terms: Vec<(usize, F)>,
+}
+
+impl<F: FieldArithmetic> SparsePoly<F> {
+    pub fn zero() -> Self {
+        Self { terms: vec![] }
+    }
+
+    /// Build a polynomial from terms in any order. Coefficients of repeated exponents are added
+    /// together, and zero coefficients are dropped
+    pub fn from_terms(terms: &[(usize, F)]) -> Self {
+        Self {
+            terms: normalize(terms.to_vec()),
+        }
+    }
+
+    /// The sum of x^e over the input exponents, e.g. a sparse modulus
+    pub fn from_exponents(exponents: &[usize]) -> Self {
+        let terms = exponents
+            .iter()
+            .map(|exp| (*exp, F::one()))
+            .collect::<Vec<(usize, F)>>();
+        Self::from_terms(&terms)
+    }
+
+    /// The non-zero terms in ascending order of exponent
+    pub fn terms(&self) -> &[(usize, F)] {
+        &self.terms
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// See `PolyVec::degree`
+    pub fn degree(&self) -> Degree {
+        match self.terms.last() {
+            Some((exp, _)) => Degree::NonNegative(*exp),
+            None => Degree::NegativeInfinity,
+        }
+    }
+
+    /// The coefficient of x^exp, which is zero for every exponent without a term
+    pub fn coeff(&self, exp: usize) -> F {
+        match self.terms.binary_search_by_key(&exp, |(e, _)| *e) {
+            Ok(i) => self.terms[i].1,
+            Err(_) => F::zero(),
+        }
+    }
+
+    pub fn add(&self, rhs: &Self) -> Self {
+        let mut terms = self.terms.clone();
+        terms.extend_from_slice(&rhs.terms);
+        Self {
+            terms: normalize(terms),
+        }
+    }
+
+    pub fn sub(&self, rhs: &Self) -> Self {
+        let mut terms = self.terms.clone();
+        terms.extend(
+            rhs.terms
+                .iter()
+                .map(|(exp, coeff)| (*exp, F::zero().modsub(coeff))),
+        );
+        Self {
+            terms: normalize(terms),
+        }
+    }
+
+    /// Multiply every pair of terms and collect the products. Unlike `PolyVec::mul`, there is no
+    /// capacity to overflow
+    pub fn mul(&self, rhs: &Self) -> Self {
+        let mut terms = Vec::with_capacity(self.terms.len() * rhs.terms.len());
+        for (lhs_exp, lhs_coeff) in self.terms.iter() {
+            for (rhs_exp, rhs_coeff) in rhs.terms.iter() {
+                terms.push((lhs_exp + rhs_exp, lhs_coeff.modmul(rhs_coeff)));
+            }
+        }
+        Self {
+            terms: normalize(terms),
+        }
+    }
+
+    /// Multiplication by a single scalar
+    pub fn mul_coeff(&self, rhs: &F) -> Self {
+        let terms = self
+            .terms
+            .iter()
+            .map(|(exp, coeff)| (*exp, coeff.modmul(rhs)))
+            .collect::<Vec<(usize, F)>>();
+        Self {
+            terms: normalize(terms),
+        }
+    }
+
+    /// Evaluate the polynomial at the given point. The powers of the point are reached in
+    /// ascending order, each from the previous one, so the cost depends on the number of terms
+    /// and the logarithm of the degree rather than on the degree
+    pub fn evaluate(&self, at: &F) -> F {
+        let mut sum = F::zero();
+        let mut power = F::one();
+        let mut prev_exp = 0;
+        for (exp, coeff) in self.terms.iter() {
+            power = power.modmul(&at.modexp((exp - prev_exp) as u64));
+            prev_exp = *exp;
+            sum.modadd_assign(&coeff.modmul(&power));
+        }
+        sum
+    }
+
+    /// Copy the terms into a dense polynomial of the given capacity. Will panic if the degree does
+    /// not fit
+    pub fn to_dense(&self, capacity: usize) -> PolyVec<F> {
+        if self.degree() >= Degree::NonNegative(capacity) {
+            panic!("polynomial does not fit in the capacity");
+        }
+        let mut dense = PolyVec::zero_with_capacity(capacity);
+        for (exp, coeff) in self.terms.iter() {
+            dense.coeffs[*exp] = *coeff;
+        }
+        dense
+    }
+}
+
+impl<F: FieldArithmetic> Default for SparsePoly<F> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F: FieldArithmetic> From<&PolyVec<F>> for SparsePoly<F> {
+    fn from(poly: &PolyVec<F>) -> Self {
+        let terms = poly
+            .coeffs
+            .iter()
+            .enumerate()
+            .filter(|(_, coeff)| !coeff.is_zero())
+            .map(|(exp, coeff)| (exp, *coeff))
+            .collect::<Vec<(usize, F)>>();
+        Self { terms }
+    }
+}
+
+/// Sort the terms by exponent, add up the coefficients of equal exponents, and drop the terms
+/// whose coefficient is zero
+fn normalize<F: FieldArithmetic>(mut terms: Vec<(usize, F)>) -> Vec<(usize, F)> {
+    terms.sort_by_key(|(exp, _)| *exp);
+    let mut normalized: Vec<(usize, F)> = Vec::with_capacity(terms.len());
+    for (exp, coeff) in terms {
+        match normalized.last_mut() {
+            Some((last_exp, last_coeff)) if *last_exp == exp => last_coeff.modadd_assign(&coeff),
+            _ => normalized.push((exp, coeff)),
+        }
+    }
+    normalized.retain(|(_, coeff)| !coeff.is_zero());
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "gf128")]
+    use crate::galoisfields::GF2p128;
+    use crate::primefields::Fp25519;
+    use rand::{thread_rng, Rng};
+
+    /// A random polynomial with up to `count` terms of degree below `bound`
+    fn random_sparse<F: FieldArithmetic>(count: usize, bound: usize) -> SparsePoly<F> {
+        let mut rng = thread_rng();
+        let terms = (0..count)
+            .map(|_| (rng.gen_range(0..bound), F::random(&mut rng)))
+            .collect::<Vec<(usize, F)>>();
+        SparsePoly::from_terms(&terms)
+    }
+
+    #[test]
+    fn random_sparse_arithmetic() {
+        let mut rng = thread_rng();
+        let lhs = random_sparse::<Fp25519>(5, 16);
+        let rhs = random_sparse::<Fp25519>(4, 16);
+        let (lhs_dense, rhs_dense) = (lhs.to_dense(32), rhs.to_dense(32));
+        assert_eq!(lhs.add(&rhs).to_dense(32), lhs_dense.add(&rhs_dense));
+        assert_eq!(lhs.sub(&rhs).to_dense(32), lhs_dense.sub(&rhs_dense));
+        assert_eq!(lhs.mul(&rhs).to_dense(32), lhs_dense.mul(&rhs_dense));
+        assert_eq!(SparsePoly::from(&lhs_dense), lhs);
+        assert!(lhs.sub(&lhs).is_zero());
+
+        let at = Fp25519::random(&mut rng);
+        assert_eq!(lhs.evaluate(&at), lhs_dense.evaluate(&at));
+        assert_eq!(lhs.evaluate(&Fp25519::zero()), lhs.coeff(0));
+        let scalar = Fp25519::random(&mut rng);
+        assert_eq!(
+            lhs.mul_coeff(&scalar).evaluate(&at),
+            lhs.evaluate(&at).modmul(&scalar)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "gf128")]
+    fn sparse_modulus() {
+        let modulus = SparsePoly::<GF2p128>::from_exponents(&[571, 10, 5, 2, 0]);
+        assert_eq!(modulus.terms().len(), 5);
+        assert_eq!(modulus.degree(), Degree::NonNegative(571));
+        assert_eq!(modulus.coeff(10), GF2p128::ONE);
+        assert_eq!(modulus.coeff(11), GF2p128::ZERO);
+        // In characteristic 2, repeated exponents cancel
+        let cancelled = SparsePoly::<GF2p128>::from_exponents(&[3, 1, 3]);
+        assert_eq!(cancelled, SparsePoly::from_exponents(&[1]));
+        assert_eq!(modulus.evaluate(&GF2p128::ONE), GF2p128::ONE);
+        assert_eq!(
+            SparsePoly::<GF2p128>::zero().degree(),
+            Degree::NegativeInfinity
+        );
+    }
+}
@@ -0,0 +1,485 @@
+//! Tower field representation of GF(2^128) as a degree-8 extension of GF(2^16)
+//!
+//! An element of GF((2^16)^8) is a polynomial of degree less than 8 with coefficients in
+//! `GF2p16`, reduced under a fixed irreducible polynomial Q(y). Arithmetic only ever touches
+//! 16-bit elements, and inversion reduces to a single inversion in GF(2^16) (see
+//! `GF2p16p8::modinv`), which makes the representation friendly to table-assisted and embedded
+//! implementations.
+//!
+//! The tower field is isomorphic to `GF2p128`. The isomorphism maps the indeterminate y to the
+//! element x of `GF2p128`, and maps the indeterminate of the base field to GAMMA, a root of
+//! x^16 + x^5 + x^3 + x + 1 in `GF2p128`. Q(y) is then the minimal polynomial of x over the
+//! subfield generated by GAMMA.
+use crate::f2x::F2x;
+use crate::galoisfields::{FieldArithmetic, GF2p128, GF2p16};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use rand_core::CryptoRngCore;
+
+/// The non-leading coefficients of the monic tower modulus Q(y), lowest power first
+const TOWER_MODULUS: [GF2p16; 8] = [
+    gf16(0xB477),
+    gf16(0x6202),
+    gf16(0xD590),
+    gf16(0xEC19),
+    gf16(0xD637),
+    gf16(0x3CCC),
+    gf16(0x935B),
+    gf16(0xA664),
+];
+
+/// The image of the base field's indeterminate in `GF2p128`
+const GAMMA: GF2p128 =
+    GF2p128::from_poly(F2x::from_u128(0x7FF5_99F0_7889_8C68_CA78_202A_D88C_773A));
+
+const fn gf16(coeffs: u16) -> GF2p16 {
+    GF2p16::from_poly(F2x::from_u128(coeffs as u128))
+}
+
+/// An element of GF((2^16)^8). Coefficients are organized in little-endian order, so coeffs[0] is
+/// the constant term.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GF2p16p8 {
+    pub coeffs: [GF2p16; 8],
+}
+
+impl GF2p16p8 {
+    pub const DEGREE: usize = 8;
+    pub const BYTES: usize = 16;
+    pub const ZERO: Self = Self::from_coeffs([GF2p16::ZERO; 8]);
+    pub const ONE: Self = {
+        let mut coeffs = [GF2p16::ZERO; 8];
+        coeffs[0] = GF2p16::ONE;
+        Self::from_coeffs(coeffs)
+    };
+
+    pub const fn from_coeffs(coeffs: [GF2p16; 8]) -> Self {
+        Self { coeffs }
+    }
+
+    /// Map into the polynomial basis representation
+    pub fn to_gf2p128(&self) -> GF2p128 {
+        let gamma_powers = gamma_powers();
+        let x = GF2p128::from_poly(F2x::from_exponents(&[1]));
+
+        // Horner's rule over the powers of y, which maps to x
+        self.coeffs.iter().rev().fold(GF2p128::ZERO, |acc, coeff| {
+            let coeff_img = (0..16)
+                .filter(|i| (coeff.poly.get_limb(0).unwrap() >> i) & 1 == 1)
+                .fold(GF2p128::ZERO, |sum, i| sum.modadd(&gamma_powers[i]));
+            acc.modmul(&x).modadd(&coeff_img)
+        })
+    }
+
+    /// Map from the polynomial basis representation. This is the inverse of `to_gf2p128`
+    pub fn from_gf2p128(elem: &GF2p128) -> Self {
+        let elem_bits = gf2p128_to_bits(elem);
+        let mut coeffs = [GF2p16::ZERO; 8];
+
+        cached!([u128; 128], inverse_basis_matrix())
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| (*row & elem_bits).count_ones() % 2 == 1)
+            .for_each(|(k, _)| {
+                let limb = coeffs[k / 16].poly.get_mut_limb(0).unwrap();
+                *limb ^= 1 << (k % 16);
+            });
+
+        Self::from_coeffs(coeffs)
+    }
+
+    /// The Frobenius map over the base field, a -> a^(2^16). Because coefficients are fixed by this
+    /// map, it sends sum(c_j * y^j) to sum(c_j * (y^(2^16))^j), which is a linear map with
+    /// precomputed images of the powers of y.
+    pub fn frobenius_base(&self) -> Self {
+        let images = cached!([GF2p16p8; 8], frobenius_images());
+        let mut output = Self::ZERO;
+
+        for (coeff, image) in self.coeffs.iter().zip(images.iter()) {
+            for j in 0..Self::DEGREE {
+                output.coeffs[j] = output.coeffs[j].modadd(&coeff.modmul(&image.coeffs[j]));
+            }
+        }
+
+        output
+    }
+
+    /// The relative norm down to the subfield GF(2^(16d)), which is the product of the conjugates
+    /// a^(q^(d * j)) for j = 0, ..., 8 / d - 1 with q = 2^16. The degree d must divide 8.
+    pub fn norm_to_subfield(&self, degree: usize) -> Self {
+        if degree == 0 || Self::DEGREE % degree != 0 {
+            panic!("subfield degree must divide 8");
+        }
+        let mut conjugate = *self;
+        let mut norm = *self;
+        for _ in 1..(Self::DEGREE / degree) {
+            conjugate = (0..degree).fold(conjugate, |acc, _| acc.frobenius_base());
+            norm = norm.mul_reduce(&conjugate);
+        }
+        norm
+    }
+
+    /// The norm down to the base field GF(2^16)
+    pub fn norm(&self) -> GF2p16 {
+        self.norm_to_subfield(1).coeffs[0]
+    }
+
+    /// The absolute norm down to GF(2), computed through the base field. Return true if the norm
+    /// is 1, which is the case exactly when self is not zero.
+    pub fn absolute_norm(&self) -> bool {
+        let base_norm = self.norm();
+        (1..GF2p16::BITS)
+            .fold(base_norm, |acc, k| acc.modmul(&base_norm.frobenius(k)))
+            .is_one()
+    }
+
+    /// Return true if self lies in the subfield GF(2^(16d)), meaning that it is fixed by the Frobenius
+    /// map a -> a^(q^d). The degree d must divide 8.
+    pub fn in_subfield(&self, degree: usize) -> bool {
+        if degree == 0 || Self::DEGREE % degree != 0 {
+            panic!("subfield degree must divide 8");
+        }
+        (0..degree).fold(*self, |acc, _| acc.frobenius_base()) == *self
+    }
+
+    /// Schoolbook multiplication followed by reduction under Q(y)
+    fn mul_reduce(&self, rhs: &Self) -> Self {
+        let mut prod = [GF2p16::ZERO; 2 * Self::DEGREE - 1];
+
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                prod[i + j] = prod[i + j].modadd(&a.modmul(b));
+            }
+        }
+        // y^8 = sum(TOWER_MODULUS[j] * y^j) since subtraction is addition
+        for d in (Self::DEGREE..(2 * Self::DEGREE - 1)).rev() {
+            let lead = prod[d];
+            for (j, q) in TOWER_MODULUS.iter().enumerate() {
+                prod[d - Self::DEGREE + j] = prod[d - Self::DEGREE + j].modadd(&lead.modmul(q));
+            }
+        }
+
+        let mut coeffs = [GF2p16::ZERO; 8];
+        coeffs.copy_from_slice(&prod[..Self::DEGREE]);
+        Self::from_coeffs(coeffs)
+    }
+}
+
+/// GAMMA^0, GAMMA^1, ..., GAMMA^15
+fn gamma_powers() -> [GF2p128; 16] {
+    let mut powers = [GF2p128::ONE; 16];
+    for i in 1..16 {
+        powers[i] = powers[i - 1].modmul(&GAMMA);
+    }
+    powers
+}
+
+/// The images of y^0, ..., y^7 under the Frobenius map over the base field
+fn frobenius_images() -> [GF2p16p8; 8] {
+    let mut y = GF2p16p8::ZERO;
+    y.coeffs[1] = GF2p16::ONE;
+    let y_q = (0..16).fold(y, |acc, _| acc.mul_reduce(&acc));
+
+    let mut images = [GF2p16p8::ONE; 8];
+    for j in 1..8 {
+        images[j] = images[j - 1].mul_reduce(&y_q);
+    }
+    images
+}
+
+fn gf2p128_to_bits(elem: &GF2p128) -> u128 {
+    let mut buf = [0u8; GF2p128::BYTES];
+    elem.write_be_bytes(&mut buf);
+    u128::from_be_bytes(buf)
+}
+
+/// Row k of the output has bit r set if bit r of a `GF2p128` element contributes to bit k of its
+/// tower representation, where bit k is bit (k % 16) of coefficient (k / 16)
+fn inverse_basis_matrix() -> [u128; 128] {
+    // The image of each tower basis vector y^j * g^i in GF2p128; these are the columns
+    let images: [u128; 128] = core::array::from_fn(|k| {
+        let mut basis = GF2p16p8::ZERO;
+        *basis.coeffs[k / 16].poly.get_mut_limb(0).unwrap() = 1 << (k % 16);
+        gf2p128_to_bits(&basis.to_gf2p128())
+    });
+    // Gauss-Jordan elimination on the transpose [images | I]: row k is image k augmented with
+    // the unit vector e_k. Reducing the left half to the identity permutes the right half into
+    // the inverse.
+    let mut rows: [(u128, u128); 128] = core::array::from_fn(|k| (images[k], 1u128 << k));
+    for bit in 0..128 {
+        let pivot = (bit..128)
+            .find(|&r| (rows[r].0 >> bit) & 1 == 1)
+            .expect("tower basis is not linearly independent");
+        rows.swap(bit, pivot);
+        let (pivot_left, pivot_right) = rows[bit];
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != bit && (row.0 >> bit) & 1 == 1 {
+                row.0 ^= pivot_left;
+                row.1 ^= pivot_right;
+            }
+        }
+    }
+    // rows[r].1 now expresses the unit vector e_r (bit r of GF2p128) in the tower basis, so
+    // tower bit k of an element is the parity of the bits r for which rows[r].1 has bit k
+    let mut matrix = [0u128; 128];
+    for (r, (_, combination)) in rows.iter().enumerate() {
+        for (k, row) in matrix.iter_mut().enumerate() {
+            if (combination >> k) & 1 == 1 {
+                *row |= 1 << r;
+            }
+        }
+    }
+    matrix
+}
+
+crate::__field_ops!(GF2p16p8);
+crate::__num_traits_field!(GF2p16p8);
+
+impl Distribution<GF2p16p8> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GF2p16p8 {
+        GF2p16p8::from_coeffs(rng.gen())
+    }
+}
+
+impl FieldArithmetic for GF2p16p8 {
+    fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+    fn is_one(&self) -> bool {
+        *self == Self::ONE
+    }
+    fn zero() -> Self {
+        Self::ZERO
+    }
+    fn one() -> Self {
+        Self::ONE
+    }
+    fn random(rng: &mut impl CryptoRngCore) -> Self {
+        rng.gen()
+    }
+    fn modadd(&self, rhs: &Self) -> Self {
+        let mut coeffs = self.coeffs;
+        coeffs
+            .iter_mut()
+            .zip(rhs.coeffs.iter())
+            .for_each(|(a, b)| *a = a.modadd(b));
+        Self::from_coeffs(coeffs)
+    }
+    fn modsub(&self, rhs: &Self) -> Self {
+        self.modadd(rhs)
+    }
+    fn modmul(&self, rhs: &Self) -> Self {
+        self.mul_reduce(rhs)
+    }
+
+    /// With r = (2^128 - 1) / (2^16 - 1), the norm a^r = a * a^q * ... * a^(q^7) (q = 2^16) lies in
+    /// the base field, so a^(-1) = (a^q * ... * a^(q^7)) / a^r needs only one inversion in
+    /// GF(2^16) and Frobenius maps, which are linear.
+    fn modinv(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        let mut conjugate = *self;
+        let mut partial = Self::ONE;
+        for _ in 1..Self::DEGREE {
+            conjugate = conjugate.frobenius_base();
+            partial = partial.mul_reduce(&conjugate);
+        }
+        let norm = self.mul_reduce(&partial).coeffs[0];
+        let norm_inv = norm.modinv()?;
+        let mut inverse = partial;
+        inverse
+            .coeffs
+            .iter_mut()
+            .for_each(|coeff| *coeff = coeff.modmul(&norm_inv));
+        Some(inverse)
+    }
+
+    /// Fermat inversion self^(2^128 - 2), with 128 squarings and 127 multiplications
+    fn inv_ct(&self) -> Self {
+        (1..(8 * Self::BYTES))
+            .fold(Self::ONE, |acc, _| acc.square().modmul(self))
+            .square()
+    }
+
+    fn bytes() -> usize {
+        Self::BYTES
+    }
+
+    /// Coefficients are written from the highest power down, each as two big-endian bytes
+    /// regardless of the limb width
+    fn write_be_bytes(&self, dst: &mut [u8]) {
+        if dst.len() != Self::BYTES {
+            panic!("input buffer size is incorrect");
+        }
+        self.coeffs.iter().rev().enumerate().for_each(|(i, coeff)| {
+            let limb = coeff.poly.get_limb(0).unwrap().to_be_bytes();
+            dst[(2 * i)..(2 * i + 2)].copy_from_slice(&limb[(limb.len() - 2)..]);
+        });
+    }
+
+    fn from_be_bytes(src: &[u8]) -> Self {
+        if src.len() != Self::BYTES {
+            panic!("input buffer size is incorrect");
+        }
+        let mut coeffs = [GF2p16::ZERO; 8];
+        coeffs
+            .iter_mut()
+            .rev()
+            .enumerate()
+            .for_each(|(i, coeff)| *coeff = gf16(u16::from_be_bytes([src[2 * i], src[2 * i + 1]])));
+        Self::from_coeffs(coeffs)
+    }
+
+    /// Every u64 is canonical: it fills the four lowest coefficients, 16 bits each
+    fn try_from_u64(val: u64) -> Option<Self> {
+        let mut coeffs = [GF2p16::ZERO; 8];
+        coeffs
+            .iter_mut()
+            .take(4)
+            .enumerate()
+            .for_each(|(i, coeff)| *coeff = gf16((val >> (16 * i)) as u16));
+        Some(Self::from_coeffs(coeffs))
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for GF2p16p8 {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&self.coeffs[..], &other.coeffs[..])
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl subtle::ConditionallySelectable for GF2p16p8 {
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        let mut coeffs = [GF2p16::ZERO; 8];
+        for (i, coeff) in coeffs.iter_mut().enumerate() {
+            *coeff = subtle::ConditionallySelectable::conditional_select(
+                &a.coeffs[i],
+                &b.coeffs[i],
+                choice,
+            );
+        }
+        Self::from_coeffs(coeffs)
+    }
+}
+
+impl zeroize::Zeroize for GF2p16p8 {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.coeffs);
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GF2p16p8 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_coeffs(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[GF2p16; 8] as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::thread_rng;
+
+    const NTESTS: usize = 10;
+
+    #[test]
+    fn tower_to_gf2p128() {
+        let elem = GF2p16p8::from_coeffs([
+            gf16(0x52E6),
+            gf16(0xF2A7),
+            gf16(0x269E),
+            gf16(0x6513),
+            gf16(0xA6A3),
+            gf16(0x0C5C),
+            gf16(0x128B),
+            gf16(0xD23F),
+        ]);
+        let image = GF2p128::from_poly(F2x::from_u128(0x4241_7C2B_2A53_FECD_E4F0_2494_B02E_6EEA));
+        assert_eq!(elem.to_gf2p128(), image);
+        assert_eq!(GF2p16p8::from_gf2p128(&image), elem);
+    }
+
+    #[test]
+    fn random_tower_isomorphism() {
+        for _ in 0..NTESTS {
+            let (lhs, rhs) = (
+                GF2p16p8::random(&mut thread_rng()),
+                GF2p16p8::random(&mut thread_rng()),
+            );
+            assert_eq!(GF2p16p8::from_gf2p128(&lhs.to_gf2p128()), lhs);
+            assert_eq!(
+                lhs.modmul(&rhs).to_gf2p128(),
+                lhs.to_gf2p128().modmul(&rhs.to_gf2p128())
+            );
+            assert_eq!(
+                lhs.modadd(&rhs).to_gf2p128(),
+                lhs.to_gf2p128().modadd(&rhs.to_gf2p128())
+            );
+        }
+    }
+
+    #[test]
+    fn random_tower_inv() {
+        for _ in 0..NTESTS {
+            let elem = GF2p16p8::random(&mut thread_rng());
+            if elem.is_zero() {
+                assert!(elem.modinv().is_none());
+            } else {
+                let inv = elem.modinv().expect("Unexpected inversion failure");
+                assert!(elem.modmul(&inv).is_one());
+            }
+        }
+    }
+
+    #[test]
+    fn random_tower_serde() {
+        for _ in 0..NTESTS {
+            let lhs = GF2p16p8::random(&mut thread_rng());
+            let mut buf = [0u8; GF2p16p8::BYTES];
+            lhs.write_be_bytes(&mut buf);
+            assert_eq!(GF2p16p8::from_be_bytes(&buf), lhs);
+        }
+        let mut buf = [0u8; GF2p16p8::BYTES];
+        buf[8..].copy_from_slice(&0x0123_4567_89AB_CDEFu64.to_be_bytes());
+        assert_eq!(
+            GF2p16p8::try_from_u64(0x0123_4567_89AB_CDEF),
+            Some(GF2p16p8::from_be_bytes(&buf))
+        );
+    }
+
+    #[test]
+    fn random_tower_norm() {
+        for _ in 0..NTESTS {
+            let (lhs, rhs) = (
+                GF2p16p8::random(&mut thread_rng()),
+                GF2p16p8::random(&mut thread_rng()),
+            );
+            assert_eq!(lhs.norm().modmul(&rhs.norm()), lhs.modmul(&rhs).norm());
+            assert_eq!(lhs.norm_to_subfield(8), lhs);
+            for degree in [1, 2, 4] {
+                assert!(lhs.norm_to_subfield(degree).in_subfield(degree));
+            }
+            assert!(lhs.absolute_norm());
+
+            // The norm agrees with the product of conjugates computed in GF2p128
+            let image = lhs.to_gf2p128();
+            let norm =
+                (1..GF2p16p8::DEGREE).fold(image, |acc, j| acc.modmul(&image.frobenius(16 * j)));
+            assert_eq!(GF2p16p8::from_gf2p128(&norm).coeffs[0], lhs.norm());
+            assert!(GF2p16p8::from_gf2p128(&norm).in_subfield(1));
+        }
+        assert!(!GF2p16p8::ZERO.absolute_norm());
+        // y has degree 8 over the base field, so it lies in no proper subfield
+        let mut y = GF2p16p8::ZERO;
+        y.coeffs[1] = GF2p16::ONE;
+        assert!(!y.in_subfield(4));
+        assert!(y.in_subfield(8));
+    }
+}
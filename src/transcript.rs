@@ -0,0 +1,167 @@
+//! Domain-separated transcripts of the public messages of a protocol
+//!
+//! Interactive protocols built on secret sharing (verifiable secret sharing, share refresh,
+//! resharing) exchange commitments, complaints, and shares over several rounds. Hashing every
+//! public message into a running transcript binds all of them to one session: challenges and
+//! session identifiers derived from the transcript differ as soon as any message, its label, or
+//! the protocol differs, so messages cannot be mixed and matched across sessions.
+//!
+//! Every label and message is absorbed into SHAKE256 with its length, so that the boundaries
+//! between them are unambiguous.
+use crate::deterministic::DeterministicSampler;
+use crate::galoisfields::FieldArithmetic;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake256;
+
+/// Absorbed before anything else, so that transcripts never collide with other uses of SHAKE256
+const TRANSCRIPT_DOMAIN: &[u8] = b"shamirsecretsharing transcript v1";
+
+/// The running hash of the public messages of one protocol session
+#[derive(Clone)]
+pub struct Transcript {
+    hasher: Shake256,
+}
+
+impl Transcript {
+    /// Start a transcript for the protocol identified by the input domain separator, e.g.
+    /// `b"feldman-vss"`
+    pub fn new(protocol: &'static [u8]) -> Self {
+        let mut transcript = Self {
+            hasher: Shake256::default(),
+        };
+        transcript.absorb(TRANSCRIPT_DOMAIN);
+        transcript.append_message(b"protocol", protocol);
+
+        transcript
+    }
+
+    /// Absorb the input with its length
+    fn absorb(&mut self, bytes: &[u8]) {
+        self.hasher.update(&(bytes.len() as u64).to_be_bytes());
+        self.hasher.update(bytes);
+    }
+
+    /// Append a labeled message
+    pub fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        self.absorb(label);
+        self.absorb(message);
+    }
+
+    /// Append a labeled integer, e.g. a participant index or a threshold
+    pub fn append_u64(&mut self, label: &'static [u8], value: u64) {
+        self.append_message(label, &value.to_be_bytes());
+    }
+
+    /// Append a labeled field element in its big-endian serialization
+    #[cfg(feature = "alloc")]
+    pub fn append_element<E: FieldArithmetic>(&mut self, label: &'static [u8], elem: &E) {
+        let mut buf = vec![0u8; E::bytes()];
+        elem.write_be_bytes(&mut buf);
+        self.append_message(label, &buf);
+    }
+
+    /// Append a labeled sequence of field elements, such as the commitments to the coefficients
+    /// of a polynomial
+    #[cfg(feature = "alloc")]
+    pub fn append_elements<E: FieldArithmetic>(&mut self, label: &'static [u8], elems: &[E]) {
+        let mut buf = vec![0u8; elems.len() * E::bytes()];
+        buf.chunks_mut(E::bytes())
+            .zip(elems)
+            .for_each(|(dst, elem)| elem.write_be_bytes(dst));
+        self.append_message(label, &buf);
+    }
+
+    /// Fill the destination with challenge bytes that depend on everything appended so far. The
+    /// challenge is then appended to the transcript itself, so that later challenges differ.
+    pub fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        self.append_u64(label, dest.len() as u64);
+        self.hasher.clone().finalize_xof().read(dest);
+        self.append_message(b"challenge", dest);
+    }
+
+    /// Derive a challenge field element. See `challenge_bytes`. Reducing squeezed bytes would
+    /// favor the small residues of a prime field, so a 32-byte challenge instead seeds a
+    /// `DeterministicSampler`, from which the element is drawn with the field's own uniform
+    /// sampler.
+    pub fn challenge_element<E: FieldArithmetic>(&mut self, label: &'static [u8]) -> E {
+        let mut seed = [0u8; 32];
+        self.challenge_bytes(label, &mut seed);
+        E::random(&mut DeterministicSampler::new(&seed, label))
+    }
+
+    /// A 32-byte identifier of the session so far, which can be attached to messages to bind them
+    /// to this session
+    pub fn session_id(&self) -> [u8; 32] {
+        let mut id = [0u8; 32];
+        let mut transcript = self.clone();
+        transcript.absorb(b"session id");
+        transcript.hasher.finalize_xof().read(&mut id);
+        id
+    }
+}
+
+#[cfg(all(test, feature = "gf256"))]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::galoisfields::GF2p256;
+    use crate::galoisfields::F3329;
+    use crate::primefields::Fp25519;
+    #[cfg(feature = "alloc")]
+    use rand::thread_rng;
+
+    #[test]
+    fn transcript_binds_messages() {
+        let transcript = |protocol: &'static [u8], label: &'static [u8], message: &[u8]| {
+            let mut transcript = Transcript::new(protocol);
+            transcript.append_u64(b"threshold", 3);
+            transcript.append_message(label, message);
+            transcript.session_id()
+        };
+        let id = transcript(b"vss", b"commitments", b"abc");
+        assert_eq!(id, transcript(b"vss", b"commitments", b"abc"));
+        assert_ne!(id, transcript(b"refresh", b"commitments", b"abc"));
+        assert_ne!(id, transcript(b"vss", b"complaints", b"abc"));
+        assert_ne!(id, transcript(b"vss", b"commitments", b"abd"));
+        // Moving bytes between the label and the message changes the transcript
+        assert_ne!(
+            transcript(b"vss", b"ab", b"cabc"),
+            transcript(b"vss", b"abc", b"abc")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn challenges_depend_on_transcript() {
+        let elems = [
+            GF2p256::random(&mut thread_rng()),
+            GF2p256::random(&mut thread_rng()),
+        ];
+        let mut lhs = Transcript::new(b"vss");
+        let mut rhs = Transcript::new(b"vss");
+        lhs.append_elements(b"commitments", &elems);
+        rhs.append_elements(b"commitments", &elems);
+        let challenge: GF2p256 = lhs.challenge_element(b"challenge");
+        assert_eq!(challenge, rhs.challenge_element(b"challenge"));
+        // Each challenge is absorbed, so the next one differs
+        assert_ne!(challenge, lhs.challenge_element::<GF2p256>(b"challenge"));
+
+        let mut swapped = Transcript::new(b"vss");
+        swapped.append_elements(b"commitments", &[elems[1], elems[0]]);
+        assert_ne!(
+            challenge,
+            swapped.challenge_element::<GF2p256>(b"challenge")
+        );
+    }
+
+    #[test]
+    fn prime_field_challenges() {
+        let mut transcript = Transcript::new(b"vss");
+        let small: F3329 = transcript.challenge_element(b"challenge");
+        assert_ne!(small, transcript.challenge_element(b"challenge"));
+        let large: Fp25519 = transcript.challenge_element(b"challenge");
+        assert_ne!(large, transcript.challenge_element(b"challenge"));
+    }
+}